@@ -0,0 +1,35 @@
+//! Bulk table-based conversions: the precomputed breakpoint lookup against per-value floating point math.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+
+use pen_and_paper_geometric_mean::table_based::{MULTIPLIERS, number_to_log_representation};
+
+/// Values spread log-uniformly from 1 to 10^9, the range of trivia answers
+fn values() -> Vec<f64> {
+    (0..10_000).map(|i| 10_f64.powf((i as f64 * 0.618_034) % 9.0)).collect()
+}
+
+/// The conversion as it was before the lookup: find the decade with a logarithm, then scan the table
+fn computed_log_representation(value: f64) -> i32 {
+    let zeros = value.log10().floor() as i32;
+    let leading_digits = value / 10_f64.powi(zeros);
+    let entry = MULTIPLIERS.iter().rposition(|&m| leading_digits >= m).unwrap_or(0);
+    zeros * 10 + entry as i32
+}
+
+fn conversions(c: &mut Criterion) {
+    let values = values();
+    assert!(values.iter().all(|&v| number_to_log_representation(v) == computed_log_representation(v)));
+
+    let mut group = c.benchmark_group("convert 10,000 values");
+    group.bench_function("computed", |b| {
+        b.iter(|| black_box(&values).iter().map(|&v| computed_log_representation(v)).sum::<i32>())
+    });
+    group.bench_function("lookup", |b| b.iter(|| black_box(&values).iter().map(|&v| number_to_log_representation(v)).sum::<i32>()));
+    group.finish();
+}
+
+criterion_group!(benches, conversions);
+criterion_main!(benches);
@@ -0,0 +1,28 @@
+//! The evaluation harness's hot loop: drawing random test cases and scoring an estimator on each.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use pen_and_paper_geometric_mean::evaluation::evaluate_estimate;
+use pen_and_paper_geometric_mean::exact::ExactGeometricMean;
+use pen_and_paper_geometric_mean::log_linear::LogLinearApproximation;
+use pen_and_paper_geometric_mean::table_based::TableBasedApproximation;
+use pen_and_paper_geometric_mean::traits::{EstimateGeometricMean, MethodMetadata};
+
+const TEST_CASES: usize = 10_000;
+
+fn bench_method<E: EstimateGeometricMean + MethodMetadata>(c: &mut Criterion) {
+    c.bench_function(&format!("evaluate 10,000 cases ({})", E::NAME), |b| {
+        b.iter(|| evaluate_estimate::<_, E>(&mut StdRng::seed_from_u64(42), 1.0, 100_000.0, TEST_CASES))
+    });
+}
+
+fn evaluation(c: &mut Criterion) {
+    bench_method::<ExactGeometricMean>(c);
+    bench_method::<LogLinearApproximation>(c);
+    bench_method::<TableBasedApproximation>(c);
+}
+
+criterion_group!(benches, evaluation);
+criterion_main!(benches);
@@ -0,0 +1,31 @@
+//! The exact geometric mean of one long input: one logarithm per chunk of split values against one per value.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+
+use pen_and_paper_geometric_mean::exact::geometric_mean;
+
+/// Values spread log-uniformly from 1 to 10^9, as many as a large evaluation corpus holds
+fn values() -> Vec<f64> {
+    (0..1_000_000).map(|i| 10_f64.powf((i as f64 * 0.618_034) % 9.0)).collect()
+}
+
+/// The geometric mean as it was before chunking: a logarithm of every value
+fn per_value_geometric_mean(values: &[f64]) -> f64 {
+    (values.iter().map(|x| x.ln()).sum::<f64>() / values.len() as f64).exp()
+}
+
+fn exact(c: &mut Criterion) {
+    let values = values();
+    let (chunked, per_value) = (geometric_mean(&values).expect("values are positive"), per_value_geometric_mean(&values));
+    assert!((chunked - per_value).abs() < per_value * 1e-12);
+
+    let mut group = c.benchmark_group("geometric mean of 10^6 values");
+    group.bench_function("per value", |b| b.iter(|| per_value_geometric_mean(black_box(&values))));
+    group.bench_function("chunked", |b| b.iter(|| geometric_mean(black_box(&values))));
+    group.finish();
+}
+
+criterion_group!(benches, exact);
+criterion_main!(benches);
@@ -0,0 +1,69 @@
+//! Trivia value rounding, which every sampled guess goes through: comparisons against logarithms.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use rand::SeedableRng;
+use rand::distributions::Distribution;
+use rand::rngs::StdRng;
+
+use pen_and_paper_geometric_mean::trivia_guess::TriviaGuessDistribution;
+
+/// Values spread log-uniformly from 1 to 10^9, the range of trivia answers
+fn values() -> Vec<f64> {
+    (0..10_000).map(|i| 10_f64.powf((i as f64 * 0.618_034) % 9.0)).collect()
+}
+
+/// The rounding as it was before the fast path: the magnitude from `log10`, the closer candidate from `ln`
+fn logarithmic_rounding(raw_value: f64) -> u64 {
+    if raw_value.is_nan() || raw_value <= 1.0 {
+        return 1;
+    }
+    let mut magnitude = raw_value.log10().floor() as i32;
+    if magnitude > 0 && 10_f64.powi(magnitude) > raw_value {
+        magnitude -= 1;
+    }
+    if magnitude > 18 {
+        return u64::MAX;
+    }
+    let power = 10_u64.pow(magnitude as u32);
+    let first_digit = (raw_value / power as f64).floor() as u64;
+    let (base, step_size) = match first_digit {
+        1 => (power, power / 20),
+        2..=4 => (first_digit * power, power / 10),
+        _ => (first_digit * power, power / 2),
+    };
+    if step_size == 0 {
+        return base;
+    }
+    let k = ((raw_value - base as f64).max(0.0) / step_size as f64).floor() as u64;
+    let (low, high) = (base + k * step_size, base + (k + 1) * step_size);
+    let ln_target = raw_value.ln();
+    if (ln_target - (low as f64).ln()).abs() <= (ln_target - (high as f64).ln()).abs() { low } else { high }
+}
+
+fn rounding(c: &mut Criterion) {
+    let values = values();
+    let dist = TriviaGuessDistribution::new(1_000, 0.0).expect("valid distribution");
+    assert!(values.iter().all(|&v| dist.round_to_trivia_value(v) == logarithmic_rounding(v)));
+
+    let mut group = c.benchmark_group("round 10,000 values");
+    group.bench_function("logarithms", |b| {
+        b.iter(|| black_box(&values).iter().map(|&v| logarithmic_rounding(v)).fold(0, u64::wrapping_add))
+    });
+    group.bench_function("comparisons", |b| {
+        b.iter(|| black_box(&values).iter().map(|&v| dist.round_to_trivia_value(v)).fold(0, u64::wrapping_add))
+    });
+    group.finish();
+
+    let guesses = TriviaGuessDistribution::new(2_500, 1.5).expect("valid distribution");
+    c.bench_function("sample 10,000 guesses", |b| {
+        b.iter(|| {
+            let mut rng = StdRng::seed_from_u64(42);
+            (0..10_000).map(|_| guesses.sample(&mut rng)).fold(0, u64::wrapping_add)
+        })
+    });
+}
+
+criterion_group!(benches, rounding);
+criterion_main!(benches);
@@ -0,0 +1,21 @@
+//! Generates the C header for the `ffi` feature's extern "C" API into `OUT_DIR`.
+//!
+//! The build never writes into the source tree, so read-only and vendored builds work; a test keeps the committed copy
+//! in `include/` in step with the generated one.
+
+fn main() {
+    #[cfg(feature = "ffi")]
+    generate_header();
+}
+
+#[cfg(feature = "ffi")]
+fn generate_header() {
+    let crate_dir = std::env::var("CARGO_MANIFEST_DIR").expect("cargo sets CARGO_MANIFEST_DIR");
+    let out_dir = std::env::var("OUT_DIR").expect("cargo sets OUT_DIR");
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+
+    cbindgen::generate(&crate_dir)
+        .expect("failed to generate the C header")
+        .write_to_file(std::path::Path::new(&out_dir).join("pen_and_paper_geometric_mean.h"));
+}
@@ -0,0 +1,24 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use pen_and_paper_geometric_mean::log_linear::{convert_from_log_linear, convert_to_log_linear};
+
+/// Above this, the power of ten for the digit count overflows to infinity
+const LARGEST_CONVERTIBLE: f64 = 1e308;
+
+fuzz_target!(|input: (f64, f64)| {
+    let (value, log_value) = input;
+
+    // Log-linear notation is only used for numbers of at least 1, which the estimator enforces
+    if (1.0..LARGEST_CONVERTIBLE).contains(&value) {
+        let notation = convert_to_log_linear(value);
+        let fractional_part = notation - notation.floor();
+        assert!(fractional_part >= 0.1 - 1e-9, "{} -> {}", value, notation);
+
+        let back = convert_from_log_linear(notation);
+        assert!(((back - value) / value).abs() < 1e-9, "{} -> {} -> {}", value, notation, back);
+    }
+
+    // Any notation converts back without panicking
+    let _ = convert_from_log_linear(log_value);
+});
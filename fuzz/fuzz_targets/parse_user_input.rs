@@ -0,0 +1,11 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use pen_and_paper_geometric_mean::practice_mode::parse_user_input;
+
+fuzz_target!(|input: &str| {
+    if let Ok(value) = parse_user_input(input) {
+        assert!(value > 0, "{:?} -> {}", input, value);
+        assert_eq!(parse_user_input(&value.to_string()), Ok(value), "{:?}", input);
+    }
+});
@@ -0,0 +1,19 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use pen_and_paper_geometric_mean::trivia_guess::TriviaGuessDistribution;
+
+/// Below 2^53, every rounded value converts to f64 exactly, so rounding it again must not move it
+const EXACT_F64_INTEGERS: u64 = 1 << 53;
+
+fuzz_target!(|raw_value: f64| {
+    let distribution = TriviaGuessDistribution::new(1, 0.0).unwrap();
+
+    let rounded = distribution.round_to_trivia_value(raw_value);
+    assert!(rounded >= 1, "{} -> {}", raw_value, rounded);
+
+    if rounded < EXACT_F64_INTEGERS {
+        let again = distribution.round_to_trivia_value(rounded as f64);
+        assert_eq!(again, rounded, "{} -> {} -> {}", raw_value, rounded, again);
+    }
+});
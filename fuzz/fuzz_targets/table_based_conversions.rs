@@ -0,0 +1,31 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use pen_and_paper_geometric_mean::table_based::{log_representation_to_number, number_to_log_representation};
+
+/// Largest ratio between neighbouring table entries (3 to 4, and 6 to 8)
+const WIDEST_TABLE_GAP: f64 = 4.0 / 3.0;
+
+/// Representations of numbers up to 10^19, the range of u64 answers, where the round trip is exact
+const EXACT_ROUND_TRIP_LIMIT: i32 = 200;
+
+fuzz_target!(|input: (f64, u16)| {
+    let (value, scaled_log) = input;
+
+    // The table only covers numbers of at least 1, which the estimator enforces
+    if value.is_finite() && value >= 1.0 {
+        let representation = number_to_log_representation(value);
+        assert!(representation >= 0, "{} -> {}", value, representation);
+
+        // Converting back gives the table entry at or just below the value
+        let number = log_representation_to_number(representation);
+        assert!(number <= value * (1.0 + 1e-12), "{} -> {} -> {}", value, representation, number);
+        assert!(value < number * WIDEST_TABLE_GAP * (1.0 + 1e-12), "{} -> {} -> {}", value, representation, number);
+    }
+
+    let scaled_log = i32::from(scaled_log);
+    let number = log_representation_to_number(scaled_log);
+    if scaled_log < EXACT_ROUND_TRIP_LIMIT {
+        assert_eq!(number_to_log_representation(number), scaled_log, "{} -> {}", scaled_log, number);
+    }
+});
@@ -0,0 +1,89 @@
+#ifndef PEN_AND_PAPER_GEOMETRIC_MEAN_H
+#define PEN_AND_PAPER_GEOMETRIC_MEAN_H
+
+/* Generated by cbindgen from src/ffi.rs; do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Outcome of a call into the C API
+typedef enum GeomeanStatus {
+  GEOMEAN_STATUS_OK = 0,
+  // A required pointer argument was null
+  GEOMEAN_STATUS_NULL_POINTER = 1,
+  // The method name was not valid UTF-8
+  GEOMEAN_STATUS_INVALID_UTF8 = 2,
+  // No estimation method has the given name
+  GEOMEAN_STATUS_UNKNOWN_METHOD = 3,
+  // The values cannot be estimated or converted, e.g. empty, non-positive, or below 1 for a pen-and-paper method
+  GEOMEAN_STATUS_INVALID_INPUT = 4,
+  // The library panicked; this is a bug
+  GEOMEAN_STATUS_PANIC = 5,
+} GeomeanStatus;
+
+// How an answer to a problem was graded, with the same tiers as practice mode
+typedef enum GeomeanGrade {
+  // The answer matches the method's estimate, rounded either way
+  GEOMEAN_GRADE_CORRECT = 0,
+  // The answer is strictly closer to the exact geometric mean than the method's estimate
+  GEOMEAN_GRADE_EXCELLENT = 1,
+  GEOMEAN_GRADE_INCORRECT = 2,
+} GeomeanGrade;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Estimate the geometric mean of `len` values with the named method ("exact", "log-linear", "table-based", or "auto-shortcut")
+//
+// # Safety
+// `method` must be a NUL-terminated string, `values` must point to `len` doubles (or may be null when `len` is 0),
+// and `out` must point to a writable double.
+enum GeomeanStatus geomean_estimate(const char *method,
+                                    const double *values,
+                                    size_t len,
+                                    double *out);
+
+// Grade a user's answer to a problem with the given guesses, solved with the named method
+//
+// # Safety
+// `method` must be a NUL-terminated string, `guesses` must point to `len` doubles (or may be null when `len` is 0),
+// and `out` must point to a writable `GeomeanGrade`.
+enum GeomeanStatus geomean_grade(const char *method,
+                                 const double *guesses,
+                                 size_t len,
+                                 uint64_t user_answer,
+                                 enum GeomeanGrade *out);
+
+// Convert a number to log-linear notation, e.g. 2847 to 4.2847
+//
+// # Safety
+// `out` must point to a writable double.
+enum GeomeanStatus geomean_to_log_linear(double value, double *out);
+
+// Convert log-linear notation back to a number, e.g. 3.75 to 750
+//
+// # Safety
+// `out` must point to a writable double.
+enum GeomeanStatus geomean_from_log_linear(double log_value, double *out);
+
+// Convert a number to the table-based log representation, in tenths, e.g. 2000 to 33 (3.3)
+//
+// # Safety
+// `out` must point to a writable int32_t.
+enum GeomeanStatus geomean_to_table_log(double value, int32_t *out);
+
+// Convert a table-based log representation, in tenths, back to a number, e.g. 36 to 4000
+//
+// # Safety
+// `out` must point to a writable double.
+enum GeomeanStatus geomean_from_table_log(int32_t scaled_log, double *out);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* PEN_AND_PAPER_GEOMETRIC_MEAN_H */
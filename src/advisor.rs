@@ -0,0 +1,210 @@
+//! Recommend the cheapest method that's accurate enough for a team, from precomputed evaluation data.
+//!
+//! Even the exact geometric mean of a team's guesses misses the true answer, by an amount set by how widely the team
+//! guesses and how many guesses there are: the guess-noise floor.
+//! A pen-and-paper method's own error, measured against the exact geometric mean, matters little while it stays well
+//! below that floor, so the advice is the cheapest method that does.
+
+use crate::evaluation::evaluate_on_problems;
+use crate::exact::ExactGeometricMean;
+use crate::log_linear::LogLinearApproximation;
+use crate::practice_mode::{ANSWER_RANGE, PracticeModeConfig};
+use crate::rng;
+use crate::table_based::TableBasedApproximation;
+use crate::traits::{EstimateGeometricMean, MethodMetadata};
+
+/// Named uncertainty levels and their guess spreads, in natural log units
+pub const UNCERTAINTY_LEVELS: [(&str, f64); 4] = [("low", 0.5), ("medium", 1.0), ("high", 1.5), ("extreme", 2.0)];
+
+/// Team sizes the evaluation data covers; larger teams use the largest
+pub const TEAM_SIZES: [usize; 7] = [2, 3, 4, 5, 6, 7, 8];
+
+const EVALUATION_SEED: u64 = 42;
+const EVALUATION_TESTS: usize = 2_000;
+
+/// Mean absolute relative error against the exact geometric mean, by uncertainty level (rows) and team size (columns)
+///
+/// Produced by [`measure_method_error`]; a test checks they are still current.
+const LOG_LINEAR_ERRORS: [[f64; 7]; 4] = [
+    [0.066, 0.087, 0.099, 0.103, 0.104, 0.104, 0.109],
+    [0.143, 0.173, 0.175, 0.180, 0.169, 0.175, 0.166],
+    [0.172, 0.188, 0.185, 0.185, 0.176, 0.180, 0.173],
+    [0.178, 0.194, 0.184, 0.190, 0.178, 0.182, 0.183],
+];
+const TABLE_BASED_ERRORS: [[f64; 7]; 4] = [
+    [0.067, 0.065, 0.064, 0.064, 0.063, 0.065, 0.066],
+    [0.067, 0.065, 0.065, 0.064, 0.064, 0.063, 0.067],
+    [0.070, 0.066, 0.064, 0.064, 0.065, 0.064, 0.064],
+    [0.066, 0.064, 0.063, 0.067, 0.064, 0.065, 0.066],
+];
+
+/// A method the advisor can recommend, cheapest first
+struct Candidate {
+    name: &'static str,
+    cost: &'static str,
+    errors: [[f64; 7]; 4],
+}
+
+const CANDIDATES: [Candidate; 3] = [
+    Candidate {
+        name: LogLinearApproximation::NAME,
+        cost: "needs nothing memorized and is no harder than the arithmetic mean",
+        errors: LOG_LINEAR_ERRORS,
+    },
+    Candidate {
+        name: TableBasedApproximation::NAME,
+        cost: "needs a ten-entry table memorized",
+        errors: TABLE_BASED_ERRORS,
+    },
+    Candidate {
+        name: ExactGeometricMean::NAME,
+        cost: "needs real logarithms, so a calculator or a lot of patience",
+        errors: [[0.0; 7]; 4],
+    },
+];
+
+/// Measure a method's mean absolute relative error on practice-style problems, as stored in the evaluation data
+///
+/// The data describes the methods rather than the generator, so it is measured with [`rng::ReferenceRng`], whichever
+/// backend [`rng::DefaultRng`] is.
+pub fn measure_method_error<E: EstimateGeometricMean>(log_std_dev: f64, team_size: usize) -> f64 {
+    let mut rng = rng::reference(EVALUATION_SEED);
+    let config = PracticeModeConfig::new(team_size, log_std_dev, ANSWER_RANGE.0, ANSWER_RANGE.1)
+        .expect("evaluation team sizes are positive and the answer range is valid");
+    evaluate_on_problems::<_, E>(&mut rng, &config, EVALUATION_TESTS).mean_absolute_relative_error
+}
+
+/// The typical relative error of the team's exact geometric mean against the true answer
+///
+/// The mean of `team_size` guess logs scatters around the answer's log with standard deviation
+/// `log_std_dev / √team_size`, and the average size of that miss is √(2/π) times as large.
+pub fn noise_floor(log_std_dev: f64, team_size: usize) -> f64 {
+    let mean_log_miss = log_std_dev / (team_size as f64).sqrt() * (2.0 / std::f64::consts::PI).sqrt();
+    mean_log_miss.exp() - 1.0
+}
+
+/// Parse an uncertainty level by name, or as a guess spread in natural log units
+pub fn parse_uncertainty(value: &str) -> Result<f64, String> {
+    if let Some(&(_, spread)) = UNCERTAINTY_LEVELS.iter().find(|(name, _)| name.eq_ignore_ascii_case(value)) {
+        return Ok(spread);
+    }
+    match value.parse::<f64>() {
+        Ok(spread) if spread > 0.0 && spread.is_finite() => Ok(spread),
+        _ => {
+            let names: Vec<&str> = UNCERTAINTY_LEVELS.iter().map(|(name, _)| *name).collect();
+            Err(format!("Invalid uncertainty: {} (expected {}, or a positive spread)", value, names.join(", ")))
+        }
+    }
+}
+
+/// A recommended method and why
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Advice {
+    pub method: String,
+    /// Typical relative miss of the team's exact geometric mean
+    pub noise_floor: f64,
+    /// The recommended method's typical relative error against the exact geometric mean
+    pub method_error: f64,
+    pub justification: String,
+}
+
+fn nearest_index<T: Copy>(values: &[T], distance: impl Fn(T) -> f64) -> usize {
+    (0..values.len()).min_by(|&a, &b| distance(values[a]).total_cmp(&distance(values[b]))).unwrap_or(0)
+}
+
+/// Recommend the cheapest method whose error is below the guess-noise floor, using the nearest evaluated team
+/// size and spread
+pub fn advise(team_size: usize, log_std_dev: f64) -> Advice {
+    let floor = noise_floor(log_std_dev, team_size.max(1));
+    if team_size <= 1 {
+        return Advice {
+            method: "none".to_string(),
+            noise_floor: floor,
+            method_error: 0.0,
+            justification: "With a single guess there is nothing to average; submit it as is.".to_string(),
+        };
+    }
+
+    let row = nearest_index(&UNCERTAINTY_LEVELS, |(_, spread)| (spread - log_std_dev).abs());
+    let column = nearest_index(&TEAM_SIZES, |size| size.abs_diff(team_size) as f64);
+    let (index, chosen) = CANDIDATES
+        .iter()
+        .enumerate()
+        .find(|(_, candidate)| candidate.errors[row][column] < floor)
+        .unwrap_or((CANDIDATES.len() - 1, &CANDIDATES[CANDIDATES.len() - 1]));
+    let error = chosen.errors[row][column];
+
+    let percent = |fraction: f64| format!("{:.0}%", fraction * 100.0);
+    let own_error = if error == 0.0 { "no error".to_string() } else { format!("{} of its own, lost in that noise", percent(error)) };
+    let mut justification = format!(
+        "The team's exact geometric mean typically misses the answer by {}, and {} adds {}; it {}.",
+        percent(floor),
+        chosen.name,
+        own_error,
+        chosen.cost
+    );
+    if let Some(cheaper) = index.checked_sub(1).map(|i| &CANDIDATES[i]) {
+        justification.push_str(&format!(
+            " The cheaper {} adds {}, more than that noise.",
+            cheaper.name,
+            percent(cheaper.errors[row][column])
+        ));
+    }
+
+    Advice { method: chosen.name.to_string(), noise_floor: floor, method_error: error, justification }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The data was measured with ChaCha12, so builds without it draw different problems
+    #[cfg(feature = "chacha")]
+    #[test]
+    fn test_evaluation_data_is_current() {
+        for (row, (_, spread)) in UNCERTAINTY_LEVELS.iter().enumerate() {
+            for (column, &team_size) in TEAM_SIZES.iter().enumerate() {
+                let log_linear = measure_method_error::<LogLinearApproximation>(*spread, team_size);
+                let table_based = measure_method_error::<TableBasedApproximation>(*spread, team_size);
+                assert!((log_linear - LOG_LINEAR_ERRORS[row][column]).abs() < 6e-4, "{} {}: {}", spread, team_size, log_linear);
+                assert!((table_based - TABLE_BASED_ERRORS[row][column]).abs() < 6e-4, "{} {}: {}", spread, team_size, table_based);
+            }
+        }
+    }
+
+    #[test]
+    fn test_noise_floor() {
+        assert!((noise_floor(1.0, 1) - ((2.0 / std::f64::consts::PI).sqrt().exp() - 1.0)).abs() < 1e-12);
+        assert!(noise_floor(1.0, 8) < noise_floor(1.0, 2));
+        assert!(noise_floor(2.0, 4) > noise_floor(1.0, 4));
+    }
+
+    #[test]
+    fn test_parse_uncertainty() {
+        assert_eq!(parse_uncertainty("high"), Ok(1.5));
+        assert_eq!(parse_uncertainty("LOW"), Ok(0.5));
+        assert_eq!(parse_uncertainty("0.8"), Ok(0.8));
+        assert!(parse_uncertainty("0").is_err());
+        assert!(parse_uncertainty("huge").is_err());
+    }
+
+    #[test]
+    fn test_advise() {
+        // Wide spreads leave plenty of noise for log-linear's error to hide in
+        let advice = advise(5, 1.5);
+        assert_eq!(advice.method, "log-linear");
+        assert!(advice.justification.starts_with("The team's exact geometric mean typically misses the answer by 71%"));
+
+        // A large team with a tight spread misses by less than log-linear's error, but not the table's
+        let advice = advise(20, 0.5);
+        assert_eq!(advice.method, "table-based");
+        assert!(advice.justification.contains("The cheaper log-linear adds 11%"));
+
+        // A very large, well-informed team's answer is good enough that only the exact mean keeps up
+        let advice = advise(60, 0.5);
+        assert_eq!(advice.method, "exact");
+        assert!(advice.justification.contains("exact adds no error; it needs real logarithms"));
+        assert_eq!(advise(1, 1.0).method, "none");
+    }
+}
@@ -0,0 +1,135 @@
+//! Bayesian aggregation: combine the team's guesses with a prior over the answer's magnitude.
+//!
+//! Trivia guesses scatter log-normally around the true answer (see [`trivia_guess`](crate::trivia_guess)), so in log
+//! space each guess is a noisy measurement of the answer's log.
+//! With a normal prior on that log too, the posterior is normal, and its mean is a precision-weighted average of the
+//! prior mean and the guesses' mean log.
+//! The point estimate is the posterior median of the answer, the exponential of that mean.
+//! With a vague prior it is the geometric mean; with a tight one it pulls the geometric mean toward the typical answer,
+//! most of all for small teams and wide spreads.
+
+/// Errors from combining guesses with a prior
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BayesianError {
+    EmptyInput,
+    NonPositiveValue,
+    InvalidSpread,
+}
+
+impl std::fmt::Display for BayesianError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BayesianError::EmptyInput => write!(f, "Cannot combine empty input"),
+            BayesianError::NonPositiveValue => write!(f, "Guesses must all be positive"),
+            BayesianError::InvalidSpread => write!(f, "Spreads must be finite and non-negative, and the prior's positive"),
+        }
+    }
+}
+
+impl std::error::Error for BayesianError {}
+
+/// A normal distribution over the natural log of a positive quantity
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LogNormal {
+    pub ln_mean: f64,
+    pub ln_std_dev: f64,
+}
+
+impl LogNormal {
+    /// The log-normal prior matching the mean and spread of answers drawn log-uniformly from `min` to `max`
+    pub fn from_range(min: f64, max: f64) -> Result<LogNormal, BayesianError> {
+        if !(min > 0.0 && max > min && max.is_finite()) {
+            return Err(BayesianError::InvalidSpread);
+        }
+        let (ln_min, ln_max) = (min.ln(), max.ln());
+        Ok(LogNormal { ln_mean: (ln_min + ln_max) / 2.0, ln_std_dev: (ln_max - ln_min) / 12.0_f64.sqrt() })
+    }
+
+    /// The median of the quantity, which is also its geometric mean
+    pub fn median(&self) -> f64 {
+        self.ln_mean.exp()
+    }
+}
+
+/// The posterior over the answer's log, given a prior and guesses scattered around the answer with `guess_log_std_dev`
+///
+/// A `guess_log_std_dev` of zero means the guesses are exact, so the prior is ignored.
+pub fn posterior(prior: &LogNormal, guesses: &[f64], guess_log_std_dev: f64) -> Result<LogNormal, BayesianError> {
+    if guesses.is_empty() {
+        return Err(BayesianError::EmptyInput);
+    }
+    if guesses.iter().any(|&g| g <= 0.0) {
+        return Err(BayesianError::NonPositiveValue);
+    }
+    if !(guess_log_std_dev >= 0.0 && guess_log_std_dev.is_finite() && prior.ln_std_dev > 0.0 && prior.ln_std_dev.is_finite()) {
+        return Err(BayesianError::InvalidSpread);
+    }
+
+    let count = guesses.len() as f64;
+    let mean_log = guesses.iter().map(|g| g.ln()).sum::<f64>() / count;
+    if guess_log_std_dev == 0.0 {
+        return Ok(LogNormal { ln_mean: mean_log, ln_std_dev: 0.0 });
+    }
+
+    let prior_precision = prior.ln_std_dev.powi(-2);
+    let guesses_precision = count / guess_log_std_dev.powi(2);
+    let precision = prior_precision + guesses_precision;
+
+    Ok(LogNormal {
+        ln_mean: (prior_precision * prior.ln_mean + guesses_precision * mean_log) / precision,
+        ln_std_dev: precision.sqrt().recip(),
+    })
+}
+
+/// The posterior median of the answer, the Bayesian point estimate
+pub fn bayesian_estimate(prior: &LogNormal, guesses: &[f64], guess_log_std_dev: f64) -> Result<f64, BayesianError> {
+    posterior(prior, guesses, guess_log_std_dev).map(|posterior| posterior.median())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exact::geometric_mean;
+
+    #[test]
+    fn test_from_range() {
+        let prior = LogNormal::from_range(10.0, 1_000.0).unwrap();
+        assert!((prior.median() - 100.0).abs() < 1e-9);
+        assert!((prior.ln_std_dev - 100.0_f64.ln() / 12.0_f64.sqrt()).abs() < 1e-12);
+        assert_eq!(LogNormal::from_range(0.0, 10.0), Err(BayesianError::InvalidSpread));
+        assert_eq!(LogNormal::from_range(10.0, 10.0), Err(BayesianError::InvalidSpread));
+    }
+
+    #[test]
+    fn test_equal_precision_splits_the_difference() {
+        // One guess with the same spread as the prior lands halfway between them in log space
+        let prior = LogNormal { ln_mean: 100.0_f64.ln(), ln_std_dev: 1.0 };
+        let posterior = posterior(&prior, &[10_000.0], 1.0).unwrap();
+        assert!((posterior.median() - 1_000.0).abs() < 1e-6);
+        assert!((posterior.ln_std_dev - 0.5_f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_limits_match_the_geometric_mean() {
+        let guesses = [200.0, 800.0, 5_000.0];
+        let geometric_mean = geometric_mean(&guesses).unwrap();
+
+        let vague = LogNormal { ln_mean: 0.0, ln_std_dev: 1e6 };
+        assert!((bayesian_estimate(&vague, &guesses, 1.0).unwrap() - geometric_mean).abs() < 1e-6);
+        let tight = LogNormal { ln_mean: 0.0, ln_std_dev: 1.0 };
+        assert!((bayesian_estimate(&tight, &guesses, 0.0).unwrap() - geometric_mean).abs() < 1e-6);
+        // A prior centred below the guesses pulls the estimate down
+        assert!(bayesian_estimate(&tight, &guesses, 1.0).unwrap() < geometric_mean);
+    }
+
+    #[test]
+    fn test_errors() {
+        let prior = LogNormal { ln_mean: 0.0, ln_std_dev: 1.0 };
+        assert_eq!(posterior(&prior, &[], 1.0), Err(BayesianError::EmptyInput));
+        assert_eq!(posterior(&prior, &[0.0], 1.0), Err(BayesianError::NonPositiveValue));
+        assert_eq!(posterior(&prior, &[1.0], f64::NAN), Err(BayesianError::InvalidSpread));
+        assert_eq!(posterior(&LogNormal { ln_mean: 0.0, ln_std_dev: 0.0 }, &[1.0], 1.0), Err(BayesianError::InvalidSpread));
+    }
+}
@@ -0,0 +1,145 @@
+use std::time::Duration;
+
+use crate::practice_mode::{AnswerEvaluation, PracticeModeConfig};
+
+/// A named practice difficulty, from gentle to the original expert default
+#[derive(Debug, Clone, PartialEq)]
+pub struct DifficultyLevel {
+    pub name: &'static str,
+    pub team_size: usize,
+    pub log_std_dev: f64,
+    pub min_answer: u64,
+    pub max_answer: u64,
+}
+
+impl DifficultyLevel {
+    pub fn practice_config(&self) -> PracticeModeConfig {
+        PracticeModeConfig::new(self.team_size, self.log_std_dev, self.min_answer, self.max_answer)
+            .expect("difficulty levels are valid configurations")
+    }
+}
+
+/// Difficulty ladder, ordered from easiest to hardest
+pub const DIFFICULTY_LEVELS: [DifficultyLevel; 5] = [
+    DifficultyLevel { name: "Beginner", team_size: 2, log_std_dev: 0.5, min_answer: 10, max_answer: 1_000 },
+    DifficultyLevel { name: "Novice", team_size: 3, log_std_dev: 1.0, min_answer: 10, max_answer: 10_000 },
+    DifficultyLevel { name: "Intermediate", team_size: 4, log_std_dev: 2.0, min_answer: 10, max_answer: 1_000_000 },
+    DifficultyLevel { name: "Expert", team_size: 4, log_std_dev: 4.0, min_answer: 10, max_answer: 1_000_000_000 },
+    DifficultyLevel { name: "Master", team_size: 6, log_std_dev: 4.0, min_answer: 10, max_answer: 1_000_000_000 },
+];
+
+/// Rounds answered slower than this count as a miss, even when correct
+pub const CALIBRATION_TARGET_TIME: Duration = Duration::from_secs(60);
+
+/// Adaptive placement test using a one-up/one-down staircase over `DIFFICULTY_LEVELS`
+///
+/// Each success (Correct or Excellent within the target time) moves up a level, and each miss moves down.
+/// The level reached once all rounds are played is the recommendation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Calibration {
+    level: usize,
+    rounds_remaining: usize,
+}
+
+impl Calibration {
+    /// Start a calibration of `rounds` problems at the middle of the ladder
+    pub fn new(rounds: usize) -> Self {
+        Calibration {
+            level: DIFFICULTY_LEVELS.len() / 2,
+            rounds_remaining: rounds,
+        }
+    }
+
+    /// Difficulty of the next problem to serve
+    pub fn current_level(&self) -> &'static DifficultyLevel {
+        &DIFFICULTY_LEVELS[self.level]
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.rounds_remaining == 0
+    }
+
+    /// Record the outcome of a problem served at the current level
+    pub fn record(&mut self, evaluation: &AnswerEvaluation, duration: Duration) {
+        if self.is_finished() {
+            return;
+        }
+        self.rounds_remaining -= 1;
+
+        let success = evaluation.is_success() && duration <= CALIBRATION_TARGET_TIME;
+        if success {
+            self.level = (self.level + 1).min(DIFFICULTY_LEVELS.len() - 1);
+        } else {
+            self.level = self.level.saturating_sub(1);
+        }
+    }
+
+    /// Recommended difficulty given the rounds recorded so far
+    pub fn recommendation(&self) -> &'static DifficultyLevel {
+        self.current_level()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FAST: Duration = Duration::from_secs(10);
+    const SLOW: Duration = Duration::from_secs(90);
+
+    #[test]
+    fn test_all_levels_are_valid_configurations() {
+        for level in &DIFFICULTY_LEVELS {
+            level.practice_config();
+        }
+    }
+
+    #[test]
+    fn test_starts_in_the_middle() {
+        let calibration = Calibration::new(6);
+        assert_eq!(calibration.current_level().name, "Intermediate");
+        assert!(!calibration.is_finished());
+    }
+
+    #[test]
+    fn test_success_moves_up_and_miss_moves_down() {
+        let mut calibration = Calibration::new(6);
+
+        calibration.record(&AnswerEvaluation::Correct, FAST);
+        assert_eq!(calibration.current_level().name, "Expert");
+
+        calibration.record(&AnswerEvaluation::Incorrect, FAST);
+        assert_eq!(calibration.current_level().name, "Intermediate");
+
+        calibration.record(&AnswerEvaluation::Excellent, SLOW);
+        assert_eq!(calibration.current_level().name, "Novice");
+
+        calibration.record(&AnswerEvaluation::GaveUp, FAST);
+        assert_eq!(calibration.current_level().name, "Beginner");
+    }
+
+    #[test]
+    fn test_levels_are_clamped_to_the_ladder() {
+        let mut calibration = Calibration::new(10);
+        for _ in 0..5 {
+            calibration.record(&AnswerEvaluation::Correct, FAST);
+        }
+        assert_eq!(calibration.recommendation().name, "Master");
+
+        for _ in 0..5 {
+            calibration.record(&AnswerEvaluation::Incorrect, FAST);
+        }
+        assert_eq!(calibration.recommendation().name, "Beginner");
+        assert!(calibration.is_finished());
+    }
+
+    #[test]
+    fn test_records_after_finishing_are_ignored() {
+        let mut calibration = Calibration::new(1);
+        calibration.record(&AnswerEvaluation::Correct, FAST);
+        assert!(calibration.is_finished());
+
+        calibration.record(&AnswerEvaluation::Correct, FAST);
+        assert_eq!(calibration.recommendation().name, "Expert");
+    }
+}
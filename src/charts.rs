@@ -0,0 +1,276 @@
+//! SVG and PNG line charts of evaluation results, for relationships that are hard to read from a table of numbers.
+//!
+//! The data functions reduce per-case [`Sample`]s to points: mean error by the size of the answer, the spread of
+//! signed errors, and mean error by how many values were averaged.
+//! [`LineChart`] draws any of them with plotters as one line per method, with a legend.
+
+use std::ops::Range;
+use std::path::Path;
+
+use plotters::chart::{ChartBuilder, SeriesLabelPosition};
+use plotters::coord::Shift;
+use plotters::coord::combinators::IntoLogRange;
+use plotters::coord::ranged1d::{AsRangedCoord, ValueFormatter};
+use plotters::drawing::{DrawingArea, DrawingAreaErrorKind, IntoDrawingArea};
+use plotters::element::PathElement;
+use plotters::prelude::{BitMapBackend, DrawingBackend, SVGBackend};
+use plotters::series::LineSeries;
+use plotters::style::{BLACK, Color, RGBColor, WHITE};
+
+use crate::evaluation::Sample;
+
+const WIDTH: u32 = 720;
+const HEIGHT: u32 = 440;
+
+/// Line colours, one per series, repeating after the last
+const COLOURS: [RGBColor; 5] =
+    [RGBColor(0x1f, 0x77, 0xb4), RGBColor(0xff, 0x7f, 0x0e), RGBColor(0x2c, 0xa0, 0x2c), RGBColor(0xd6, 0x27, 0x28), RGBColor(0x94, 0x67, 0xbd)];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChartError {
+    /// The backend failed to draw or write the chart, such as a missing font or an unwritable file
+    Render(String),
+}
+
+impl std::fmt::Display for ChartError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChartError::Render(message) => write!(f, "Could not draw the chart: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for ChartError {}
+
+impl<E: std::error::Error + Send + Sync> From<DrawingAreaErrorKind<E>> for ChartError {
+    fn from(error: DrawingAreaErrorKind<E>) -> Self {
+        ChartError::Render(error.to_string())
+    }
+}
+
+/// A named line of points
+#[derive(Debug, Clone, PartialEq)]
+pub struct Series {
+    pub name: String,
+    pub points: Vec<(f64, f64)>,
+}
+
+/// A chart of one or more series sharing axes
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineChart {
+    pub title: String,
+    pub x_label: String,
+    pub y_label: String,
+    /// Space the x axis by powers of ten, for quantities spanning several orders of magnitude
+    pub log_x: bool,
+    pub series: Vec<Series>,
+}
+
+/// The smallest and largest value, widened when they are equal so a flat line still has a range to draw in
+fn bounds(values: impl Iterator<Item = f64>) -> Range<f64> {
+    let (min, max) = values.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| (min.min(v), max.max(v)));
+    match (min, max) {
+        (min, max) if min > max => 0.0..1.0,
+        (min, max) if min == max => min - 1.0..max + 1.0,
+        (min, max) => min..max,
+    }
+}
+
+impl LineChart {
+    /// Points that can be drawn on the chart's axes
+    fn drawable(&self, series: &Series) -> Vec<(f64, f64)> {
+        series.points.iter().copied().filter(|&(x, y)| x.is_finite() && y.is_finite() && (!self.log_x || x > 0.0)).collect()
+    }
+
+    /// Render the chart as a standalone SVG document
+    pub fn to_svg(&self) -> Result<String, ChartError> {
+        let mut svg = String::new();
+        self.draw(SVGBackend::with_string(&mut svg, (WIDTH, HEIGHT)).into_drawing_area())?;
+        Ok(svg)
+    }
+
+    /// Render the chart to a PNG file
+    pub fn save_png(&self, path: &Path) -> Result<(), ChartError> {
+        self.draw(BitMapBackend::new(path, (WIDTH, HEIGHT)).into_drawing_area())
+    }
+
+    fn draw<DB: DrawingBackend>(&self, root: DrawingArea<DB, Shift>) -> Result<(), ChartError> {
+        root.fill(&WHITE)?;
+        let points: Vec<(f64, f64)> = self.series.iter().flat_map(|series| self.drawable(series)).collect();
+        let x_range = bounds(points.iter().map(|&(x, _)| x));
+        // The y axis starts from zero, so errors are seen at their true scale
+        let y_range = bounds(points.iter().map(|&(_, y)| y).chain([0.0]));
+        if self.log_x {
+            let x_range = x_range.start.max(f64::MIN_POSITIVE)..x_range.end;
+            self.draw_on(&root, x_range.log_scale(), y_range)?;
+        } else {
+            self.draw_on(&root, x_range, y_range)?;
+        }
+        root.present()?;
+        Ok(())
+    }
+
+    fn draw_on<DB, X>(&self, root: &DrawingArea<DB, Shift>, x_range: X, y_range: Range<f64>) -> Result<(), ChartError>
+    where
+        DB: DrawingBackend,
+        X: AsRangedCoord<Value = f64>,
+        X::CoordDescType: ValueFormatter<f64>,
+    {
+        let mut chart = ChartBuilder::on(root)
+            .caption(&self.title, ("sans-serif", 18))
+            .margin(12)
+            .x_label_area_size(40)
+            .y_label_area_size(56)
+            .build_cartesian_2d(x_range, y_range)?;
+        chart
+            .configure_mesh()
+            .x_desc(&self.x_label)
+            .y_desc(&self.y_label)
+            .x_label_formatter(&|&x| if x.fract() == 0.0 { format!("{:.0}", x) } else { format!("{}", x) })
+            .draw()?;
+
+        for (i, series) in self.series.iter().enumerate() {
+            let style = COLOURS[i % COLOURS.len()].stroke_width(2);
+            chart
+                .draw_series(LineSeries::new(self.drawable(series), style))?
+                .label(&series.name)
+                .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], style));
+        }
+        chart
+            .configure_series_labels()
+            .position(SeriesLabelPosition::UpperRight)
+            .background_style(WHITE.mix(0.8))
+            .border_style(BLACK)
+            .draw()?;
+        Ok(())
+    }
+}
+
+/// Mean absolute error in percent for answers grouped by size, `bins_per_decade` groups to each power of ten
+///
+/// Points are at each group's geometric centre, for a chart with [`LineChart::log_x`].
+pub fn error_by_magnitude(samples: &[Sample], bins_per_decade: usize) -> Vec<(f64, f64)> {
+    let width = 1.0 / bins_per_decade.max(1) as f64;
+    let mut bins: std::collections::BTreeMap<i64, (f64, usize)> = std::collections::BTreeMap::new();
+    for sample in samples.iter().filter(|sample| sample.exact > 0.0) {
+        let bin = bins.entry((sample.exact.log10() / width).floor() as i64).or_default();
+        bin.0 += sample.relative_error.abs();
+        bin.1 += 1;
+    }
+    bins.into_iter()
+        .map(|(bin, (total, count))| (10_f64.powf((bin as f64 + 0.5) * width), 100.0 * total / count as f64))
+        .collect()
+}
+
+/// The share of samples in percent with each signed error, grouped into bins `bin_width` wide, as a fraction
+///
+/// Points are at each bin's centre, in percent, covering every bin from the lowest error to the highest.
+pub fn error_histogram(samples: &[Sample], bin_width: f64) -> Vec<(f64, f64)> {
+    let bin_of = |sample: &Sample| (sample.relative_error / bin_width).floor() as i64;
+    let (Some(low), Some(high)) = (samples.iter().map(bin_of).min(), samples.iter().map(bin_of).max()) else {
+        return Vec::new();
+    };
+    let mut counts = vec![0usize; (high - low + 1) as usize];
+    for sample in samples {
+        counts[(bin_of(sample) - low) as usize] += 1;
+    }
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(i, count)| (100.0 * (low as f64 + i as f64 + 0.5) * bin_width, 100.0 * count as f64 / samples.len() as f64))
+        .collect()
+}
+
+/// Mean absolute error in percent for each number of values averaged
+pub fn error_by_count(samples: &[Sample]) -> Vec<(f64, f64)> {
+    let mut counts: std::collections::BTreeMap<usize, (f64, usize)> = std::collections::BTreeMap::new();
+    for sample in samples {
+        let entry = counts.entry(sample.count).or_default();
+        entry.0 += sample.relative_error.abs();
+        entry.1 += 1;
+    }
+    counts.into_iter().map(|(count, (total, n))| (count as f64, 100.0 * total / n as f64)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(count: usize, exact: f64, relative_error: f64) -> Sample {
+        Sample { count, exact, relative_error }
+    }
+
+    #[test]
+    fn test_error_by_magnitude() {
+        let samples = [sample(2, 20.0, 0.1), sample(3, 50.0, -0.3), sample(1, 500.0, 0.0)];
+        let points = error_by_magnitude(&samples, 1);
+        assert_eq!(points.len(), 2);
+        assert!((points[0].0 - 10_f64.powf(1.5)).abs() < 1e-9 && (points[0].1 - 20.0).abs() < 1e-9);
+        assert_eq!(points[1].1, 0.0);
+    }
+
+    #[test]
+    fn test_error_histogram() {
+        let samples = [sample(1, 1.0, -0.05), sample(1, 1.0, 0.01), sample(1, 1.0, 0.02), sample(1, 1.0, 0.15)];
+        let points = error_histogram(&samples, 0.1);
+        assert_eq!(points.len(), 3);
+        assert!((points[0].0 + 5.0).abs() < 1e-9);
+        assert_eq!(points.iter().map(|&(_, share)| share).collect::<Vec<_>>(), vec![25.0, 50.0, 25.0]);
+        assert!(error_histogram(&[], 0.1).is_empty());
+    }
+
+    #[test]
+    fn test_error_by_count() {
+        let samples = [sample(2, 1.0, 0.1), sample(2, 1.0, -0.3), sample(5, 1.0, 0.0)];
+        let points = error_by_count(&samples);
+        assert_eq!(points.len(), 2);
+        assert!((points[0].1 - 20.0).abs() < 1e-9);
+        assert_eq!(points[1], (5.0, 0.0));
+    }
+
+    fn chart() -> LineChart {
+        LineChart {
+            title: "Error <by> magnitude".to_string(),
+            x_label: "Exact geometric mean".to_string(),
+            y_label: "Mean error (%)".to_string(),
+            log_x: true,
+            series: vec![
+                Series { name: "log-linear".to_string(), points: vec![(10.0, 15.0), (1_000.0, 18.0)] },
+                Series { name: "table-based".to_string(), points: vec![(10.0, 6.0), (1_000.0, 7.0), (0.0, 5.0)] },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_bounds() {
+        assert_eq!(bounds([3.0, -1.0, 2.0].into_iter()), -1.0..3.0);
+        // A flat line still gets a range to draw in, and no points get a default one
+        assert_eq!(bounds([3.0].into_iter()), 2.0..4.0);
+        assert_eq!(bounds(std::iter::empty()), 0.0..1.0);
+    }
+
+    #[test]
+    fn test_to_svg() {
+        let chart = chart();
+        // A zero can't be placed on a log axis
+        assert_eq!(chart.drawable(&chart.series[1]).len(), 2);
+
+        let svg = chart.to_svg().unwrap();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        // Each line is drawn once on the plot and once in the legend
+        assert_eq!(svg.matches("stroke=\"#1F77B4\" stroke-width=\"2\"").count(), 2);
+        assert_eq!(svg.matches("stroke=\"#FF7F0E\" stroke-width=\"2\"").count(), 2);
+        assert!(svg.contains("Error &lt;by&gt; magnitude"));
+        assert!(svg.contains("\nlog-linear\n</text>") && svg.contains("\ntable-based\n</text>"));
+    }
+
+    #[test]
+    fn test_save_png() {
+        let path = std::env::temp_dir().join(format!("charts-test-{}.png", std::process::id()));
+        chart().save_png(&path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(bytes.starts_with(b"\x89PNG\r\n\x1a\n"));
+    }
+}
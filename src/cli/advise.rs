@@ -0,0 +1,84 @@
+use pen_and_paper_geometric_mean::advisor::{self, Advice};
+
+/// Options for `advise`
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdviseOptions {
+    pub team_size: usize,
+    /// Guess spread in natural log units
+    pub log_std_dev: f64,
+}
+
+impl Default for AdviseOptions {
+    fn default() -> Self {
+        AdviseOptions { team_size: 4, log_std_dev: 1.0 }
+    }
+}
+
+impl AdviseOptions {
+    /// Parse the arguments following `advise`
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut options = AdviseOptions::default();
+
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--team-size" => {
+                    let value = iter.next().ok_or("--team-size requires a number of guesses")?;
+                    options.team_size = value.parse().map_err(|_| format!("Invalid team size: {}", value))?;
+                }
+                "--uncertainty" => {
+                    let value = iter.next().ok_or("--uncertainty requires a level or spread")?;
+                    options.log_std_dev = advisor::parse_uncertainty(value)?;
+                }
+                other => return Err(format!("Unknown advise option: {}", other)),
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+/// Format a recommendation and its justification
+pub fn format_advice(options: &AdviseOptions, advice: &Advice) -> String {
+    format!(
+        "For a team of {} guessing with a spread of {}:\nRecommended method: {}\n\n{}\n",
+        options.team_size, options.log_std_dev, advice.method, advice.justification
+    )
+}
+
+/// Recommend the cheapest method accurate enough for a team's size and uncertainty
+pub fn run_advise(args: &[String]) {
+    match AdviseOptions::parse(args) {
+        Ok(options) => print!("{}", format_advice(&options, &advisor::advise(options.team_size, options.log_std_dev))),
+        Err(e) => println!("{}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_advise_options() {
+        let args = |values: &[&str]| values.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(AdviseOptions::parse(&args(&[])), Ok(AdviseOptions::default()));
+        assert_eq!(
+            AdviseOptions::parse(&args(&["--team-size", "5", "--uncertainty", "high"])),
+            Ok(AdviseOptions { team_size: 5, log_std_dev: 1.5 })
+        );
+        assert_eq!(AdviseOptions::parse(&args(&["--uncertainty", "0.7"])).unwrap().log_std_dev, 0.7);
+        assert!(AdviseOptions::parse(&args(&["--team-size", "five"])).is_err());
+        assert!(AdviseOptions::parse(&args(&["--uncertainty"])).is_err());
+        assert!(AdviseOptions::parse(&args(&["--bogus"])).is_err());
+    }
+
+    #[test]
+    fn test_format_advice() {
+        let options = AdviseOptions { team_size: 5, log_std_dev: 1.5 };
+        let advice = advisor::advise(options.team_size, options.log_std_dev);
+        let output = format_advice(&options, &advice);
+        assert!(output.starts_with("For a team of 5 guessing with a spread of 1.5:\nRecommended method: log-linear\n\n"));
+        assert!(output.ends_with(&format!("{}\n", advice.justification)));
+    }
+}
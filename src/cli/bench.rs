@@ -0,0 +1,211 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::cli::benchmark::{self, Comparison, DEFAULT_THRESHOLD, MEASUREMENT_TIME, Timing};
+
+/// What `bench` does with the suite's timings
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BenchMode {
+    /// Print them
+    Report,
+    /// Store them as the baseline for later comparisons
+    SaveBaseline,
+    /// Compare them against the stored baseline, failing on regressions
+    CompareBaseline,
+}
+
+/// Options for `bench`
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchOptions {
+    pub mode: BenchMode,
+    /// Where the baseline is stored; the user's data directory by default
+    pub baseline: Option<PathBuf>,
+    /// Slowdown beyond which a benchmark counts as regressed, e.g. 0.25 for 25%
+    pub threshold: f64,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions { mode: BenchMode::Report, baseline: None, threshold: DEFAULT_THRESHOLD }
+    }
+}
+
+impl BenchOptions {
+    /// Parse the arguments following `bench`
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut options = BenchOptions::default();
+
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--save-baseline" => options.mode = BenchMode::SaveBaseline,
+                "--compare-baseline" => options.mode = BenchMode::CompareBaseline,
+                "--baseline" => {
+                    let value = iter.next().ok_or("--baseline requires a path")?;
+                    options.baseline = Some(PathBuf::from(value));
+                }
+                "--threshold" => {
+                    let value = iter.next().ok_or("--threshold requires a percentage")?;
+                    options.threshold = value
+                        .trim_end_matches('%')
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|percent| percent.is_finite() && *percent >= 0.0)
+                        .map(|percent| percent / 100.0)
+                        .ok_or_else(|| format!("Invalid threshold: {} (expected a percentage like 25%)", value))?;
+                }
+                other => return Err(format!("Unknown bench option: {}", other)),
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+/// Default location of the baseline, inside the user's data directory, since timings only compare on one machine
+fn default_baseline_path() -> Option<PathBuf> {
+    pen_and_paper_geometric_mean::paths::data_dir().map(|dir| dir.join("bench-baseline.json"))
+}
+
+fn load_baseline(path: &Path) -> Result<Vec<Timing>, String> {
+    let contents = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    serde_json::from_str(&contents).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn save_baseline(path: &Path, timings: &[Timing]) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("{}: {}", parent.display(), e))?;
+    }
+    let contents = serde_json::to_string_pretty(timings).map_err(|e| e.to_string())?;
+    fs::write(path, contents + "\n").map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// One line per benchmark with its median time per iteration
+pub fn format_timings(timings: &[Timing]) -> String {
+    timings.iter().map(|timing| format!("{:<40} {:>12.1?}/iter\n", timing.name, timing.per_iteration)).collect()
+}
+
+/// Each benchmark against its baseline, marking regressions beyond `threshold`, and a summary
+pub fn format_comparison(comparisons: &[Comparison], threshold: f64) -> String {
+    let mut output = format!("{:<40} {:>12} {:>12} {:>8}\n", "Benchmark", "Baseline", "Current", "Change");
+    for comparison in comparisons {
+        let baseline = comparison.baseline.map_or("-".to_string(), |baseline| format!("{:.1?}", baseline));
+        let change = comparison.change().map_or("new".to_string(), |change| format!("{:+.1}%", change * 100.0));
+        let flag = if comparison.regressed(threshold) { "  REGRESSED" } else { "" };
+        output.push_str(&format!(
+            "{:<40} {:>12} {:>12} {:>8}{}\n",
+            comparison.name,
+            baseline,
+            format!("{:.1?}", comparison.current),
+            change,
+            flag
+        ));
+    }
+
+    let regressed = comparisons.iter().filter(|comparison| comparison.regressed(threshold)).count();
+    if regressed == 0 {
+        output.push_str(&format!("\nNo benchmark slowed by more than {:.0}%.\n", threshold * 100.0));
+    } else {
+        output.push_str(&format!(
+            "\n{} of {} benchmarks slowed by more than {:.0}%.\n",
+            regressed,
+            comparisons.len(),
+            threshold * 100.0
+        ));
+    }
+    output
+}
+
+fn bench(options: &BenchOptions) -> Result<bool, String> {
+    let path = match &options.baseline {
+        Some(path) => path.clone(),
+        None => default_baseline_path().ok_or("Could not determine the baseline location")?,
+    };
+
+    // Load first, so a missing baseline fails before the suite runs
+    let baseline = match options.mode {
+        BenchMode::CompareBaseline => Some(load_baseline(&path)?),
+        _ => None,
+    };
+
+    let timings = benchmark::run_suite(MEASUREMENT_TIME);
+    if let Some(baseline) = baseline {
+        let comparisons = benchmark::compare(&baseline, &timings);
+        print!("{}", format_comparison(&comparisons, options.threshold));
+        return Ok(!comparisons.iter().any(|comparison| comparison.regressed(options.threshold)));
+    }
+
+    print!("{}", format_timings(&timings));
+    if options.mode == BenchMode::SaveBaseline {
+        save_baseline(&path, &timings)?;
+        println!("\nSaved baseline to {}", path.display());
+    }
+    Ok(true)
+}
+
+/// Time the benchmark suite, exiting with an error status if comparing finds a regression
+pub fn run_bench(args: &[String]) {
+    let options = match BenchOptions::parse(args) {
+        Ok(options) => options,
+        Err(e) => {
+            println!("{}", e);
+            return;
+        }
+    };
+
+    match bench(&options) {
+        Ok(true) => {}
+        Ok(false) => std::process::exit(1),
+        Err(e) => {
+            println!("Benchmark failed: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_options() {
+        assert_eq!(BenchOptions::parse(&[]).unwrap(), BenchOptions::default());
+
+        let options = BenchOptions::parse(&args(&["--compare-baseline", "--baseline", "ci.json", "--threshold", "10%"])).unwrap();
+        assert_eq!(options.mode, BenchMode::CompareBaseline);
+        assert_eq!(options.baseline, Some(PathBuf::from("ci.json")));
+        assert!((options.threshold - 0.1).abs() < 1e-9);
+
+        assert!(BenchOptions::parse(&args(&["--threshold", "-5"])).is_err());
+        assert!(BenchOptions::parse(&args(&["--baseline"])).is_err());
+        assert!(BenchOptions::parse(&args(&["--fast"])).is_err());
+    }
+
+    #[test]
+    fn test_format_comparison() {
+        let comparisons = vec![
+            Comparison { name: "round".to_string(), baseline: Some(Duration::from_micros(200)), current: Duration::from_micros(300) },
+            Comparison { name: "sample".to_string(), baseline: None, current: Duration::from_micros(100) },
+        ];
+        let report = format_comparison(&comparisons, 0.25);
+        assert!(report.contains("+50.0%  REGRESSED\n"), "{}", report);
+        assert!(report.contains("new\n"), "{}", report);
+        assert!(report.ends_with("\n1 of 2 benchmarks slowed by more than 25%.\n"));
+        assert!(format_comparison(&comparisons[1..], 0.25).ends_with("\nNo benchmark slowed by more than 25%.\n"));
+    }
+
+    #[test]
+    fn test_baseline_round_trips() {
+        let path = std::env::temp_dir().join(format!("bench-baseline-{}.json", std::process::id()));
+        let timings = vec![Timing { name: "round".to_string(), per_iteration: Duration::from_nanos(123_456) }];
+        save_baseline(&path, &timings).unwrap();
+        assert_eq!(load_baseline(&path).unwrap(), timings);
+        fs::remove_file(&path).unwrap();
+        assert!(load_baseline(&path).is_err());
+    }
+}
@@ -0,0 +1,186 @@
+//! A fixed benchmark suite over the hot paths, and comparison against stored baseline timings to catch regressions.
+//!
+//! The suite times the main estimators, the exact geometric mean of one long input, trivia value rounding and guess
+//! sampling, and the evaluation harness, each on inputs drawn from a fixed seed.
+//! Timings are the median time per iteration over a measurement window, which is steadier than the mean against
+//! scheduling noise.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use rand::distributions::Distribution;
+use serde::{Deserialize, Serialize};
+
+use pen_and_paper_geometric_mean::evaluation::{evaluate_estimate, random_corpus};
+use pen_and_paper_geometric_mean::exact::{ExactGeometricMean, geometric_mean};
+use pen_and_paper_geometric_mean::log_linear::LogLinearApproximation;
+use pen_and_paper_geometric_mean::rng;
+use pen_and_paper_geometric_mean::table_based::TableBasedApproximation;
+use pen_and_paper_geometric_mean::traits::{EstimateGeometricMean, MethodMetadata};
+use pen_and_paper_geometric_mean::trivia_guess::TriviaGuessDistribution;
+
+/// How long each benchmark is measured for by default, after warming up for a tenth of this
+pub const MEASUREMENT_TIME: Duration = Duration::from_millis(500);
+
+/// Batches per benchmark, whose median is reported
+const BATCHES: u32 = 25;
+
+/// Slowdown beyond which a benchmark counts as regressed, e.g. 0.25 for 25% slower than its baseline
+pub const DEFAULT_THRESHOLD: f64 = 0.25;
+
+/// Work done by each iteration of the suite's benchmarks, and the seed their inputs are drawn from
+const SUITE_SIZE: usize = 10_000;
+const SUITE_SEED: u64 = 42;
+
+/// Values in the suite's one long input, as large evaluation corpora and simulations reach
+const LONG_INPUT_SIZE: usize = 1_000_000;
+
+/// The median time per iteration of one benchmark
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Timing {
+    pub name: String,
+    pub per_iteration: Duration,
+}
+
+/// Median time per iteration of `f`, measured over `window`
+pub fn measure_for<T>(window: Duration, mut f: impl FnMut() -> T) -> Duration {
+    // Warm up, and find how many iterations fill a batch
+    let warm_up = Instant::now();
+    let mut iterations: u32 = 0;
+    while warm_up.elapsed() < window / 10 {
+        black_box(f());
+        iterations += 1;
+    }
+    let per_batch = (iterations * 10 / BATCHES).max(1);
+
+    let mut times: Vec<Duration> = (0..BATCHES)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..per_batch {
+                black_box(f());
+            }
+            start.elapsed() / per_batch
+        })
+        .collect();
+    times.sort();
+    times[times.len() / 2]
+}
+
+fn estimate_corpus<E: EstimateGeometricMean>(corpus: &[Vec<f64>]) -> f64 {
+    corpus.iter().filter_map(|values| E::estimate_geometric_mean(black_box(values)).ok()).sum()
+}
+
+fn estimate_name<E: MethodMetadata>() -> String {
+    format!("estimate {} cases ({})", SUITE_SIZE, E::NAME)
+}
+
+/// Time every benchmark in the suite, in a fixed order, measuring each over `window`
+pub fn run_suite(window: Duration) -> Vec<Timing> {
+    let corpus = random_corpus(&mut rng::seeded(SUITE_SEED), 1.0, 100_000.0, SUITE_SIZE);
+    let raw_values: Vec<f64> = corpus.iter().flatten().copied().take(SUITE_SIZE).collect();
+    let long_input: Vec<f64> = corpus.iter().flatten().copied().cycle().take(LONG_INPUT_SIZE).collect();
+    let guesses = TriviaGuessDistribution::new(2_500, 1.5).expect("suite distribution is valid");
+
+    let mut timings = Vec::new();
+    let mut time = |name: String, per_iteration: Duration| timings.push(Timing { name, per_iteration });
+
+    time(estimate_name::<ExactGeometricMean>(), measure_for(window, || estimate_corpus::<ExactGeometricMean>(&corpus)));
+    time(estimate_name::<LogLinearApproximation>(), measure_for(window, || estimate_corpus::<LogLinearApproximation>(&corpus)));
+    time(estimate_name::<TableBasedApproximation>(), measure_for(window, || estimate_corpus::<TableBasedApproximation>(&corpus)));
+    time(
+        format!("exact geometric mean of {} values", LONG_INPUT_SIZE),
+        measure_for(window, || geometric_mean(black_box(&long_input))),
+    );
+
+    time(
+        format!("round {} trivia values", SUITE_SIZE),
+        measure_for(window, || raw_values.iter().map(|&v| guesses.round_to_trivia_value(black_box(v))).fold(0, u64::wrapping_add)),
+    );
+    time(
+        format!("sample {} trivia guesses", SUITE_SIZE),
+        measure_for(window, || {
+            let mut rng = rng::seeded(SUITE_SEED);
+            (0..SUITE_SIZE).map(|_| guesses.sample(&mut rng)).fold(0, u64::wrapping_add)
+        }),
+    );
+
+    time(
+        format!("evaluate {} cases ({})", SUITE_SIZE, TableBasedApproximation::NAME),
+        measure_for(window, || {
+            evaluate_estimate::<_, TableBasedApproximation>(&mut rng::seeded(SUITE_SEED), 1.0, 100_000.0, SUITE_SIZE)
+        }),
+    );
+
+    timings
+}
+
+/// A benchmark's current timing against its baseline
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub name: String,
+    /// `None` for a benchmark added since the baseline was stored
+    pub baseline: Option<Duration>,
+    pub current: Duration,
+}
+
+impl Comparison {
+    /// Relative change from the baseline, positive when slower, e.g. 0.1 for 10% slower
+    pub fn change(&self) -> Option<f64> {
+        self.baseline.map(|baseline| self.current.as_secs_f64() / baseline.as_secs_f64() - 1.0)
+    }
+
+    /// Whether this benchmark slowed by more than `threshold`
+    pub fn regressed(&self, threshold: f64) -> bool {
+        self.change().is_some_and(|change| change > threshold)
+    }
+}
+
+/// Pair each current timing with the baseline timing of the same name
+pub fn compare(baseline: &[Timing], current: &[Timing]) -> Vec<Comparison> {
+    current
+        .iter()
+        .map(|timing| Comparison {
+            name: timing.name.clone(),
+            baseline: baseline.iter().find(|b| b.name == timing.name).map(|b| b.per_iteration),
+            current: timing.per_iteration,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(name: &str, micros: u64) -> Timing {
+        Timing { name: name.to_string(), per_iteration: Duration::from_micros(micros) }
+    }
+
+    #[test]
+    fn test_suite_times_every_hot_path() {
+        let timings = run_suite(Duration::from_millis(1));
+        let names: Vec<&str> = timings.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, [
+            "estimate 10000 cases (exact)",
+            "estimate 10000 cases (log-linear)",
+            "estimate 10000 cases (table-based)",
+            "exact geometric mean of 1000000 values",
+            "round 10000 trivia values",
+            "sample 10000 trivia guesses",
+            "evaluate 10000 cases (table-based)",
+        ]);
+        assert!(timings.iter().all(|t| t.per_iteration > Duration::ZERO));
+    }
+
+    #[test]
+    fn test_comparison_flags_regressions_beyond_threshold() {
+        let baseline = [timing("rounding", 100), timing("evaluation", 100)];
+        let current = [timing("rounding", 130), timing("evaluation", 110), timing("new", 50)];
+        let comparisons = compare(&baseline, &current);
+
+        assert!((comparisons[0].change().unwrap() - 0.3).abs() < 1e-9);
+        assert!(comparisons[0].regressed(DEFAULT_THRESHOLD));
+        assert!(!comparisons[1].regressed(DEFAULT_THRESHOLD));
+        assert_eq!(comparisons[2].baseline, None);
+        assert!(!comparisons[2].regressed(DEFAULT_THRESHOLD));
+    }
+}
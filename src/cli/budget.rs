@@ -0,0 +1,156 @@
+use pen_and_paper_geometric_mean::advisor::parse_uncertainty;
+use pen_and_paper_geometric_mean::error_budget::{self, BudgetLine, Verdict};
+use pen_and_paper_geometric_mean::practice_mode::{ANSWER_RANGE, PracticeModeConfig};
+
+/// Options for `budget`
+#[derive(Debug, Clone, PartialEq)]
+pub struct BudgetOptions {
+    /// Largest acceptable relative error against the exact geometric mean, e.g. 0.25 for 25%
+    pub target: f64,
+    pub team_size: usize,
+    pub log_std_dev: f64,
+}
+
+impl Default for BudgetOptions {
+    fn default() -> Self {
+        BudgetOptions { target: 0.25, team_size: 4, log_std_dev: 1.0 }
+    }
+}
+
+impl BudgetOptions {
+    /// Parse the arguments following `budget`
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut options = BudgetOptions::default();
+
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--target" => {
+                    let value = iter.next().ok_or("--target requires a percentage")?;
+                    options.target = value
+                        .trim_end_matches('%')
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|percent| percent.is_finite() && *percent >= 0.0)
+                        .map(|percent| percent / 100.0)
+                        .ok_or_else(|| format!("Invalid target: {} (expected a percentage like 25%)", value))?;
+                }
+                "--team-size" => {
+                    let value = iter.next().ok_or("--team-size requires a number of guesses")?;
+                    options.team_size = value.parse().map_err(|_| format!("Invalid team size: {}", value))?;
+                }
+                "--uncertainty" => {
+                    let value = iter.next().ok_or("--uncertainty requires a level or spread")?;
+                    options.log_std_dev = parse_uncertainty(value)?;
+                }
+                other => return Err(format!("Unknown budget option: {}", other)),
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+fn describe_verdict(verdict: Verdict) -> &'static str {
+    match verdict {
+        Verdict::Always => "always",
+        Verdict::Typically => "typically",
+        Verdict::No => "no",
+    }
+}
+
+/// Format each procedure's bound, empirical errors, and verdict, and the cheapest that meets the target
+pub fn format_budget(options: &BudgetOptions, lines: &[BudgetLine]) -> String {
+    let mut output = String::new();
+    output.push_str("Error Budget\n");
+    output.push_str("============\n");
+    output.push_str(&format!(
+        "Within {:.0}% of the exact geometric mean, for teams of {} guessing with a spread of {}\n\n",
+        options.target * 100.0,
+        options.team_size,
+        options.log_std_dev
+    ));
+
+    let names: Vec<String> = lines.iter().map(|line| line.procedure.name()).collect();
+    let name_width = names.iter().map(String::len).chain(["Procedure".len()]).max().unwrap_or(0);
+    output.push_str(&format!("{:<name_width$}  {:>10}  {:>6}  {:>6}  {:>12}\n", "Procedure", "Worst case", "Mean", "95th", "Meets target"));
+    for (line, name) in lines.iter().zip(&names) {
+        output.push_str(&format!(
+            "{:<name_width$}  {:>9.1}%  {:>5.1}%  {:>5.1}%  {:>12}\n",
+            name,
+            line.bound * 100.0,
+            line.mean_error * 100.0,
+            line.percentile_95 * 100.0,
+            describe_verdict(line.verdict(options.target))
+        ));
+    }
+    output.push('\n');
+
+    match error_budget::cheapest_meeting(lines, options.target) {
+        Some((line, Verdict::Always)) => output.push_str(&format!(
+            "Cheapest: {}, whose worst case is within the target.\n",
+            line.procedure.name()
+        )),
+        Some((line, _)) => output.push_str(&format!(
+            "Cheapest: {}, which lands within the target at least 95% of the time, though its worst case doesn't.\n",
+            line.procedure.name()
+        )),
+        None => output.push_str("Nothing meets the target.\n"),
+    }
+    output.push_str(&format!(
+        "Worst cases are analytical bounds; means and 95th percentiles come from {} simulated teams.\n",
+        error_budget::EMPIRICAL_TESTS
+    ));
+
+    output
+}
+
+/// Report which table granularity and method meet a target error for a team
+pub fn run_budget(args: &[String]) {
+    let options = match BudgetOptions::parse(args) {
+        Ok(options) => options,
+        Err(e) => {
+            println!("{}", e);
+            return;
+        }
+    };
+
+    let lines = PracticeModeConfig::new(options.team_size, options.log_std_dev, ANSWER_RANGE.0, ANSWER_RANGE.1)
+        .and_then(|config| error_budget::error_budget(&config));
+    match lines {
+        Ok(lines) => print!("{}", format_budget(&options, &lines)),
+        Err(e) => println!("{}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_budget_options() {
+        let args = |values: &[&str]| values.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(BudgetOptions::parse(&args(&[])), Ok(BudgetOptions::default()));
+        assert_eq!(
+            BudgetOptions::parse(&args(&["--target", "10%", "--team-size", "6", "--uncertainty", "low"])),
+            Ok(BudgetOptions { target: 0.1, team_size: 6, log_std_dev: 0.5 })
+        );
+        assert_eq!(BudgetOptions::parse(&args(&["--target", "40"])).unwrap().target, 0.4);
+        assert!(BudgetOptions::parse(&args(&["--target", "-5%"])).is_err());
+        assert!(BudgetOptions::parse(&args(&["--target"])).is_err());
+        assert!(BudgetOptions::parse(&args(&["--bogus"])).is_err());
+    }
+
+    #[test]
+    fn test_format_budget() {
+        let options = BudgetOptions::default();
+        let config = PracticeModeConfig::new(options.team_size, options.log_std_dev, ANSWER_RANGE.0, ANSWER_RANGE.1).unwrap();
+        let output = format_budget(&options, &error_budget::error_budget(&config).unwrap());
+
+        assert!(output.starts_with("Error Budget\n============\nWithin 25% of the exact geometric mean, for teams of 4 guessing with a spread of 1\n\n"));
+        assert!(output.contains("\nexact                               0.0%    0.0%    0.0%        always\n"), "{}", output);
+        assert!(output.contains("Cheapest: 10-entry table (table-based), which lands within the target at least 95% of the time"));
+        assert!(format_budget(&options, &[]).contains("Nothing meets the target.\n"));
+    }
+}
@@ -0,0 +1,91 @@
+use pen_and_paper_geometric_mean::calibration::Calibration;
+use crate::cli::transcript::Transcript;
+use crate::cli::duration::DurationFormat;
+use crate::cli::practice_mode::{format_problem_display, format_results_display, play_round, record_history};
+use pen_and_paper_geometric_mean::config;
+use pen_and_paper_geometric_mean::history::{self, SessionContext};
+use pen_and_paper_geometric_mean::practice_mode::{AnswerEvaluation, PracticeSession, Ready, SystemTimer};
+use pen_and_paper_geometric_mean::rng;
+use pen_and_paper_geometric_mean::table_based::TableBasedApproximation;
+use std::time::Duration;
+
+/// Number of problems in a placement test
+const CALIBRATION_ROUNDS: usize = 6;
+
+/// Run the adaptive placement test and save the recommended difficulty to the config file
+pub fn run_calibrate() {
+    println!("Calibration - Table-Based Geometric Mean");
+    println!("========================================");
+    println!();
+    println!("Answer {} problems; difficulty adapts to how quickly and accurately you solve them.", CALIBRATION_ROUNDS);
+    println!();
+
+    let mut calibration = Calibration::new(CALIBRATION_ROUNDS);
+    let mut rng = rng::from_entropy();
+    let timer = SystemTimer;
+    let mut round = 1;
+    let mut transcript = Transcript::disabled();
+    let started = history::unix_timestamp_now();
+    let user_config = config::load_default().unwrap_or_default();
+    let keep_history = user_config.history;
+    let time_format = DurationFormat::new(user_config.time_precision).unwrap_or_default();
+
+    while !calibration.is_finished() {
+        let level = calibration.current_level();
+        println!("Problem {} of {} ({})", round, CALIBRATION_ROUNDS, level.name);
+
+        let session: PracticeSession<Ready, _, _, TableBasedApproximation> =
+            PracticeSession::new(&mut rng, timer);
+        let practice_config = level.practice_config();
+        let history_session = SessionContext::new(started, &practice_config);
+        let (guesses, active_session) = match session.start(practice_config) {
+            Ok(result) => result,
+            Err(e) => {
+                println!("Error generating problem: {}", e);
+                return;
+            }
+        };
+
+        print!("{}", format_problem_display(&guesses));
+        println!();
+
+        let result = play_round(&mut transcript, active_session, None);
+        println!();
+        round += 1;
+
+        // Skipping a placement problem counts as a miss
+        let Some(result) = result else {
+            calibration.record(&AnswerEvaluation::GaveUp, Duration::ZERO);
+            continue;
+        };
+        if keep_history {
+            record_history(&result, &history_session);
+        }
+        calibration.record(&result.evaluation, result.duration);
+
+        print!("{}", format_results_display(&result, &time_format));
+        println!();
+    }
+
+    let recommended = calibration.recommendation();
+    println!("Recommended difficulty: {}", recommended.name);
+    println!(
+        "  Team size: {}, log standard deviation: {}, answers from {} to {}",
+        recommended.team_size, recommended.log_std_dev, recommended.min_answer, recommended.max_answer
+    );
+
+    let Some(path) = config::default_config_path() else {
+        println!("Could not determine the config file location; settings were not saved.");
+        return;
+    };
+
+    let saved = config::load(&path).and_then(|mut user_config| {
+        user_config.set_practice_config(recommended.name, &recommended.practice_config());
+        config::save(&path, &user_config)
+    });
+
+    match saved {
+        Ok(()) => println!("Saved to {}", path.display()),
+        Err(e) => println!("Error saving configuration: {}", e),
+    }
+}
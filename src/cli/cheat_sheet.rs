@@ -0,0 +1,115 @@
+use std::path::PathBuf;
+
+use pen_and_paper_geometric_mean::log_linear::{convert_from_log_linear, convert_to_log_linear};
+use pen_and_paper_geometric_mean::table_based::{MULTIPLIERS, log_representation_to_number, number_to_log_representation};
+
+use super::practice_mode::format_number;
+use super::worksheet::save_pdf;
+
+/// Format a log to at most two decimal places, without trailing zeros
+fn format_log(value: f64) -> String {
+    let formatted = format!("{:.2}", value);
+    formatted.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// Format a run of worked conversions on one indented line, e.g. "  2,000 → 3.3    50 → 1.7"
+fn format_examples(examples: impl IntoIterator<Item = (String, String)>) -> String {
+    let examples: Vec<String> = examples.into_iter().map(|(from, to)| format!("{} → {}", from, to)).collect();
+    format!("  {}\n", examples.join("    "))
+}
+
+/// A printable reference card for the log-linear method, with worked conversions
+pub fn format_log_linear_cheat_sheet() -> String {
+    let mut output = String::new();
+    output.push_str("Log-Linear Cheat Sheet\n");
+    output.push_str("======================\n");
+    output.push_str("To a log: the digit count, then the digits after the point.\n");
+    output.push_str(&format_examples(
+        [300, 10000, 900, 70].map(|n| (format_number(n), format_log(convert_to_log_linear(n as f64)))),
+    ));
+    output.push_str("Average the logs.\n");
+    output.push_str("From a log: the whole part is the digit count, and the decimals are the leading digits.\n");
+    output.push_str(&format_examples(
+        [3.75, 2.43].map(|log| (format_log(log), format_number(convert_from_log_linear(log).round() as u64))),
+    ));
+    output.push_str("If the decimals are below .1, use .1 instead.\n");
+    output.push_str(&format_examples(
+        [3.05].map(|log| (format_log(log), format_number(convert_from_log_linear(log).round() as u64))),
+    ));
+    output
+}
+
+/// A printable reference card for the table-based method: the memorized table, with worked conversions
+pub fn format_table_based_cheat_sheet() -> String {
+    let mut output = String::new();
+    output.push_str("Table-Based Cheat Sheet\n");
+    output.push_str("=======================\n");
+    output.push_str("log  starts with\n");
+    output.push_str("---  -----------\n");
+    for (tenths, multiplier) in MULTIPLIERS.iter().enumerate() {
+        output.push_str(&format!("0.{}  {}\n", tenths, multiplier));
+    }
+    output.push('\n');
+    output.push_str("To a log: count the zeros, then find the last row the number starts at or above.\n");
+    output.push_str(&format_examples([2000, 50, 350, 9001].map(|n| {
+        (format_number(n), format_log(number_to_log_representation(n as f64) as f64 / 10.0))
+    })));
+    output.push_str("Average the logs, rounding up to the next tenth.\n");
+    output.push_str("From a log: the whole part is the number of zeros, and the tenths give the row.\n");
+    output.push_str(&format_examples([36, 28, 44].map(|scaled_log| {
+        (format_log(scaled_log as f64 / 10.0), format_number(log_representation_to_number(scaled_log).round() as u64))
+    })));
+    output
+}
+
+/// Print the cheat sheet for one method, or both when none is named, or write it to a PDF with `--pdf <path>`
+pub fn run_cheat_sheet(args: &[String]) {
+    let (pdf, args) = match args.iter().position(|arg| arg == "--pdf") {
+        Some(index) => match args.get(index + 1) {
+            Some(path) => (Some(PathBuf::from(path)), [&args[..index], &args[index + 2..]].concat()),
+            None => {
+                println!("--pdf requires a file path");
+                return;
+            }
+        },
+        None => (None, args.to_vec()),
+    };
+
+    let sheets = match args.as_slice() {
+        [] => vec![format_log_linear_cheat_sheet(), format_table_based_cheat_sheet()],
+        [method] if method == "log-linear" => vec![format_log_linear_cheat_sheet()],
+        [method] if method == "table-based" => vec![format_table_based_cheat_sheet()],
+        _ => {
+            println!("Usage: cargo run cheat-sheet [log-linear|table-based] [--pdf <path>]");
+            return;
+        }
+    };
+
+    match pdf {
+        Some(path) => match save_pdf(&path, &sheets) {
+            Ok(()) => println!("Wrote {} cheat sheet(s) to {}", sheets.len(), path.display()),
+            Err(e) => println!("{}", e),
+        },
+        None => print!("{}", sheets.join("\n")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_log() {
+        assert_eq!(format_log(3.3000000000000003), "3.3");
+        assert_eq!(format_log(3.0), "3");
+        assert_eq!(format_log(2.43), "2.43");
+    }
+
+    #[test]
+    fn test_table_based_cheat_sheet_matches_readme() {
+        let sheet = format_table_based_cheat_sheet();
+        assert!(sheet.contains("0.1  1.25\n"));
+        assert!(sheet.contains("2,000 → 3.3    50 → 1.7    350 → 2.5    9,001 → 3.9"));
+        assert!(sheet.contains("3.6 → 4,000    2.8 → 600    4.4 → 25,000"));
+    }
+}
@@ -0,0 +1,601 @@
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use serde::Serialize;
+
+use pen_and_paper_geometric_mean::evaluation::{
+    ErrorTotals, Results, Sample, evaluate_corpus, for_each_corpus_case, random_corpus, sample_corpus,
+};
+use pen_and_paper_geometric_mean::exact::ExactGeometricMean;
+use pen_and_paper_geometric_mean::log_linear::LogLinearApproximation;
+use pen_and_paper_geometric_mean::rng;
+use pen_and_paper_geometric_mean::shortcuts::{AutoShortcut, SHORTCUT_SIZES};
+use pen_and_paper_geometric_mean::table_based::TableBasedApproximation;
+use pen_and_paper_geometric_mean::traits::{EstimateGeometricMean, MethodMetadata};
+
+use super::schema::{COMPARISON_SCHEMA_VERSION, Versioned};
+use super::sparkline::mini_histogram;
+
+/// Seed shared by every method, so each is evaluated against the same test cases
+const SEED: u64 = 42;
+const NUM_TESTS: usize = 10000;
+const MIN_VALUE: f64 = 1.0;
+const MAX_VALUE: f64 = 100000.0;
+
+/// Width assumed when the terminal width cannot be determined
+pub const DEFAULT_WIDTH: usize = 80;
+
+/// Space between table columns
+const COLUMN_GAP: &str = "  ";
+
+/// Evaluation results for a single estimation method
+pub struct MethodComparison {
+    pub name: &'static str,
+    pub results: Results,
+}
+
+/// A method registered for the comparison, with how to evaluate and sample it on the shared test cases
+struct ComparedMethod {
+    name: &'static str,
+    evaluate: fn(&[Vec<f64>]) -> Results,
+    sample: fn(&[Vec<f64>]) -> Vec<Sample>,
+    /// Score one streamed test case
+    add: fn(&mut ErrorTotals, &[f64]),
+}
+
+fn compared<E: EstimateGeometricMean + MethodMetadata>() -> ComparedMethod {
+    ComparedMethod { name: E::NAME, evaluate: evaluate_corpus::<E>, sample: sample_corpus::<E>, add: ErrorTotals::add::<E> }
+}
+
+/// Every method included in the comparison, in column order
+fn compared_methods() -> [ComparedMethod; 3] {
+    [compared::<ExactGeometricMean>(), compared::<LogLinearApproximation>(), compared::<TableBasedApproximation>()]
+}
+
+/// The random test cases every method is evaluated against, generated once so each sees identical inputs
+///
+/// Drawn with `rng::ReferenceRng` rather than `rng::DefaultRng`, so the report is the same in every build with the
+/// `chacha` feature, whichever backend seeds problems.
+fn shared_corpus() -> Vec<Vec<f64>> {
+    random_corpus(&mut rng::reference(SEED), MIN_VALUE, MAX_VALUE, NUM_TESTS)
+}
+
+/// Run `f` for every method on the corpus, each on its own thread, returning the outputs in column order
+///
+/// The corpus is only read, so the outputs are the same as running the methods one after another.
+fn for_each_method<T: Send>(corpus: &[Vec<f64>], f: impl Fn(&ComparedMethod, &[Vec<f64>]) -> T + Sync) -> Vec<T> {
+    let methods = compared_methods();
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = methods.iter().map(|method| scope.spawn(|| f(method, corpus))).collect();
+        handles.into_iter().map(|handle| handle.join().expect("method evaluation panicked")).collect()
+    })
+}
+
+/// Every method's results on the corpus, in column order
+fn evaluate_all_methods(corpus: &[Vec<f64>]) -> Vec<MethodComparison> {
+    for_each_method(corpus, |method, corpus| MethodComparison { name: method.name, results: (method.evaluate)(corpus) })
+}
+
+/// Every method's results on a corpus file, and how many test cases it held
+///
+/// The file is streamed once, with every method scoring each line as it's read, so it never has to fit in memory.
+fn evaluate_corpus_file(path: &Path) -> Result<(usize, Vec<MethodComparison>), String> {
+    let file = File::open(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let methods = compared_methods();
+    let mut totals: Vec<ErrorTotals> = methods.iter().map(|_| ErrorTotals::default()).collect();
+    let cases = for_each_corpus_case(BufReader::new(file), |test_values| {
+        for (method, totals) in methods.iter().zip(&mut totals) {
+            (method.add)(totals, test_values);
+        }
+    })
+    .map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    let results = methods.iter().zip(totals).map(|(method, totals)| MethodComparison { name: method.name, results: totals.results() });
+    Ok((cases, results.collect()))
+}
+
+/// Width of the terminal, queried from the terminal itself, or taken from `COLUMNS` when output isn't a terminal
+fn terminal_width() -> usize {
+    terminal_size::terminal_size()
+        .map(|(terminal_size::Width(width), _)| width as usize)
+        .filter(|&width| width > 0)
+        .or_else(|| std::env::var("COLUMNS").ok().and_then(|columns| columns.trim().parse().ok()).filter(|&width| width > 0))
+        .unwrap_or(DEFAULT_WIDTH)
+}
+
+/// Rank of each method by mean absolute relative error, where 1 is the most accurate
+fn accuracy_ranks(methods: &[MethodComparison]) -> Vec<usize> {
+    methods
+        .iter()
+        .map(|method| {
+            let error = method.results.mean_absolute_relative_error;
+            1 + methods.iter().filter(|other| other.results.mean_absolute_relative_error < error).count()
+        })
+        .collect()
+}
+
+/// Metric rows of the table: a label, followed by one cell per method
+fn metric_rows(methods: &[MethodComparison]) -> Vec<(&'static str, Vec<String>)> {
+    let cells = |metric: fn(&Results) -> String| methods.iter().map(|m| metric(&m.results)).collect();
+    let ranks = accuracy_ranks(methods);
+
+    vec![
+        ("Mean Absolute Relative Error", cells(|r| format!("{:.6e}", r.mean_absolute_relative_error))),
+        ("Worst Case Error", cells(|r| format!("{:.6e}", r.worst_case_error))),
+        ("Worst Case Overestimate", cells(|r| format!("{:.6e}", r.worst_case_overestimate))),
+        ("Overall Bias", cells(|r| format!("{:.6e}", r.overall_bias))),
+        ("Valid Tests", cells(|r| r.total_tests.to_string())),
+        ("Accuracy Rank", ranks.iter().map(|rank| rank.to_string()).collect()),
+    ]
+}
+
+/// Render the comparison as a table with methods as columns and metrics as rows
+///
+/// When every method does not fit within `width`, the methods are split across several stacked tables,
+/// each repeating the metric labels. A table always holds at least one method, however narrow the terminal.
+pub fn format_comparison_table(methods: &[MethodComparison], width: usize) -> String {
+    let rows = metric_rows(methods);
+    let label_width = rows.iter().map(|(label, _)| label.len()).max().unwrap_or(0);
+    let column_widths: Vec<usize> = methods
+        .iter()
+        .enumerate()
+        .map(|(i, method)| rows.iter().map(|(_, cells)| cells[i].len()).chain([method.name.len()]).max().unwrap())
+        .collect();
+
+    // Greedily pack method columns into groups that fit the available width
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+    let mut line_width = label_width;
+    for (i, column_width) in column_widths.iter().enumerate() {
+        let added = COLUMN_GAP.len() + column_width;
+        match groups.last_mut() {
+            Some(group) if line_width + added <= width => group.push(i),
+            _ => {
+                groups.push(vec![i]);
+                line_width = label_width;
+            }
+        }
+        line_width += added;
+    }
+
+    let mut output = String::new();
+    for (n, group) in groups.iter().enumerate() {
+        if n > 0 {
+            output.push('\n');
+        }
+
+        let mut header = format!("{:<label_width$}", "");
+        let mut rule = "-".repeat(label_width);
+        for &i in group {
+            header.push_str(&format!("{}{:>w$}", COLUMN_GAP, methods[i].name, w = column_widths[i]));
+            rule.push_str(&format!("{}{}", COLUMN_GAP, "-".repeat(column_widths[i])));
+        }
+        output.push_str(header.trim_end());
+        output.push('\n');
+        output.push_str(&rule);
+        output.push('\n');
+
+        for (label, cells) in &rows {
+            output.push_str(&format!("{:<label_width$}", label));
+            for &i in group {
+                output.push_str(&format!("{}{:>w$}", COLUMN_GAP, cells[i], w = column_widths[i]));
+            }
+            output.push('\n');
+        }
+    }
+
+    output
+}
+
+/// How many times worse each method's mean absolute relative error is than each method before it, starting with the
+/// first method, which is the exact baseline
+pub fn format_comparison_summary(methods: &[MethodComparison]) -> String {
+    let mut output = String::from("Comparison Summary:\n");
+    let Some((baseline, rest)) = methods.split_first() else {
+        return output;
+    };
+    // A ratio against an error-free method is infinite, so say so instead
+    let ratio = |method: &MethodComparison, other: &MethodComparison, suffix: &str| {
+        let (error, other_error) = (method.results.mean_absolute_relative_error, other.results.mean_absolute_relative_error);
+        if other_error > 0.0 {
+            format!("  {} vs {}: {:.2}x{}\n", method.name, other.name, error / other_error, suffix)
+        } else {
+            format!("  {} vs {}: {} has no measurable error\n", method.name, other.name, other.name)
+        }
+    };
+    for method in rest {
+        output.push_str(&ratio(method, baseline, " worse"));
+    }
+    for (i, method) in rest.iter().enumerate() {
+        for other in &rest[..i] {
+            output.push_str(&ratio(method, other, ""));
+        }
+    }
+    output
+}
+
+/// Absolute errors from zero up to this fraction are spread across the distribution sparklines
+const DISTRIBUTION_MAX: f64 = 0.4;
+const DISTRIBUTION_BINS: usize = 20;
+
+/// Every method's signed error on each of the shared test cases, in column order
+fn sample_all_methods(corpus: &[Vec<f64>]) -> Vec<(&'static str, Vec<Sample>)> {
+    for_each_method(corpus, |method, corpus| (method.name, (method.sample)(corpus)))
+}
+
+/// A one-line histogram of each method's absolute errors, for comparing their shapes at a glance
+pub fn format_error_distributions(methods: &[(&str, Vec<Sample>)]) -> String {
+    let mut output = String::new();
+    output.push_str(&format!(
+        "Error distribution, from 0% on the left to {:.0}% or more on the right, {:.0}% per character:\n",
+        DISTRIBUTION_MAX * 100.0,
+        DISTRIBUTION_MAX * 100.0 / DISTRIBUTION_BINS as f64
+    ));
+    let name_width = methods.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+    for (name, samples) in methods {
+        let errors = samples.iter().map(|sample| sample.relative_error.abs());
+        output.push_str(&format!("{:<name_width$}  {}\n", name, mini_histogram(errors, 0.0, DISTRIBUTION_MAX, DISTRIBUTION_BINS)));
+    }
+    output
+}
+
+/// A team-size shortcut's results against the table-based method on the same test cases
+pub struct ShortcutComparison {
+    pub size: usize,
+    pub name: &'static str,
+    pub table_based: Results,
+    pub shortcut: Results,
+}
+
+/// Each shortcut and the table-based method on the corpus's test cases of the shortcut's size
+fn compare_shortcuts(corpus: &[Vec<f64>]) -> Vec<ShortcutComparison> {
+    SHORTCUT_SIZES
+        .iter()
+        .map(|&size| {
+            let cases: Vec<Vec<f64>> = corpus.iter().filter(|case| case.len() == size).cloned().collect();
+            ShortcutComparison {
+                size,
+                name: AutoShortcut::selected_method(size),
+                table_based: evaluate_corpus::<TableBasedApproximation>(&cases),
+                shortcut: evaluate_corpus::<AutoShortcut>(&cases),
+            }
+        })
+        .collect()
+}
+
+/// How much of the table-based method's mean error each team-size shortcut removes
+pub fn format_shortcut_comparison(shortcuts: &[ShortcutComparison]) -> String {
+    let mut output = String::new();
+    output.push_str(&format!("Team-size shortcuts against {}, on the test cases of each size:\n", TableBasedApproximation::NAME));
+    let name_width = shortcuts.iter().map(|s| s.name.len()).max().unwrap_or(0).max("Shortcut".len());
+    output.push_str(&format!("Size  {:<name_width$}  Cases  Table Error  Shortcut Error  Error Removed\n", "Shortcut"));
+    for shortcut in shortcuts {
+        let table_error = shortcut.table_based.mean_absolute_relative_error;
+        let shortcut_error = shortcut.shortcut.mean_absolute_relative_error;
+        let removed = if table_error > 0.0 { format!("{:.1}%", (1.0 - shortcut_error / table_error) * 100.0) } else { "-".to_string() };
+        output.push_str(&format!(
+            "{:>4}  {:<name_width$}  {:>5}  {:>10.2}%  {:>13.2}%  {:>13}\n",
+            shortcut.size,
+            shortcut.name,
+            shortcut.shortcut.total_tests,
+            table_error * 100.0,
+            shortcut_error * 100.0,
+            removed
+        ));
+    }
+    output
+}
+
+/// The full comparison report: a header describing the test cases, then the results table fitted to `width`, then
+/// how the methods' errors compare, each method's error distribution, and what the team-size shortcuts gain over the table
+pub fn format_comparison_report(width: usize) -> String {
+    let mut output = String::new();
+    output.push_str("Pen and Paper Geometric Mean Comparison\n");
+    output.push_str("======================================\n");
+    output.push_str(&format!("Testing {} random cases with values from {} to {}\n", NUM_TESTS, MIN_VALUE, MAX_VALUE));
+    output.push('\n');
+    let corpus = shared_corpus();
+    let methods = evaluate_all_methods(&corpus);
+    output.push_str(&format_comparison_table(&methods, width));
+    output.push('\n');
+    output.push_str(&format_comparison_summary(&methods));
+    output.push('\n');
+    output.push_str(&format_error_distributions(&sample_all_methods(&corpus)));
+    output.push('\n');
+    output.push_str(&format_shortcut_comparison(&compare_shortcuts(&corpus)));
+    output
+}
+
+/// The test cases every method was evaluated against, and each method's results
+#[derive(Serialize)]
+struct ComparisonJson<'a> {
+    seed: u64,
+    num_tests: usize,
+    min_value: f64,
+    max_value: f64,
+    methods: Vec<MethodJson<'a>>,
+}
+
+#[derive(Serialize)]
+struct MethodJson<'a> {
+    name: &'static str,
+    #[serde(flatten)]
+    results: &'a Results,
+    accuracy_rank: usize,
+}
+
+/// Render the comparison as pretty-printed JSON, stamped with [`COMPARISON_SCHEMA_VERSION`], for downstream tooling
+pub fn format_comparison_json(methods: &[MethodComparison]) -> String {
+    let report = ComparisonJson {
+        seed: SEED,
+        num_tests: NUM_TESTS,
+        min_value: MIN_VALUE,
+        max_value: MAX_VALUE,
+        methods: methods
+            .iter()
+            .zip(accuracy_ranks(methods))
+            .map(|(method, accuracy_rank)| MethodJson { name: method.name, results: &method.results, accuracy_rank })
+            .collect(),
+    };
+    serde_json::to_string_pretty(&Versioned::new(COMPARISON_SCHEMA_VERSION, report)).expect("comparison serializes") + "\n"
+}
+
+/// Compare every estimation method against the same random test cases, printing the results as a table
+pub fn run_compare() {
+    print!("{}", format_comparison_report(terminal_width()));
+}
+
+/// Compare every estimation method on a corpus file of one test case per line, streamed rather than loaded
+pub fn run_compare_corpus(args: &[String]) {
+    let [path] = args else {
+        println!("Usage: cargo run -- --corpus <path>");
+        return;
+    };
+
+    match evaluate_corpus_file(Path::new(path)) {
+        Ok((cases, methods)) => {
+            println!("Testing {} cases from {}\n", cases, path);
+            print!("{}", format_comparison_table(&methods, terminal_width()));
+            println!();
+            print!("{}", format_comparison_summary(&methods));
+        }
+        Err(e) => println!("Failed to evaluate corpus: {}", e),
+    }
+}
+
+/// Compare every estimation method, printing the results as JSON
+pub fn run_compare_json() {
+    print!("{}", format_comparison_json(&evaluate_all_methods(&shared_corpus())));
+}
+
+/// Chart file names without an extension, and each chart for the approximate methods' samples
+///
+/// The exact method is left off, since its error is always zero.
+#[cfg(feature = "charts")]
+pub fn comparison_charts() -> Vec<(&'static str, pen_and_paper_geometric_mean::charts::LineChart)> {
+    use pen_and_paper_geometric_mean::charts::{LineChart, Series, error_by_count, error_by_magnitude, error_histogram};
+
+    let methods: Vec<(&str, Vec<Sample>)> = sample_all_methods(&shared_corpus()).into_iter().filter(|(name, _)| *name != ExactGeometricMean::NAME).collect();
+    let chart = |title: &str, x_label: &str, y_label: &str, log_x: bool, points: fn(&[Sample]) -> Vec<(f64, f64)>| LineChart {
+        title: title.to_string(),
+        x_label: x_label.to_string(),
+        y_label: y_label.to_string(),
+        log_x,
+        series: methods.iter().map(|(name, samples)| Series { name: name.to_string(), points: points(samples) }).collect(),
+    };
+
+    vec![
+        (
+            "error-vs-magnitude",
+            chart("Error by size of the answer", "Exact geometric mean", "Mean absolute error (%)", true, |s| error_by_magnitude(s, 8)),
+        ),
+        (
+            "error-histogram",
+            chart("Distribution of errors", "Signed error (%)", "Share of test cases (%)", false, |s| error_histogram(s, 0.02)),
+        ),
+        (
+            "error-vs-team-size",
+            chart("Error by number of values", "Values averaged", "Mean absolute error (%)", false, error_by_count),
+        ),
+    ]
+}
+
+/// Write SVG and PNG charts of the comparison's errors into a directory
+#[cfg(feature = "charts")]
+pub fn run_compare_charts(args: &[String]) {
+    let [dir] = args else {
+        println!("Usage: cargo run --features charts -- --charts <dir>");
+        return;
+    };
+    let dir = std::path::Path::new(dir);
+    if let Err(e) = std::fs::create_dir_all(dir) {
+        println!("Could not create {}: {}", dir.display(), e);
+        return;
+    }
+    for (file_name, chart) in comparison_charts() {
+        let svg_path = dir.join(format!("{}.svg", file_name));
+        let svg = chart.to_svg().map_err(|e| e.to_string());
+        match svg.and_then(|svg| std::fs::write(&svg_path, svg).map_err(|e| e.to_string())) {
+            Ok(()) => println!("Wrote {}", svg_path.display()),
+            Err(e) => println!("Could not write {}: {}", svg_path.display(), e),
+        }
+
+        let png_path = dir.join(format!("{}.png", file_name));
+        match chart.save_png(&png_path) {
+            Ok(()) => println!("Wrote {}", png_path.display()),
+            Err(e) => println!("Could not write {}: {}", png_path.display(), e),
+        }
+    }
+}
+
+#[cfg(not(feature = "charts"))]
+pub fn run_compare_charts(_args: &[String]) {
+    println!("Charts need the charts feature: cargo run --features charts -- --charts <dir>");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn method(name: &'static str, error: f64) -> MethodComparison {
+        MethodComparison {
+            name,
+            results: Results {
+                mean_absolute_relative_error: error,
+                worst_case_error: error * 2.0,
+                worst_case_overestimate: error,
+                overall_bias: error / 2.0,
+                total_tests: 100,
+            },
+        }
+    }
+
+    #[test]
+    fn test_format_comparison_table() {
+        let methods = vec![method("exact", 0.0), method("table-based", 0.07)];
+        let table = format_comparison_table(&methods, 80);
+
+        let expected = "                                   exact  table-based\n\
+                        ----------------------------  ----------  -----------\n\
+                        Mean Absolute Relative Error  0.000000e0  7.000000e-2\n\
+                        Worst Case Error              0.000000e0  1.400000e-1\n\
+                        Worst Case Overestimate       0.000000e0  7.000000e-2\n\
+                        Overall Bias                  0.000000e0  3.500000e-2\n\
+                        Valid Tests                          100          100\n\
+                        Accuracy Rank                          1            2\n";
+        assert_eq!(table, expected);
+    }
+
+    #[test]
+    fn test_columns_are_aligned() {
+        let methods = vec![method("exact", 0.0), method("log-linear", 0.15), method("table-based", 0.07)];
+        let table = format_comparison_table(&methods, 200);
+
+        let lengths: Vec<usize> = table.lines().skip(1).map(|line| line.len()).collect();
+        assert!(lengths.iter().all(|&len| len == lengths[0]));
+    }
+
+    #[test]
+    fn test_narrow_width_splits_into_stacked_tables() {
+        let methods = vec![method("exact", 0.0), method("log-linear", 0.15), method("table-based", 0.07)];
+        let table = format_comparison_table(&methods, 60);
+
+        assert!(table.lines().all(|line| line.len() <= 60));
+        let headers: Vec<&str> = table.lines().filter(|line| line.starts_with(' ')).collect();
+        assert_eq!(headers.len(), 2);
+        assert!(headers[0].contains("exact") && headers[0].contains("log-linear"));
+        assert!(headers[1].contains("table-based"));
+    }
+
+    #[test]
+    fn test_too_narrow_still_shows_one_method_per_table() {
+        let methods = vec![method("exact", 0.0), method("log-linear", 0.15)];
+        let table = format_comparison_table(&methods, 10);
+        assert_eq!(table.matches("Accuracy Rank").count(), 2);
+    }
+
+    #[cfg(feature = "charts")]
+    #[test]
+    fn test_comparison_charts() {
+        let charts = comparison_charts();
+        assert_eq!(charts.len(), 3);
+        for (file_name, chart) in &charts {
+            assert!(file_name.starts_with("error-") && !file_name.contains('.'));
+            let names: Vec<&str> = chart.series.iter().map(|series| series.name.as_str()).collect();
+            assert_eq!(names, ["log-linear", "table-based"]);
+            assert!(chart.series.iter().all(|series| !series.points.is_empty()));
+        }
+        // Test cases have 1 to 10 values
+        assert_eq!(charts[2].1.series[0].points.len(), 10);
+    }
+
+    #[test]
+    fn test_format_error_distributions() {
+        let sample = |relative_error| Sample { count: 2, exact: 100.0, relative_error };
+        let methods = vec![
+            ("exact", vec![sample(0.0); 4]),
+            ("table-based", vec![sample(0.01), sample(-0.05), sample(0.05), sample(0.5)]),
+        ];
+        let output = format_error_distributions(&methods);
+        assert!(output.starts_with("Error distribution, from 0% on the left to 40% or more on the right, 2% per character:\n"));
+        assert!(output.contains("\nexact        █▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁\n"));
+        assert!(output.contains("\ntable-based  ▅▁█▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▁▅\n"), "{}", output);
+    }
+
+    #[test]
+    fn test_parallel_evaluation_matches_each_method() {
+        let corpus = random_corpus(&mut rng::reference(5), MIN_VALUE, MAX_VALUE, 300);
+        let methods = evaluate_all_methods(&corpus);
+        let names: Vec<&str> = methods.iter().map(|method| method.name).collect();
+        assert_eq!(names, ["exact", "log-linear", "table-based"]);
+
+        let table_based = evaluate_corpus::<TableBasedApproximation>(&corpus);
+        assert_eq!(methods[2].results.mean_absolute_relative_error, table_based.mean_absolute_relative_error);
+        assert_eq!(methods[2].results.overall_bias, table_based.overall_bias);
+        assert_eq!(sample_all_methods(&corpus)[1].1, sample_corpus::<LogLinearApproximation>(&corpus));
+    }
+
+    #[test]
+    fn test_corpus_file_matches_corpus_in_memory() {
+        let corpus = random_corpus(&mut rng::reference(5), MIN_VALUE, MAX_VALUE, 300);
+        let path = std::env::temp_dir().join(format!("compare-corpus-{}.txt", std::process::id()));
+        let lines: Vec<String> = corpus.iter().map(|case| case.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(" ")).collect();
+        std::fs::write(&path, lines.join("\n")).unwrap();
+
+        let (cases, streamed) = evaluate_corpus_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(cases, 300);
+        for (streamed, in_memory) in streamed.iter().zip(evaluate_all_methods(&corpus)) {
+            assert_eq!(streamed.name, in_memory.name);
+            assert_eq!(streamed.results.mean_absolute_relative_error, in_memory.results.mean_absolute_relative_error);
+            assert_eq!(streamed.results.total_tests, in_memory.results.total_tests);
+        }
+        assert!(evaluate_corpus_file(&path).is_err());
+    }
+
+    #[test]
+    fn test_format_shortcut_comparison() {
+        let shortcuts = vec![
+            ShortcutComparison { size: 2, name: "shortcut-2", table_based: method("", 0.04).results, shortcut: method("", 0.0).results },
+            ShortcutComparison { size: 4, name: "shortcut-4", table_based: method("", 0.0).results, shortcut: method("", 0.0).results },
+        ];
+        assert_eq!(
+            format_shortcut_comparison(&shortcuts),
+            "Team-size shortcuts against table-based, on the test cases of each size:\n\
+             Size  Shortcut    Cases  Table Error  Shortcut Error  Error Removed\n\
+             \x20  2  shortcut-2    100        4.00%           0.00%         100.0%\n\
+             \x20  4  shortcut-4    100        0.00%           0.00%              -\n"
+        );
+    }
+
+    #[test]
+    fn test_shortcuts_beat_the_table() {
+        let corpus = random_corpus(&mut rng::reference(5), MIN_VALUE, MAX_VALUE, 300);
+        let shortcuts = compare_shortcuts(&corpus);
+        let sizes: Vec<usize> = shortcuts.iter().map(|shortcut| shortcut.size).collect();
+        assert_eq!(sizes, SHORTCUT_SIZES);
+        for shortcut in &shortcuts {
+            assert_eq!(shortcut.shortcut.total_tests, corpus.iter().filter(|case| case.len() == shortcut.size).count());
+            assert!(shortcut.shortcut.mean_absolute_relative_error > 0.0);
+            assert!(shortcut.shortcut.mean_absolute_relative_error < shortcut.table_based.mean_absolute_relative_error);
+        }
+    }
+
+    #[test]
+    fn test_format_comparison_summary() {
+        let methods = vec![method("exact", 0.01), method("log-linear", 0.15), method("table-based", 0.06)];
+        assert_eq!(
+            format_comparison_summary(&methods),
+            "Comparison Summary:\n  log-linear vs exact: 15.00x worse\n  table-based vs exact: 6.00x worse\n  table-based vs log-linear: 0.40x\n"
+        );
+
+        let methods = vec![method("exact", 0.0), method("table-based", 0.06)];
+        assert_eq!(format_comparison_summary(&methods), "Comparison Summary:\n  table-based vs exact: exact has no measurable error\n");
+    }
+
+    #[test]
+    fn test_accuracy_ranks() {
+        let methods = vec![method("a", 0.2), method("b", 0.0), method("c", 0.1), method("d", 0.1)];
+        assert_eq!(accuracy_ranks(&methods), vec![4, 1, 2, 2]);
+    }
+}
@@ -0,0 +1,81 @@
+use std::io::{self, IsTerminal, Write};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How often the countdown line is redrawn while waiting for input
+const TICK: Duration = Duration::from_millis(250);
+
+/// Whether a live countdown can be drawn, which requires both ends to be an interactive terminal
+pub fn is_supported() -> bool {
+    io::stdin().is_terminal() && io::stdout().is_terminal()
+}
+
+/// Format the countdown status line for a round with the given limit
+pub fn format_countdown(limit: Duration, elapsed: Duration) -> String {
+    match limit.checked_sub(elapsed) {
+        Some(remaining) if !remaining.is_zero() => {
+            // Round up, so the display only reaches 0:00 when time has actually run out
+            let seconds = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+            format!("Time remaining: {}:{:02}", seconds / 60, seconds % 60)
+        }
+        _ => {
+            let over = elapsed.saturating_sub(limit).as_secs();
+            format!("Time's up! Over by {}:{:02}", over / 60, over % 60)
+        }
+    }
+}
+
+/// Print the status line that the countdown will keep redrawing in place
+pub fn start_status_line(limit: Duration, started: Instant) {
+    println!("{}", format_countdown(limit, started.elapsed()));
+}
+
+/// Read a line of input while redrawing the countdown on the line above the prompt
+///
+/// Input is read on a background thread so the main thread can refresh the display.
+/// The cursor is saved and restored around each redraw, so the user's typing is undisturbed.
+pub fn read_line_with_countdown(limit: Duration, started: Instant) -> io::Result<String> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let mut input = String::new();
+        let result = io::stdin().read_line(&mut input).map(|_| input);
+        let _ = sender.send(result);
+    });
+
+    loop {
+        redraw_status_line(&format_countdown(limit, started.elapsed()));
+        match receiver.recv_timeout(TICK) {
+            Ok(result) => return result,
+            Err(RecvTimeoutError::Timeout) => continue,
+            Err(RecvTimeoutError::Disconnected) => return Err(io::Error::other("input reader stopped")),
+        }
+    }
+}
+
+fn redraw_status_line(status: &str) {
+    // Save cursor, move up a line, clear it, write the status, then restore the cursor
+    print!("\x1b7\x1b[1A\r\x1b[2K{}\x1b8", status);
+    io::stdout().flush().unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_countdown_remaining() {
+        let limit = Duration::from_secs(90);
+        assert_eq!(format_countdown(limit, Duration::ZERO), "Time remaining: 1:30");
+        assert_eq!(format_countdown(limit, Duration::from_millis(500)), "Time remaining: 1:30");
+        assert_eq!(format_countdown(limit, Duration::from_secs(31)), "Time remaining: 0:59");
+        assert_eq!(format_countdown(limit, Duration::from_millis(89_900)), "Time remaining: 0:01");
+    }
+
+    #[test]
+    fn test_format_countdown_expired() {
+        let limit = Duration::from_secs(30);
+        assert_eq!(format_countdown(limit, Duration::from_secs(30)), "Time's up! Over by 0:00");
+        assert_eq!(format_countdown(limit, Duration::from_secs(95)), "Time's up! Over by 1:05");
+    }
+}
@@ -0,0 +1,53 @@
+use pen_and_paper_geometric_mean::doctor::{Check, run_checks};
+
+/// Failures listed per check; the rest are summarized as a count
+const FAILURES_SHOWN: usize = 3;
+
+/// One line per check, with its first few failures indented below, and a summary
+pub fn format_report(checks: &[Check]) -> String {
+    let mut output = String::new();
+    for check in checks {
+        output.push_str(&format!("{} {}\n", if check.passed() { "PASS" } else { "FAIL" }, check.name));
+        for failure in check.failures.iter().take(FAILURES_SHOWN) {
+            output.push_str(&format!("     {}\n", failure));
+        }
+        if check.failures.len() > FAILURES_SHOWN {
+            output.push_str(&format!("     ...and {} more\n", check.failures.len() - FAILURES_SHOWN));
+        }
+    }
+
+    let failed = checks.iter().filter(|check| !check.passed()).count();
+    if failed == 0 {
+        output.push_str(&format!("\nAll {} checks passed.\n", checks.len()));
+    } else {
+        output.push_str(&format!("\n{} of {} checks failed.\n", failed, checks.len()));
+    }
+    output
+}
+
+/// Run the internal consistency checks, exiting with an error status if any fail
+pub fn run_doctor() {
+    let checks = run_checks();
+    print!("{}", format_report(&checks));
+    if checks.iter().any(|check| !check.passed()) {
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_report() {
+        let checks = vec![
+            Check { name: "known cases", failures: vec![] },
+            Check { name: "errors within bounds", failures: (1..=5).map(|i| format!("case {}", i)).collect() },
+        ];
+        assert_eq!(
+            format_report(&checks),
+            "PASS known cases\nFAIL errors within bounds\n     case 1\n     case 2\n     case 3\n     ...and 2 more\n\n1 of 2 checks failed.\n"
+        );
+        assert!(format_report(&checks[..1]).ends_with("\nAll 1 checks passed.\n"));
+    }
+}
@@ -0,0 +1,276 @@
+use std::io::{self, Write};
+use std::time::Duration;
+
+use crate::cli::duration::DurationFormat;
+use crate::cli::practice_mode::prompt_for_continue;
+use crate::cli::transcript::Transcript;
+use pen_and_paper_geometric_mean::drills::{
+    ActiveDrill, DrillKind, DrillQuestion, DrillResult, format_number, parse_drill_answer, warm_up,
+};
+use pen_and_paper_geometric_mean::exact::geometric_mean;
+use pen_and_paper_geometric_mean::history::{self, HistoryRecord};
+use pen_and_paper_geometric_mean::methods::METHOD_NAMES;
+use pen_and_paper_geometric_mean::practice_mode::{AnswerEvaluation, SystemTimer};
+use pen_and_paper_geometric_mean::rng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// What the user entered at the drill prompt
+#[derive(Debug, Clone, PartialEq)]
+enum DrillInput {
+    Answer(f64),
+    GiveUp,
+    Skip,
+}
+
+fn parse_drill_input(input: &str) -> Result<DrillInput, String> {
+    match input.trim().to_lowercase().as_str() {
+        "g" => Ok(DrillInput::GiveUp),
+        "s" => Ok(DrillInput::Skip),
+        _ => parse_drill_answer(input).map(DrillInput::Answer),
+    }
+}
+
+fn prompt_for_drill_input() -> DrillInput {
+    loop {
+        print!("Your answer (g give up, s skip): ");
+        io::stdout().flush().unwrap();
+
+        let mut input = String::new();
+        if io::stdin().read_line(&mut input).is_err() {
+            println!("Error reading input. Please try again.");
+            continue;
+        }
+        match parse_drill_input(&input) {
+            Ok(value) => return value,
+            Err(error) => println!("Invalid input: {}. Please try again.", error),
+        }
+    }
+}
+
+/// Format the grade, exact answer, and time for a drill question, with the working when it was missed
+pub fn format_drill_result(result: &DrillResult, time_format: &DurationFormat) -> String {
+    let mut output = String::new();
+    match result.evaluation {
+        AnswerEvaluation::Correct | AnswerEvaluation::Excellent => output.push_str("✓ CORRECT!\n"),
+        AnswerEvaluation::Incorrect => output.push_str("✗ Not close enough.\n"),
+        AnswerEvaluation::GaveUp => output.push_str("You gave up.\n"),
+    }
+    output.push_str(&format!("Exact answer: {}\n", format_number(result.question.exact_answer)));
+    output.push_str(&format!("Time taken: {}\n", time_format.format(result.duration)));
+
+    if !result.evaluation.is_success() {
+        output.push('\n');
+        output.push_str(&result.question.working);
+    }
+    output
+}
+
+/// Format the accuracy and timing over every drill question answered
+pub fn format_drill_summary(results: &[DrillResult], time_format: &DurationFormat) -> String {
+    if results.is_empty() {
+        return String::new();
+    }
+
+    let correct = results.iter().filter(|r| r.evaluation.is_success()).count();
+    let total: Duration = results.iter().map(|r| r.duration).sum();
+    format!(
+        "Drill Summary\n=============\nQuestions: {}\nAccuracy: {:.1}%\nAverage time: {}\n",
+        results.len(),
+        correct as f64 / results.len() as f64 * 100.0,
+        time_format.format(total / results.len() as u32)
+    )
+}
+
+/// Answers the user submitted in past geometric mean rounds, paired with each round's exact geometric mean
+fn past_answers(records: &[HistoryRecord]) -> Vec<(f64, f64)> {
+    records
+        .iter()
+        .filter(|record| record.evaluation != AnswerEvaluation::GaveUp && METHOD_NAMES.contains(&record.method.as_str()))
+        .filter_map(|record| {
+            let guesses: Vec<f64> = record.guesses.iter().map(|&g| g as f64).collect();
+            let exact = geometric_mean(&guesses).ok()?;
+            Some((record.answer as f64, exact.round()))
+        })
+        .filter(|&(answer, exact)| answer > 0.0 && exact > 0.0)
+        .collect()
+}
+
+fn load_past_answers() -> Vec<(f64, f64)> {
+    history::default_history_path()
+        .and_then(|path| history::load_records(&path).ok())
+        .map(|records| past_answers(&records))
+        .unwrap_or_default()
+}
+
+/// A question of `kind`, about one of the user's own past answers when the kind allows and any exist
+fn next_question<R: Rng>(kind: DrillKind, past_answers: &[(f64, f64)], rng: &mut R) -> DrillQuestion {
+    past_answers
+        .choose(rng)
+        .and_then(|&(answer, exact)| kind.question_for_answer(answer, exact))
+        .unwrap_or_else(|| kind.generate(rng))
+}
+
+fn usage() -> String {
+    let names: Vec<&str> = DrillKind::ALL.iter().map(|kind| kind.name()).collect();
+    format!("Usage: cargo run drill <{}>", names.join("|"))
+}
+
+/// Run timed drill questions of one kind until the user stops
+pub fn run_drill(args: &[String]) {
+    let kind = match args {
+        [name] => match DrillKind::from_name(name) {
+            Some(kind) => kind,
+            None => {
+                println!("Unknown drill: {}\n{}", name, usage());
+                return;
+            }
+        },
+        _ => {
+            println!("{}", usage());
+            return;
+        }
+    };
+
+    let past_answers = if kind.uses_past_answers() { load_past_answers() } else { Vec::new() };
+
+    println!("Drill - {}", kind.name());
+    if !past_answers.is_empty() {
+        println!("Questions use your {} past practice answers.", format_number(past_answers.len() as f64));
+    }
+    println!();
+
+    let time_format = DurationFormat::from_config();
+    let mut rng = rng::from_entropy();
+    let mut transcript = Transcript::disabled();
+    let mut results = Vec::new();
+
+    loop {
+        let drill = ActiveDrill::start(next_question(kind, &past_answers, &mut rng), SystemTimer);
+        println!("{}", drill.question().prompt);
+
+        let result = match prompt_for_drill_input() {
+            DrillInput::Answer(answer) => drill.submit_answer(answer),
+            DrillInput::GiveUp => drill.give_up(),
+            DrillInput::Skip => {
+                println!("Skipped.\n");
+                continue;
+            }
+        };
+        println!();
+        println!("{}", format_drill_result(&result, &time_format));
+        results.push(result);
+
+        if !prompt_for_continue(&mut transcript) {
+            break;
+        }
+        println!();
+    }
+
+    println!();
+    print!("{}", format_drill_summary(&results, &time_format));
+}
+
+/// Run a short warm-up of the sums and divisions the methods need, before practice
+pub fn run_warm_up(count: usize, time_format: &DurationFormat) {
+    println!("Warm-up - {} quick sums and divisions", count);
+    println!();
+
+    let mut rng = rng::from_entropy();
+    let mut results = Vec::new();
+    for question in warm_up(&mut rng, count) {
+        let drill = ActiveDrill::start(question, SystemTimer);
+        println!("{}", drill.question().prompt);
+
+        let result = match prompt_for_drill_input() {
+            DrillInput::Answer(answer) => drill.submit_answer(answer),
+            DrillInput::GiveUp => drill.give_up(),
+            DrillInput::Skip => {
+                println!("Skipped.\n");
+                continue;
+            }
+        };
+        println!();
+        println!("{}", format_drill_result(&result, time_format));
+        results.push(result);
+    }
+
+    print!("{}", format_drill_summary(&results, time_format));
+    println!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pen_and_paper_geometric_mean::drills::Tolerance;
+
+    fn result(evaluation: AnswerEvaluation, seconds: u64) -> DrillResult {
+        DrillResult {
+            question: DrillQuestion {
+                prompt: "Average these numbers:\n  10\n  25\n".to_string(),
+                exact_answer: 17.5,
+                tolerance: Tolerance::Relative(0.01),
+                working: "Sum: 10 + 25 = 35\nAverage: 35 ÷ 2 = 17.5\n".to_string(),
+            },
+            user_answer: Some(17.0),
+            evaluation,
+            duration: Duration::from_secs(seconds),
+        }
+    }
+
+    #[test]
+    fn test_parse_drill_input() {
+        assert_eq!(parse_drill_input("g\n"), Ok(DrillInput::GiveUp));
+        assert_eq!(parse_drill_input("S"), Ok(DrillInput::Skip));
+        assert_eq!(parse_drill_input("2,500"), Ok(DrillInput::Answer(2500.0)));
+        assert!(parse_drill_input("?").is_err());
+    }
+
+    #[test]
+    fn test_format_drill_result() {
+        let output = format_drill_result(&result(AnswerEvaluation::Incorrect, 4), &DurationFormat::default());
+        assert_eq!(
+            output,
+            "✗ Not close enough.\nExact answer: 17.5\nTime taken: 4.0s\n\nSum: 10 + 25 = 35\nAverage: 35 ÷ 2 = 17.5\n"
+        );
+        assert!(!format_drill_result(&result(AnswerEvaluation::Correct, 4), &DurationFormat::default()).contains("Sum:"));
+    }
+
+    #[test]
+    fn test_format_drill_summary() {
+        let results = [result(AnswerEvaluation::Correct, 2), result(AnswerEvaluation::Incorrect, 4)];
+        assert_eq!(
+            format_drill_summary(&results, &DurationFormat::default()),
+            "Drill Summary\n=============\nQuestions: 2\nAccuracy: 50.0%\nAverage time: 3.0s\n"
+        );
+        assert!(format_drill_summary(&results, &DurationFormat::new(0).unwrap()).ends_with("Average time: 3s\n"));
+        assert_eq!(format_drill_summary(&[], &DurationFormat::default()), "");
+    }
+
+    fn record(guesses: Vec<u64>, answer: u64, evaluation: AnswerEvaluation, method: &str) -> HistoryRecord {
+        HistoryRecord { guesses, answer, evaluation, method: method.to_string(), ..HistoryRecord::default() }
+    }
+
+    #[test]
+    fn test_past_answers() {
+        let records = [
+            record(vec![25, 400], 120, AnswerEvaluation::Incorrect, "table-based"),
+            record(vec![25, 400], 0, AnswerEvaluation::GaveUp, "table-based"),
+            record(vec![30, 60], 50, AnswerEvaluation::Correct, "reciprocal-table"),
+        ];
+        assert_eq!(past_answers(&records), vec![(120.0, 100.0)]);
+    }
+
+    #[test]
+    fn test_next_question() {
+        let mut rng = rng::seeded(3);
+        let past = [(120.0, 100.0)];
+
+        let question = next_question(DrillKind::PercentError, &past, &mut rng);
+        assert!(question.prompt.starts_with("Your answer: 120\nExact value: 100\n"));
+        assert!((question.exact_answer - 20.0).abs() < 1e-9);
+
+        let question = next_question(DrillKind::ArithmeticMean, &past, &mut rng);
+        assert!(question.prompt.starts_with("Average these numbers:"));
+    }
+}
@@ -0,0 +1,87 @@
+use std::time::Duration;
+
+use pen_and_paper_geometric_mean::config;
+
+/// Most decimal places a time can be shown with; finer than milliseconds is only timer noise
+pub const MAX_PRECISION: usize = 3;
+
+/// How solve times are shown: seconds for quick solves, and minutes (and hours) for long ones, as `1:05.3`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DurationFormat {
+    /// Decimal places shown on the seconds
+    precision: usize,
+}
+
+impl Default for DurationFormat {
+    fn default() -> Self {
+        DurationFormat { precision: 1 }
+    }
+}
+
+impl DurationFormat {
+    /// A format showing `precision` decimal places, up to [`MAX_PRECISION`]
+    pub fn new(precision: usize) -> Result<Self, String> {
+        if precision > MAX_PRECISION {
+            return Err(format!("Invalid time precision: {} (expected 0 to {} decimal places)", precision, MAX_PRECISION));
+        }
+        Ok(DurationFormat { precision })
+    }
+
+    /// The format from the user's config file, or the default when it can't be loaded
+    pub fn from_config() -> Self {
+        config::load_default()
+            .ok()
+            .and_then(|user_config| DurationFormat::new(user_config.time_precision).ok())
+            .unwrap_or_default()
+    }
+
+    /// Parse a number of decimal places
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let precision = value.parse().map_err(|_| format!("Invalid time precision: {}", value))?;
+        DurationFormat::new(precision)
+    }
+
+    /// `12.3s` under a minute, `1:05.3` under an hour, and `1:01:05.3` beyond
+    pub fn format(&self, duration: Duration) -> String {
+        // Round before splitting into units, so 59.96 seconds shows as 1:00.0 rather than 0:60.0
+        let scale = 10u64.pow(self.precision as u32);
+        let units = (duration.as_secs_f64() * scale as f64).round() as u64;
+        let (seconds, fraction) = (units / scale, units % scale);
+        let fraction = if self.precision == 0 { String::new() } else { format!(".{:0width$}", fraction, width = self.precision) };
+
+        if seconds < 60 {
+            return format!("{}{}s", seconds, fraction);
+        }
+        let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+        if hours == 0 {
+            format!("{}:{:02}{}", minutes, seconds, fraction)
+        } else {
+            format!("{}:{:02}:{:02}{}", hours, minutes, seconds, fraction)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_duration() {
+        let format = DurationFormat::default();
+        assert_eq!(format.format(Duration::from_millis(12_345)), "12.3s");
+        assert_eq!(format.format(Duration::ZERO), "0.0s");
+        assert_eq!(format.format(Duration::from_millis(59_960)), "1:00.0");
+        assert_eq!(format.format(Duration::from_millis(65_300)), "1:05.3");
+        assert_eq!(format.format(Duration::from_millis(3_725_000)), "1:02:05.0");
+
+        assert_eq!(DurationFormat::new(0).unwrap().format(Duration::from_millis(8_700)), "9s");
+        assert_eq!(DurationFormat::new(3).unwrap().format(Duration::from_millis(125_042)), "2:05.042");
+    }
+
+    #[test]
+    fn test_parse_precision() {
+        assert_eq!(DurationFormat::parse("2"), DurationFormat::new(2));
+        assert!(DurationFormat::parse("4").is_err());
+        assert!(DurationFormat::parse("one").is_err());
+    }
+}
@@ -0,0 +1,142 @@
+use std::fmt::{Display, Write};
+
+use pen_and_paper_geometric_mean::exact::ExactGeometricMean;
+use pen_and_paper_geometric_mean::harmonic::{ExactHarmonicMean, ReciprocalTableApproximation};
+use pen_and_paper_geometric_mean::log_linear::LogLinearApproximation;
+use pen_and_paper_geometric_mean::median::{LowerQuartileMethod, MedianMethod, UpperQuartileMethod};
+use pen_and_paper_geometric_mean::table_based::TableBasedApproximation;
+use pen_and_paper_geometric_mean::traits::{EstimateGeometricMeanStepByStep, MethodMetadata};
+use pen_and_paper_geometric_mean::weighted::{ConfidenceWeightedTable, WeightedGuess};
+
+/// Worked examples for the geometric mean methods: the README's, a pair, and one needing an edge case or rounding
+const GEOMETRIC_EXAMPLES: [&[f64]; 3] = [&[300.0, 10_000.0, 900.0, 70.0], &[25.0, 400.0], &[80.0, 80.0, 80.0, 800.0]];
+
+/// A guess of 2,000 with confidence 3 and one of 500 with confidence 1, then a guess of 40 with confidence 2 and one of 9,000
+const WEIGHTED_EXAMPLES: [&[WeightedGuess]; 2] = [
+    &[WeightedGuess { guess: 2_000, confidence: 3 }, WeightedGuess { guess: 500, confidence: 1 }],
+    &[WeightedGuess { guess: 40, confidence: 2 }, WeightedGuess { guess: 9_000, confidence: 1 }],
+];
+
+/// Speeds, in the harmonic mean's usual setting
+const HARMONIC_EXAMPLES: [&[f64]; 2] = [&[30.0, 60.0], &[40.0, 60.0, 120.0]];
+
+/// Odd and even counts, so both the middle value and the middle pair are shown
+const ORDER_EXAMPLES: [&[f64]; 3] = [&[150.0, 2_500.0, 800.0, 45.0], &[9.0, 1.0, 5.0, 3.0, 7.0], &[300.0, 10_000.0, 900.0, 70.0, 2_000.0]];
+
+/// Every method `explain` knows, in the order it lists them
+const EXPLAINABLE: [&str; 9] = [
+    ExactGeometricMean::NAME,
+    LogLinearApproximation::NAME,
+    TableBasedApproximation::NAME,
+    ConfidenceWeightedTable::NAME,
+    ExactHarmonicMean::NAME,
+    ReciprocalTableApproximation::NAME,
+    MedianMethod::NAME,
+    LowerQuartileMethod::NAME,
+    UpperQuartileMethod::NAME,
+];
+
+/// The method's name, what it estimates, and its description
+pub fn format_description<E: MethodMetadata>() -> String {
+    let mut output = String::new();
+    output.push_str(&format!("{}\n", E::NAME));
+    output.push_str(&format!("{}\n", "=".repeat(E::NAME.chars().count())));
+    output.push_str(&format!("Estimates the {}.\n", E::MEAN));
+    output.push_str(&format!("{}\n", E::DESCRIPTION));
+    output
+}
+
+/// The description followed by each example worked through the method's own steps, so the explanation can't drift
+/// from the implementation
+pub fn format_explanation<E>(examples: &[&[f64]]) -> String
+where
+    E: MethodMetadata + EstimateGeometricMeanStepByStep,
+    E::StepByStep: Display,
+{
+    let mut output = format_description::<E>();
+    for (number, values) in examples.iter().enumerate() {
+        let heading = format!("Example {}", number + 1);
+        output.push_str(&format!("\n{}\n{}\n", heading, "-".repeat(heading.len())));
+        match E::estimate_geometric_mean_steps(values) {
+            Ok(steps) => writeln!(output, "{}", steps).expect("writing to a String cannot fail"),
+            Err(e) => output.push_str(&format!("Error: {}\n", e)),
+        }
+    }
+    output
+}
+
+/// The confidence-weighted description and examples, worked from each guess's own confidence
+fn format_weighted_explanation() -> String {
+    let mut output = format_description::<ConfidenceWeightedTable>();
+    for (number, guesses) in WEIGHTED_EXAMPLES.iter().enumerate() {
+        let heading = format!("Example {}", number + 1);
+        output.push_str(&format!("\n{}\n{}\n", heading, "-".repeat(heading.len())));
+        match ConfidenceWeightedTable::weighted_steps(guesses) {
+            Ok(steps) => writeln!(output, "{}", steps).expect("writing to a String cannot fail"),
+            Err(e) => output.push_str(&format!("Error: {}\n", e)),
+        }
+    }
+    output
+}
+
+/// The explanation for the method named `name`, or `None` when no method has that name
+pub fn explain(name: &str) -> Option<String> {
+    let reference = "It is exact, so there are no pen-and-paper steps to work through.\n";
+    match name {
+        ExactGeometricMean::NAME => Some(format_description::<ExactGeometricMean>() + reference),
+        LogLinearApproximation::NAME => Some(format_explanation::<LogLinearApproximation>(&GEOMETRIC_EXAMPLES)),
+        TableBasedApproximation::NAME => Some(format_explanation::<TableBasedApproximation>(&GEOMETRIC_EXAMPLES)),
+        ConfidenceWeightedTable::NAME => Some(format_weighted_explanation()),
+        ExactHarmonicMean::NAME => Some(format_description::<ExactHarmonicMean>() + reference),
+        ReciprocalTableApproximation::NAME => {
+            Some(format_explanation::<ReciprocalTableApproximation>(&HARMONIC_EXAMPLES))
+        }
+        MedianMethod::NAME => Some(format_explanation::<MedianMethod>(&ORDER_EXAMPLES)),
+        LowerQuartileMethod::NAME => Some(format_explanation::<LowerQuartileMethod>(&ORDER_EXAMPLES)),
+        UpperQuartileMethod::NAME => Some(format_explanation::<UpperQuartileMethod>(&ORDER_EXAMPLES)),
+        _ => None,
+    }
+}
+
+/// Print how a method works, with worked examples
+pub fn run_explain(args: &[String]) {
+    match args {
+        [name] => match explain(name) {
+            Some(explanation) => print!("{}", explanation),
+            None => println!("Unknown method: {}\nMethods: {}", name, EXPLAINABLE.join(", ")),
+        },
+        _ => println!("Usage: cargo run explain <method>\nMethods: {}", EXPLAINABLE.join(", ")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_method_is_explained() {
+        for name in EXPLAINABLE {
+            let explanation = explain(name).unwrap();
+            assert!(explanation.starts_with(&format!("{}\n", name)), "{}", explanation);
+            assert!(!explanation.contains("Error:"), "{}", explanation);
+        }
+        assert_eq!(explain("abacus"), None);
+    }
+
+    #[test]
+    fn test_explanation_works_examples_through_the_steps() {
+        let explanation = format_explanation::<TableBasedApproximation>(&[&[2_000.0, 50.0, 350.0]]);
+        assert!(explanation.starts_with("table-based\n===========\nEstimates the geometric mean.\n"));
+        assert!(explanation.contains(TableBasedApproximation::DESCRIPTION));
+        assert!(explanation.contains("\nExample 1\n---------\nInput values: [2000, 50, 350]\n"));
+        assert!(explanation.ends_with("Final estimation: 300\n"));
+        assert!(!explanation.contains("Example 2"));
+    }
+
+    #[test]
+    fn test_examples_cover_the_log_linear_edge_case() {
+        let explanation = explain("log-linear").unwrap();
+        assert!(explanation.contains("Final estimation: 750\n"));
+        assert!(explanation.contains("decimals below .1"));
+    }
+}
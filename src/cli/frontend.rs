@@ -0,0 +1,422 @@
+use serde::{Deserialize, Serialize};
+use std::io::{self, BufRead, Write};
+
+use crate::cli::practice_mode::{self, PracticeOptions};
+use crate::cli::schema::{FRONTEND_SCHEMA_VERSION, Versioned};
+use crate::cli::tutorial::{LOCKED_MESSAGE, has_practice_history, practice_unlocked};
+use pen_and_paper_geometric_mean::config;
+use pen_and_paper_geometric_mean::history::{self, SessionContext};
+use pen_and_paper_geometric_mean::practice_mode::{
+    ActiveSession, AnswerEvaluation, Hint, PracticeModeConfig, PracticeResult, PracticeSession, Ready, SessionStats, SystemTimer, Timer,
+};
+use pen_and_paper_geometric_mean::harmonic::ReciprocalTableApproximation;
+use pen_and_paper_geometric_mean::median::{LowerQuartileMethod, MedianMethod, UpperQuartileMethod};
+use pen_and_paper_geometric_mean::rng;
+use pen_and_paper_geometric_mean::table_based::TableBasedApproximation;
+use pen_and_paper_geometric_mean::traits::{EstimateGeometricMean, EstimateGeometricMeanStepByStep, MethodMetadata};
+use rand::Rng;
+
+/// A command read from a frontend, one JSON object per line
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum Command {
+    NewProblem,
+    Hint,
+    Answer { value: u64 },
+    GiveUp,
+    Skip,
+    Quit,
+}
+
+/// An event written to a frontend, one JSON object per line, stamped with [`FRONTEND_SCHEMA_VERSION`]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    Ready {
+        /// Always the schema version, kept for frontends written before every event carried one
+        protocol_version: u32,
+        method: &'static str,
+    },
+    Problem {
+        guesses: Vec<u64>,
+    },
+    Hint {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        digit_count: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        leading_digits: Option<u64>,
+    },
+    NoMoreHints,
+    Result {
+        /// `None` when the user gave up
+        answer: Option<u64>,
+        /// Tier label, as recorded in history
+        evaluation: &'static str,
+        /// The exact mean the method estimates, such as the median for `median`, under its original name to keep the
+        /// schema stable
+        exact_geometric_mean: f64,
+        estimate: u64,
+        correct_answer: u64,
+        duration_ms: u64,
+        hints_used: usize,
+        /// The estimation method's worked solution
+        steps: Option<String>,
+    },
+    Skipped,
+    Summary {
+        rounds: usize,
+        accuracy: Option<f64>,
+        average_duration_ms: Option<u64>,
+    },
+    /// A command could not be carried out; the session continues
+    Error {
+        message: String,
+    },
+    /// Something went wrong outside the round, such as saving history; the session continues
+    Warning {
+        message: String,
+    },
+}
+
+impl Event {
+    fn error(message: impl Into<String>) -> Self {
+        Event::Error { message: message.into() }
+    }
+
+    fn hint(hint: Hint) -> Self {
+        match hint {
+            Hint::DigitCount(digits) => Event::Hint { digit_count: Some(digits), leading_digits: None },
+            Hint::LeadingDigits(leading) => Event::Hint { digit_count: None, leading_digits: Some(leading) },
+        }
+    }
+
+    fn result<E>(result: &PracticeResult<E>) -> Self
+    where
+        E: EstimateGeometricMeanStepByStep,
+        E::StepByStep: std::fmt::Display,
+    {
+        Event::Result {
+            answer: (result.evaluation != AnswerEvaluation::GaveUp).then_some(result.user_answer),
+            evaluation: history::tier_label(&result.evaluation),
+            exact_geometric_mean: result.exact_mean,
+            estimate: result.estimation_result,
+            correct_answer: result.correct_answer,
+            duration_ms: result.duration.as_millis() as u64,
+            hints_used: result.hints_used,
+            steps: result.get_step_by_step().ok().map(|steps| steps.to_string()),
+        }
+    }
+
+    fn summary(stats: &SessionStats) -> Self {
+        Event::Summary {
+            rounds: stats.rounds().len(),
+            accuracy: stats.accuracy(),
+            average_duration_ms: stats.average_duration().map(|d| d.as_millis() as u64),
+        }
+    }
+}
+
+/// A practice session driven by frontend commands rather than a terminal
+pub struct Frontend<R, T: Timer, E> {
+    rng: R,
+    timer: T,
+    config: PracticeModeConfig,
+    active: Option<ActiveSession<T, E>>,
+    stats: SessionStats,
+}
+
+impl<R, T, E> Frontend<R, T, E>
+where
+    R: Rng,
+    T: Timer + Clone,
+    E: EstimateGeometricMeanStepByStep + EstimateGeometricMean,
+    E::StepByStep: std::fmt::Display,
+{
+    pub fn new(rng: R, timer: T, config: PracticeModeConfig) -> Self {
+        Frontend { rng, timer, config, active: None, stats: SessionStats::new() }
+    }
+
+    /// Carry out a command, returning the event to send and the finished round, if the command ended one
+    pub fn handle(&mut self, command: Command) -> (Event, Option<PracticeResult<E>>) {
+        match command {
+            Command::NewProblem => {
+                if self.active.is_some() {
+                    return (Event::error("A problem is already in progress; answer, give up, or skip it first"), None);
+                }
+                let session: PracticeSession<Ready, _, _, E> = PracticeSession::new(&mut self.rng, self.timer.clone());
+                match session.start(self.config.clone()) {
+                    Ok((guesses, active)) => {
+                        self.active = Some(active);
+                        (Event::Problem { guesses }, None)
+                    }
+                    Err(e) => (Event::error(format!("Error generating problem: {}", e)), None),
+                }
+            }
+            Command::Hint => match self.active.as_mut() {
+                Some(active) => (active.hint().map_or(Event::NoMoreHints, Event::hint), None),
+                None => (no_problem(), None),
+            },
+            Command::Answer { value: 0 } => (Event::error("Answers must be positive"), None),
+            Command::Answer { value } => self.finish(|active| active.submit_answer(value)),
+            Command::GiveUp => self.finish(ActiveSession::give_up),
+            Command::Skip => match self.active.take() {
+                Some(active) => {
+                    active.skip();
+                    (Event::Skipped, None)
+                }
+                None => (no_problem(), None),
+            },
+            Command::Quit => (Event::summary(&self.stats), None),
+        }
+    }
+
+    fn finish(&mut self, end: impl FnOnce(ActiveSession<T, E>) -> PracticeResult<E>) -> (Event, Option<PracticeResult<E>>) {
+        let Some(active) = self.active.take() else {
+            return (no_problem(), None);
+        };
+
+        let result = end(active);
+        self.stats.record(&result);
+        (Event::result(&result), Some(result))
+    }
+
+    pub fn stats(&self) -> &SessionStats {
+        &self.stats
+    }
+}
+
+fn no_problem() -> Event {
+    Event::error("No problem in progress; send new_problem first")
+}
+
+fn write_event<W: Write>(output: &mut W, event: &Event) -> io::Result<()> {
+    let line = serde_json::to_string(&Versioned::new(FRONTEND_SCHEMA_VERSION, event)).map_err(io::Error::other)?;
+    writeln!(output, "{}", line)?;
+    output.flush()
+}
+
+/// Read commands from `input` until `quit` or end of input, writing an event for each to `output`
+///
+/// Every finished round is passed to `on_result`, which can return warnings to forward to the frontend.
+/// A summary of the session is always the last event.
+pub fn serve<I, O, R, T, E>(
+    input: I,
+    output: &mut O,
+    frontend: &mut Frontend<R, T, E>,
+    mut on_result: impl FnMut(&PracticeResult<E>) -> Vec<String>,
+) -> io::Result<()>
+where
+    I: BufRead,
+    O: Write,
+    R: Rng,
+    T: Timer + Clone,
+    E: EstimateGeometricMeanStepByStep + MethodMetadata + EstimateGeometricMean,
+    E::StepByStep: std::fmt::Display,
+{
+    write_event(output, &Event::Ready { protocol_version: FRONTEND_SCHEMA_VERSION, method: E::NAME })?;
+
+    for line in input.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let command = match serde_json::from_str(&line) {
+            Ok(command) => command,
+            Err(e) => {
+                write_event(output, &Event::error(format!("Malformed command: {}", e)))?;
+                continue;
+            }
+        };
+        if command == Command::Quit {
+            break;
+        }
+
+        let (event, result) = frontend.handle(command);
+        write_event(output, &event)?;
+        for message in result.iter().flat_map(&mut on_result) {
+            write_event(output, &Event::Warning { message })?;
+        }
+    }
+
+    write_event(output, &Event::summary(frontend.stats()))
+}
+
+/// Run practice mode over stdin and stdout as JSON lines, for GUI wrappers and editor plugins
+pub fn run_frontend_protocol(options: PracticeOptions) {
+    // New users take the tutorial first, as in the terminal; a config that fails to load is reported by the session itself
+    let locked = config::load_default().is_ok_and(|user_config| !practice_unlocked(&user_config, has_practice_history()));
+    if locked {
+        let _ = write_event(&mut io::stdout().lock(), &Event::error(LOCKED_MESSAGE));
+        return;
+    }
+
+    match options.method.as_deref().unwrap_or(TableBasedApproximation::NAME) {
+        ReciprocalTableApproximation::NAME => serve_stdio::<ReciprocalTableApproximation>(options),
+        MedianMethod::NAME => serve_stdio::<MedianMethod>(options),
+        LowerQuartileMethod::NAME => serve_stdio::<LowerQuartileMethod>(options),
+        UpperQuartileMethod::NAME => serve_stdio::<UpperQuartileMethod>(options),
+        _ => serve_stdio::<TableBasedApproximation>(options),
+    }
+}
+
+/// Serve the protocol over stdio with the estimation method `E`
+fn serve_stdio<E>(options: PracticeOptions)
+where
+    E: EstimateGeometricMeanStepByStep + MethodMetadata + EstimateGeometricMean,
+    E::StepByStep: std::fmt::Display,
+{
+    let mut stdout = io::stdout().lock();
+
+    let (user_config, config) = match practice_mode::load_practice_config() {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            let _ = write_event(&mut stdout, &Event::error(format!("Error loading configuration: {}", e)));
+            return;
+        }
+    };
+
+    let keep_history = user_config.history && !options.no_history;
+    let mut history_session = SessionContext::starting_now(&config);
+    history_session.tags = options.tags;
+    history_session.note = options.note;
+    history_session.profile = options.profile;
+
+    let mut frontend: Frontend<_, _, E> = Frontend::new(rng::from_entropy(), SystemTimer, config);
+
+    let record = |result: &PracticeResult<E>| {
+        let mut warnings = Vec::new();
+        if !keep_history {
+            return warnings;
+        }
+        if let Err(e) = practice_mode::try_record_history(result, &history_session) {
+            warnings.push(format!("Could not record practice history: {}", e));
+        }
+        if !result.evaluation.is_success()
+            && let Err(e) = practice_mode::try_bank_missed_problem(result)
+        {
+            warnings.push(format!("Could not save missed problem for review: {}", e));
+        }
+        warnings
+    };
+
+    // Once stdout is gone, there is no frontend left to report to
+    let _ = serve(io::stdin().lock(), &mut stdout, &mut frontend, record);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pen_and_paper_geometric_mean::traits::FinalAnswer;
+    use std::time::Duration;
+
+    #[derive(Clone)]
+    struct ZeroTimer;
+
+    impl Timer for ZeroTimer {
+        type Instant = ();
+
+        fn now(&self) -> Self::Instant {}
+
+        fn elapsed(&self, _start: Self::Instant) -> Duration {
+            Duration::ZERO
+        }
+    }
+
+    fn frontend() -> Frontend<rng::DefaultRng, ZeroTimer, TableBasedApproximation> {
+        let config = PracticeModeConfig::new(4, 1.0, 10, 10_000).unwrap();
+        Frontend::new(rng::seeded(42), ZeroTimer, config)
+    }
+
+    /// Serve `commands`, returning the events written as JSON values
+    fn run(commands: &str) -> Vec<serde_json::Value> {
+        let mut output = Vec::new();
+        serve(commands.as_bytes(), &mut output, &mut frontend(), |_| Vec::new()).unwrap();
+        String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    fn event_names(events: &[serde_json::Value]) -> Vec<&str> {
+        events.iter().map(|e| e["event"].as_str().unwrap()).collect()
+    }
+
+    #[test]
+    fn test_parse_commands() {
+        let parse = |line: &str| serde_json::from_str::<Command>(line).unwrap();
+        assert_eq!(parse(r#"{"command":"new_problem"}"#), Command::NewProblem);
+        assert_eq!(parse(r#"{"command":"answer","value":400}"#), Command::Answer { value: 400 });
+        assert_eq!(parse(r#"{"command":"give_up"}"#), Command::GiveUp);
+    }
+
+    #[test]
+    fn test_round_of_events() {
+        let events = run(concat!(
+            "{\"command\":\"new_problem\"}\n",
+            "{\"command\":\"hint\"}\n",
+            "{\"command\":\"give_up\"}\n",
+            "\n",
+            "{\"command\":\"new_problem\"}\n",
+            "{\"command\":\"skip\"}\n",
+            "{\"command\":\"quit\"}\n",
+            "{\"command\":\"new_problem\"}\n",
+        ));
+
+        assert_eq!(event_names(&events), vec!["ready", "problem", "hint", "result", "problem", "skipped", "summary"]);
+        assert_eq!(events[0]["protocol_version"], FRONTEND_SCHEMA_VERSION);
+        assert!(events.iter().all(|event| event["schema_version"] == FRONTEND_SCHEMA_VERSION));
+        assert_eq!(events[0]["method"], "table-based");
+        assert_eq!(events[1]["guesses"].as_array().unwrap().len(), 4);
+        assert!(events[2]["digit_count"].is_u64());
+        assert_eq!(events[3]["evaluation"], "gave-up");
+        assert!(events[3]["answer"].is_null());
+        assert_eq!(events[3]["hints_used"], 1);
+        assert!(events[3]["steps"].as_str().unwrap().contains("Final estimation"));
+        assert_eq!(events[6]["rounds"], 1);
+        assert_eq!(events[6]["accuracy"], 0.0);
+    }
+
+    #[test]
+    fn test_answer_is_graded() {
+        let mut frontend = frontend();
+        let (Event::Problem { guesses }, None) = frontend.handle(Command::NewProblem) else {
+            panic!("expected a problem");
+        };
+
+        let values: Vec<f64> = guesses.iter().map(|&g| g as f64).collect();
+        let steps = TableBasedApproximation::estimate_geometric_mean_steps(&values).unwrap();
+        let estimate = FinalAnswer::final_answer(&steps) as u64;
+
+        let (event, result) = frontend.handle(Command::Answer { value: estimate });
+        assert!(matches!(event, Event::Result { evaluation: "correct", answer: Some(a), .. } if a == estimate));
+        assert!(result.is_some());
+        assert_eq!(frontend.stats().rounds().len(), 1);
+    }
+
+    #[test]
+    fn test_errors_keep_the_session_going() {
+        let events = run(concat!(
+            "not json\n",
+            "{\"command\":\"answer\",\"value\":100}\n",
+            "{\"command\":\"new_problem\"}\n",
+            "{\"command\":\"new_problem\"}\n",
+            "{\"command\":\"answer\",\"value\":0}\n",
+        ));
+
+        assert_eq!(event_names(&events), vec!["ready", "error", "error", "problem", "error", "error", "summary"]);
+        assert_eq!(events[6]["rounds"], 0);
+        assert!(events[6]["accuracy"].is_null());
+    }
+
+    #[test]
+    fn test_result_warnings_are_forwarded() {
+        let mut output = Vec::new();
+        let commands = "{\"command\":\"new_problem\"}\n{\"command\":\"give_up\"}\n";
+        serve(commands.as_bytes(), &mut output, &mut frontend(), |_| vec!["disk full".to_string()]).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("{\"schema_version\":1,\"event\":\"warning\",\"message\":\"disk full\"}\n"));
+    }
+}
@@ -0,0 +1,169 @@
+use std::io::{self, Write};
+use std::path::Path;
+
+use pen_and_paper_geometric_mean::history::{self, ClearFilter, ExportFormat};
+use pen_and_paper_geometric_mean::review;
+
+/// Parse the arguments following `history export`
+fn parse_export_args(args: &[String]) -> Result<ExportFormat, String> {
+    let mut format = ExportFormat::Csv;
+    let mut iter = args.iter();
+
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--format" => {
+                let value = iter.next().ok_or("--format requires a value")?;
+                format = value.parse()?;
+            }
+            other => return Err(format!("Unknown export option: {}", other)),
+        }
+    }
+
+    Ok(format)
+}
+
+fn run_export(args: &[String]) -> Result<(), String> {
+    let format = parse_export_args(args)?;
+    let path = history::default_history_path().ok_or("Could not determine the history file location")?;
+    let records = history::load_records(&path).map_err(|e| e.to_string())?;
+
+    history::export(&records, format, &mut io::stdout().lock()).map_err(|e| e.to_string())
+}
+
+fn run_import(args: &[String]) -> Result<(), String> {
+    let [source] = args else {
+        return Err("import requires exactly one file to merge".to_string());
+    };
+    let path = history::default_history_path().ok_or("Could not determine the history file location")?;
+    let added = history::import(&path, Path::new(source)).map_err(|e| format!("{}: {}", source, e))?;
+
+    println!("Imported {} new record{} into {}", added, if added == 1 { "" } else { "s" }, path.display());
+    Ok(())
+}
+
+/// Parse the arguments following `history clear`, returning the filter and whether confirmation was pre-approved
+fn parse_clear_args(args: &[String]) -> Result<(ClearFilter, bool), String> {
+    let mut filter = ClearFilter::default();
+    let mut confirmed = false;
+    let mut iter = args.iter();
+
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--before" => {
+                let date = iter.next().ok_or("--before requires a date")?;
+                let before = history::parse_date(date).ok_or_else(|| format!("Invalid date (expected YYYY-MM-DD): {}", date))?;
+                filter.before = Some(before);
+            }
+            "--profile" => {
+                let profile = iter.next().ok_or("--profile requires a name")?;
+                filter.profile = Some(profile.clone());
+            }
+            "--yes" => confirmed = true,
+            other => return Err(format!("Unknown clear option: {}", other)),
+        }
+    }
+
+    Ok((filter, confirmed))
+}
+
+/// Ask the user to confirm a destructive action, defaulting to no
+fn confirm(question: &str) -> bool {
+    print!("{} [y/N] ", question);
+    io::stdout().flush().unwrap();
+
+    let mut input = String::new();
+    if io::stdin().read_line(&mut input).is_err() {
+        return false;
+    }
+    matches!(input.trim().to_lowercase().as_str(), "y" | "yes")
+}
+
+fn run_clear(args: &[String]) -> Result<(), String> {
+    let (filter, confirmed) = parse_clear_args(args)?;
+    let path = history::default_history_path().ok_or("Could not determine the history file location")?;
+    let records = history::load_records(&path).map_err(|e| e.to_string())?;
+
+    let matching = records.iter().filter(|r| filter.matches(r)).count();
+    if matching == 0 {
+        println!("No matching records to delete.");
+        return Ok(());
+    }
+
+    let question = format!("Delete {} of {} recorded rounds from {}?", matching, records.len(), path.display());
+    if !confirmed && !confirm(&question) {
+        println!("Nothing was deleted.");
+        return Ok(());
+    }
+
+    let deleted = history::clear(&path, &filter).map_err(|e| e.to_string())?;
+    println!("Deleted {} record{}.", deleted, if deleted == 1 { "" } else { "s" });
+
+    // Problems missed in the deleted rounds shouldn't keep coming back in review
+    let bank_path = review::default_bank_path().ok_or("Could not determine the review bank location")?;
+    let mut bank = review::load(&bank_path).map_err(|e| e.to_string())?;
+    let forgotten = bank.forget_cleared(&records, &filter);
+    if forgotten > 0 {
+        review::save(&bank_path, &bank).map_err(|e| e.to_string())?;
+        println!("Removed {} missed problem{} from review.", forgotten, if forgotten == 1 { "" } else { "s" });
+    }
+    Ok(())
+}
+
+fn print_usage() {
+    println!("Usage:");
+    println!("  cargo run history export --format csv - Dump every recorded practice round");
+    println!("  cargo run history import <file>       - Merge another machine's history, skipping rounds already recorded");
+    println!("  cargo run history clear [--before YYYY-MM-DD] [--profile <name>] [--yes]");
+    println!("                                        - Delete recorded rounds, after confirmation");
+}
+
+/// Run the `history` subcommand
+pub fn run_history(args: &[String]) {
+    let outcome = match args.first().map(|s| s.as_str()) {
+        Some("export") => run_export(&args[1..]),
+        Some("import") => run_import(&args[1..]),
+        Some("clear") => run_clear(&args[1..]),
+        _ => {
+            print_usage();
+            return;
+        }
+    };
+
+    if let Err(e) = outcome {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_export_args() {
+        assert_eq!(parse_export_args(&args(&[])), Ok(ExportFormat::Csv));
+        assert_eq!(parse_export_args(&args(&["--format", "csv"])), Ok(ExportFormat::Csv));
+        assert!(parse_export_args(&args(&["--format"])).is_err());
+        assert!(parse_export_args(&args(&["--format", "xml"])).is_err());
+        assert!(parse_export_args(&args(&["--verbose"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_clear_args() {
+        assert_eq!(parse_clear_args(&args(&[])), Ok((ClearFilter::default(), false)));
+
+        let (filter, confirmed) = parse_clear_args(&args(&["--before", "2025-10-09", "--profile", "alice", "--yes"])).unwrap();
+        assert_eq!(filter.before, Some(1_759_968_000));
+        assert_eq!(filter.profile, Some("alice".to_string()));
+        assert!(confirmed);
+
+        assert!(parse_clear_args(&args(&["--before", "last week"])).is_err());
+        assert!(parse_clear_args(&args(&["--before"])).is_err());
+        assert!(parse_clear_args(&args(&["--profile"])).is_err());
+        assert!(parse_clear_args(&args(&["--all"])).is_err());
+    }
+}
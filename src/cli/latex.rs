@@ -0,0 +1,139 @@
+use pen_and_paper_geometric_mean::exact::geometric_mean;
+use pen_and_paper_geometric_mean::practice_mode::Problem;
+use pen_and_paper_geometric_mean::questions::Question;
+use pen_and_paper_geometric_mean::table_based::TableBasedApproximation;
+use pen_and_paper_geometric_mean::traits::{EstimateGeometricMeanStepByStep, FinalAnswer};
+
+use super::practice_mode::format_number;
+
+/// A number with thousands separators, braced so math mode doesn't space the commas, e.g. "2{,}000"
+fn latex_number(n: u64) -> String {
+    format_number(n).replace(',', "{,}")
+}
+
+/// A scaled log in tenths, e.g. "3.3"
+fn latex_log(scaled_log: i32) -> String {
+    format!("{:.1}", scaled_log as f64 / 10.0)
+}
+
+/// Text with LaTeX's special characters escaped, for question text
+fn latex_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '~' => escaped.push_str("\\textasciitilde{}"),
+            '^' => escaped.push_str("\\textasciicircum{}"),
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Wrap a fragment in a minimal article, to compile on its own
+pub fn standalone(fragment: &str) -> String {
+    format!("\\documentclass{{article}}\n\\begin{{document}}\n{}\\end{{document}}\n", fragment)
+}
+
+/// The worksheet as a LaTeX fragment for `\input`, with space to work each problem
+///
+/// `questions` is empty without a question bank, and otherwise holds the question each problem was drawn around.
+pub fn format_worksheet_latex(problems: &[Problem], questions: &[Question]) -> String {
+    let mut output = String::new();
+    output.push_str("\\section*{Geometric Mean Worksheet}\n");
+    output.push_str("Estimate the geometric mean of each team's guesses.\n");
+    output.push_str("\\begin{enumerate}\n");
+    for (i, problem) in problems.iter().enumerate() {
+        let guesses: Vec<String> = problem.guesses.iter().map(|&g| latex_number(g)).collect();
+        let question = questions.get(i).map_or(String::new(), |question| format!("{}\\\\\n  ", latex_text(&question.text)));
+        output.push_str(&format!("  \\item {}${}$\n\n  \\vspace{{4em}}Estimate: \\rule{{8em}}{{0.4pt}}\n", question, guesses.join(",\\ ")));
+    }
+    output.push_str("\\end{enumerate}\n");
+    output
+}
+
+/// The answer key as a LaTeX fragment, working each problem step by step with the table-based method
+pub fn format_answer_key_latex(problems: &[Problem]) -> String {
+    let mut output = String::new();
+    output.push_str("\\section*{Answer Key}\n");
+    output.push_str("\\begin{enumerate}\n");
+    for problem in problems {
+        let values: Vec<f64> = problem.guesses.iter().map(|&g| g as f64).collect();
+        let Ok(steps) = TableBasedApproximation::estimate_geometric_mean_steps(&values) else {
+            output.push_str("  \\item The table-based method needs every guess to be at least 1.\n");
+            continue;
+        };
+        let logs = steps.log_conversions();
+        let count = logs.len();
+        let estimate = steps.final_answer().round() as u64;
+
+        let conversions: Vec<String> =
+            problem.guesses.iter().zip(logs).map(|(&g, &log)| format!("{} \\to {}", latex_number(g), latex_log(log))).collect();
+        let terms: Vec<String> = logs.iter().map(|&log| latex_log(log)).collect();
+        output.push_str("  \\item Convert each guess with the table: ");
+        output.push_str(&format!("${}$.\\\\\n", conversions.join(",\\quad ")));
+        output.push_str(&format!(
+            "  Average, rounding up: $({}) \\div {} = {} \\div {} \\approx {}$.\\\\\n",
+            terms.join(" + "),
+            count,
+            latex_log(steps.sum()),
+            count,
+            latex_log(steps.average())
+        ));
+        output.push_str(&format!("  Convert back: ${} \\to \\mathbf{{{}}}$.", latex_log(steps.average()), latex_number(estimate)));
+
+        if let Ok(exact) = geometric_mean(&values) {
+            output.push_str(&format!(" (Exact geometric mean: ${}$.)", latex_number(exact.round() as u64)));
+        }
+        output.push('\n');
+    }
+    output.push_str("\\end{enumerate}\n");
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pen_and_paper_geometric_mean::table_based::TableBasedApproximation;
+    use pen_and_paper_geometric_mean::traits::EstimateGeometricMean;
+
+    fn problems() -> Vec<Problem> {
+        vec![Problem { guesses: vec![2_000, 50, 350], correct_answer: 300 }]
+    }
+
+    #[test]
+    fn test_format_worksheet_latex() {
+        let worksheet = format_worksheet_latex(&problems(), &[]);
+        assert!(worksheet.starts_with("\\section*{Geometric Mean Worksheet}\n"));
+        assert!(worksheet.contains("  \\item $2{,}000,\\ 50,\\ 350$\n"));
+        assert!(worksheet.ends_with("\\end{enumerate}\n"));
+        assert!(standalone(&worksheet).starts_with("\\documentclass{article}\n\\begin{document}\n\\section*"));
+    }
+
+    #[test]
+    fn test_worksheet_latex_shows_bank_questions() {
+        let question = Question { text: "Cost of 100 #2 pencils, in $".to_string(), answer: 25, category: None, source: None };
+        let worksheet = format_worksheet_latex(&problems(), &[question]);
+        assert!(worksheet.contains("  \\item Cost of 100 \\#2 pencils, in \\$\\\\\n  $2{,}000,\\ 50,\\ 350$\n"), "{}", worksheet);
+    }
+
+    #[test]
+    fn test_format_answer_key_latex() {
+        let key = format_answer_key_latex(&problems());
+        assert!(key.contains("$2{,}000 \\to 3.3,\\quad 50 \\to 1.7,\\quad 350 \\to 2.5$"));
+        assert!(key.contains("$(3.3 + 1.7 + 2.5) \\div 3 = 7.5 \\div 3 \\approx 2.5$"));
+        assert!(key.contains("Convert back: $2.5 \\to \\mathbf{300}$. (Exact geometric mean: $327$.)\n"));
+    }
+
+    #[test]
+    fn test_answer_key_matches_table_based() {
+        let problem = Problem { guesses: vec![9_001, 12_000, 7], correct_answer: 1_000 };
+        let values: Vec<f64> = problem.guesses.iter().map(|&g| g as f64).collect();
+        let estimate = TableBasedApproximation::estimate_geometric_mean(&values).unwrap().round() as u64;
+        assert!(format_answer_key_latex(&[problem]).contains(&format!("\\mathbf{{{}}}", latex_number(estimate))));
+    }
+}
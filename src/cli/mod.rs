@@ -1 +1,25 @@
-pub mod practice_mode;
\ No newline at end of file
+pub mod advise;
+pub mod bench;
+pub mod benchmark;
+pub mod budget;
+pub mod calibrate;
+pub mod cheat_sheet;
+pub mod compare;
+pub mod countdown;
+pub mod doctor;
+pub mod drill;
+pub mod duration;
+pub mod explain;
+pub mod frontend;
+pub mod history;
+pub mod latex;
+pub mod practice_mode;
+pub mod questions;
+pub mod schema;
+pub mod simulate;
+pub mod snapshots;
+pub mod sparkline;
+pub mod stats;
+pub mod transcript;
+pub mod tutorial;
+pub mod worksheet;
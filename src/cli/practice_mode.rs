@@ -1,10 +1,29 @@
 use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
 
-use crate::practice_mode::{
-    AnswerEvaluation, PracticeModeConfig, PracticeSession, Ready, SystemTimer,
+use crate::cli::countdown;
+use crate::cli::drill::run_warm_up;
+use crate::cli::duration::DurationFormat;
+use crate::cli::transcript::Transcript;
+use crate::cli::tutorial::{LOCKED_MESSAGE, has_practice_history, practice_unlocked};
+use pen_and_paper_geometric_mean::config::{self, UserConfig};
+use pen_and_paper_geometric_mean::history::{self, HistoryRecord, SessionContext};
+use pen_and_paper_geometric_mean::questions::{self, Question};
+use pen_and_paper_geometric_mean::ranges::{self, GuessRange, RangeProblem};
+use pen_and_paper_geometric_mean::rng;
+use pen_and_paper_geometric_mean::spread::{self, SpreadResult};
+use pen_and_paper_geometric_mean::weighted::{ConfidenceWeightedTable, WeightedGuess, WeightedProblem};
+use pen_and_paper_geometric_mean::review::{self, ReviewBank};
+use pen_and_paper_geometric_mean::practice_mode::{
+    ActiveSession, AnswerEvaluation, Hint, PracticeModeConfig, PracticeResult, PracticeSession, Problem, Ready,
+    SessionStats, SystemTimer, Timer, parse_user_input,
 };
-use crate::table_based::TableBasedApproximation;
-use rand::{SeedableRng, rngs::StdRng};
+pub use pen_and_paper_geometric_mean::practice_mode::format_number;
+use pen_and_paper_geometric_mean::harmonic::ReciprocalTableApproximation;
+use pen_and_paper_geometric_mean::median::{LowerQuartileMethod, MedianMethod, UpperQuartileMethod};
+use pen_and_paper_geometric_mean::table_based::TableBasedApproximation;
+use pen_and_paper_geometric_mean::traits::{EstimateGeometricMean, EstimateGeometricMeanStepByStep, MethodMetadata};
 
 /// Format problem display for consistent presentation
 pub fn format_problem_display(guesses: &[u64]) -> String {
@@ -18,14 +37,94 @@ pub fn format_problem_display(guesses: &[u64]) -> String {
     output
 }
 
+/// Format the team's ranges, shown instead of point guesses in range practice
+pub fn format_range_display(ranges: &[GuessRange]) -> String {
+    let mut output = String::new();
+    output.push_str("Here are the team's ranges:\n");
+
+    for (i, range) in ranges.iter().enumerate() {
+        output.push_str(&format!("  {}. {} – {}\n", i + 1, format_number(range.low), format_number(range.high)));
+    }
+
+    output
+}
+
+/// Describe the ranges' overlap, the alternative to averaging their midpoints, against the true answer
+pub fn format_range_overlap(ranges: &[GuessRange], correct_answer: u64) -> String {
+    match ranges::intersection(ranges) {
+        Some(overlap) => format!(
+            "Every range overlaps from {} to {}, with a geometric midpoint of {:.1}; the true answer {} {} inside it\n",
+            format_number(overlap.low),
+            format_number(overlap.high),
+            overlap.geometric_midpoint(),
+            format_number(correct_answer),
+            if (overlap.low..=overlap.high).contains(&correct_answer) { "was" } else { "wasn't" }
+        ),
+        None => "The ranges don't all overlap, so averaging their midpoints is the only estimate\n".to_string(),
+    }
+}
+
+/// Format the team's guesses with their confidences, for confidence-weighted practice
+pub fn format_weighted_display(guesses: &[WeightedGuess]) -> String {
+    let mut output = String::new();
+    output.push_str("Here are the team's guesses, each with a confidence from 1 to 5:\n");
+
+    for (i, guess) in guesses.iter().enumerate() {
+        output.push_str(&format!("  {}. {} (confidence {})\n", i + 1, format_number(guess.guess), guess.confidence));
+    }
+
+    output
+}
+
+/// A problem posed as something other than point guesses, graded as its equivalent point-guess problem
+enum PosedProblem {
+    Ranges(RangeProblem),
+    Weighted(WeightedProblem),
+}
+
+impl PosedProblem {
+    fn to_problem(&self) -> Problem {
+        match self {
+            PosedProblem::Ranges(problem) => problem.to_problem(),
+            PosedProblem::Weighted(problem) => problem.to_problem(),
+        }
+    }
+
+    /// Working that needs more than the point guesses carry, like each guess's confidence
+    fn worked_steps(&self) -> Option<String> {
+        match self {
+            PosedProblem::Ranges(_) => None,
+            PosedProblem::Weighted(problem) => {
+                ConfidenceWeightedTable::weighted_steps(&problem.guesses).ok().map(|steps| steps.to_string())
+            }
+        }
+    }
+}
+
 /// Format results display for consistent presentation
-pub fn format_results_display<E>(result: &crate::practice_mode::PracticeResult<E>) -> String
+pub fn format_results_display<E>(
+    result: &pen_and_paper_geometric_mean::practice_mode::PracticeResult<E>,
+    time_format: &DurationFormat,
+) -> String
 where
-    E: crate::traits::EstimateGeometricMeanStepByStep,
+    E: pen_and_paper_geometric_mean::traits::EstimateGeometricMeanStepByStep + MethodMetadata,
+    E::StepByStep: std::fmt::Display,
+{
+    format_worked_results_display(result, time_format, None)
+}
+
+/// Format results display, showing `worked` in place of the method's own working when given
+fn format_worked_results_display<E>(
+    result: &pen_and_paper_geometric_mean::practice_mode::PracticeResult<E>,
+    time_format: &DurationFormat,
+    worked: Option<&str>,
+) -> String
+where
+    E: pen_and_paper_geometric_mean::traits::EstimateGeometricMeanStepByStep + MethodMetadata,
     E::StepByStep: std::fmt::Display,
 {
     let user_answer = result.user_answer;
-    let exact_mean = result.exact_geometric_mean;
+    let exact_mean = result.exact_mean;
     let estimation_result = result.estimation_result;
     let duration = result.duration;
     let evaluation = &result.evaluation;
@@ -33,10 +132,14 @@ where
 
     output.push_str("Results:\n");
     output.push_str("========\n");
-    output.push_str(&format!("Your answer: {}\n", format_number(user_answer)));
-    output.push_str(&format!("Exact geometric mean: {:.1}\n", exact_mean));
+    if *evaluation == AnswerEvaluation::GaveUp {
+        output.push_str("Your answer: (gave up)\n");
+    } else {
+        output.push_str(&format!("Your answer: {}\n", format_number(user_answer)));
+    }
+    output.push_str(&format!("Exact {}: {:.1}\n", E::MEAN, exact_mean));
     output.push_str(&format!("Estimation method result: {}\n", format_number(estimation_result)));
-    output.push_str(&format!("Time taken: {:.1} seconds\n", duration.as_secs_f64()));
+    output.push_str(&format!("Time taken: {}\n", time_format.format(duration)));
     output.push('\n');
 
     match evaluation {
@@ -49,163 +152,832 @@ where
         AnswerEvaluation::Incorrect => {
             output.push_str("You have calculated the estimation method incorrectly.\n");
             output.push('\n');
-            output.push_str("Step-by-step calculation:\n");
-            output.push_str("========================\n");
+            write_step_by_step(&mut output, result, worked).expect("writing to a String cannot fail");
+        }
+        AnswerEvaluation::GaveUp => {
+            output.push_str("You gave up. Here is how the estimation method works it out.\n");
+            output.push('\n');
+            write_step_by_step(&mut output, result, worked).expect("writing to a String cannot fail");
+        }
+    }
 
-            match result.get_step_by_step() {
-                Ok(steps) => {
-                    output.push_str(&format!("{}", steps));
+    output
+}
+
+/// Format a hint revealed during a round
+pub fn format_hint(hint: &Hint) -> String {
+    match hint {
+        Hint::DigitCount(digits) => format!("Hint: the estimate has {} digits\n", digits),
+        Hint::LeadingDigits(leading) => format!("Hint: the estimate starts with {}\n", leading),
+    }
+}
+
+/// Format the reveal of the hidden true answer and how the team fared against it
+pub fn format_reveal_display<E: MethodMetadata>(result: &PracticeResult<E>) -> String {
+    let correct_answer = result.correct_answer;
+    let mut output = String::new();
+
+    output.push_str(&format!("The true answer was: {}\n", format_number(correct_answer)));
+
+    let relative_error = (result.exact_mean - correct_answer as f64) / correct_answer as f64;
+    let direction = if relative_error >= 0.0 { "above" } else { "below" };
+    output.push_str(&format!(
+        "The exact {} was {:.1}% {} the true answer\n",
+        E::MEAN,
+        relative_error.abs() * 100.0,
+        direction
+    ));
+
+    if let Some((index, guess)) = result.closest_guess() {
+        output.push_str(&format!("Closest guess: teammate {} with {}\n", index + 1, format_number(guess)));
+    }
+
+    output
+}
+
+/// Write the worked step-by-step solution for a result into `sink`, without building intermediate strings
+///
+/// `worked` replaces the method's own working, for problems posed with more than the values carry.
+pub fn write_step_by_step<E>(
+    sink: &mut impl std::fmt::Write,
+    result: &pen_and_paper_geometric_mean::practice_mode::PracticeResult<E>,
+    worked: Option<&str>,
+) -> std::fmt::Result
+where
+    E: pen_and_paper_geometric_mean::traits::EstimateGeometricMeanStepByStep,
+    E::StepByStep: std::fmt::Display,
+{
+    sink.write_str("Step-by-step calculation:\n")?;
+    sink.write_str("========================\n")?;
+
+    if let Some(worked) = worked {
+        return writeln!(sink, "{}", worked);
+    }
+    match result.get_step_by_step() {
+        Ok(steps) => writeln!(sink, "{}", steps),
+        Err(_) => sink.write_str("Error calculating step-by-step display\n"),
+    }
+}
+
+/// Format a graded spread estimate, and how far the spread suggests trusting the team's mean
+pub fn format_spread_result(result: &SpreadResult) -> String {
+    let verdict = match result.steps_off {
+        0 => "spot on".to_string(),
+        steps if result.is_close() => format!("within one table step ({})", if steps > 0 { "slightly wide" } else { "slightly narrow" }),
+        steps => format!("{} table steps too {}", steps.abs(), if steps > 0 { "wide" } else { "narrow" }),
+    };
+    format!(
+        "Spread: you said ×{}, and the guesses' geometric standard deviation is ×{:.2}, {}\n\
+         With {} guesses that spread, their geometric mean typically misses the true answer by ×{:.2}\n",
+        result.estimate,
+        result.exact,
+        verdict,
+        result.team_size,
+        result.mean_miss_factor()
+    )
+}
+
+/// Format the share of spread estimates within one table step over a session
+pub fn format_spread_summary(close: usize, total: usize) -> String {
+    if total == 0 {
+        return String::new();
+    }
+    format!("Spread estimates within one table step: {} of {}\n", close, total)
+}
+
+/// Format the worked step-by-step solution for a result, or `worked` in place of the method's own working
+pub fn format_step_by_step<E>(
+    result: &pen_and_paper_geometric_mean::practice_mode::PracticeResult<E>,
+    worked: Option<&str>,
+) -> String
+where
+    E: pen_and_paper_geometric_mean::traits::EstimateGeometricMeanStepByStep,
+    E::StepByStep: std::fmt::Display,
+{
+    let mut output = String::new();
+    write_step_by_step(&mut output, result, worked).expect("writing to a String cannot fail");
+    output
+}
+
+/// Command line options for practice mode
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PracticeOptions {
+    /// Show the worked solution after every round, not just incorrect ones
+    pub show_work: bool,
+    /// File to record a replayable transcript of the session to
+    pub transcript: Option<PathBuf>,
+    /// Time limit per problem, overriding the config file
+    pub time_limit: Option<Duration>,
+    /// Decimal places shown on solve times, overriding the config file
+    pub time_format: Option<DurationFormat>,
+    /// Ring the terminal bell in a distinct pattern for each answer tier
+    pub bell: bool,
+    /// Serve previously missed problems instead of new ones
+    pub review: bool,
+    /// Labels to attach to the session in history
+    pub tags: Vec<String>,
+    /// Free-text note to attach to the session in history
+    pub note: Option<String>,
+    /// Name of the person practicing, stored in history
+    pub profile: Option<String>,
+    /// Don't record this session to history or the review bank
+    pub no_history: bool,
+    /// Drive the session with JSON-lines commands and events on stdio instead of the terminal prompts
+    pub frontend_protocol: bool,
+    /// Name of the method to practice, one of [`PRACTICE_METHODS`]; table-based when unset
+    pub method: Option<String>,
+    /// Question bank whose true answers the team's guesses are drawn around, instead of random ones
+    pub question_bank: Option<PathBuf>,
+    /// Show each teammate's guess as a low–high range, combined by the geometric mean of the endpoints
+    pub ranges: bool,
+    /// Give each guess a confidence from 1 to 5, to be combined by the confidence-weighted geometric mean
+    pub weighted: bool,
+    /// After each answer, also estimate the guesses' spread, graded against their geometric standard deviation
+    pub spread: bool,
+    /// Warm-up questions before the first problem, overriding the config file; 0 skips the warm-up
+    pub warm_up: Option<usize>,
+}
+
+/// Names of the methods practice mode can drill
+pub const PRACTICE_METHODS: [&str; 5] = [
+    TableBasedApproximation::NAME,
+    ReciprocalTableApproximation::NAME,
+    MedianMethod::NAME,
+    LowerQuartileMethod::NAME,
+    UpperQuartileMethod::NAME,
+];
+
+impl PracticeOptions {
+    /// Parse the arguments following `practice`
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut options = PracticeOptions::default();
+
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--show-work" => options.show_work = true,
+                "--bell" => options.bell = true,
+                "--review" => options.review = true,
+                "--ranges" => options.ranges = true,
+                "--weighted" => options.weighted = true,
+                "--spread" => options.spread = true,
+                "--tag" => {
+                    let tag = iter.next().ok_or("--tag requires a label")?;
+                    options.tags.push(tag.clone());
+                }
+                "--note" => {
+                    let note = iter.next().ok_or("--note requires text")?;
+                    options.note = Some(note.clone());
+                }
+                "--profile" => {
+                    let profile = iter.next().ok_or("--profile requires a name")?;
+                    options.profile = Some(profile.clone());
+                }
+                "--no-history" => options.no_history = true,
+                "--frontend-protocol" => options.frontend_protocol = true,
+                "--harmonic" => options.method = Some(ReciprocalTableApproximation::NAME.to_string()),
+                "--method" => {
+                    let method = iter.next().ok_or("--method requires a method name")?;
+                    if !PRACTICE_METHODS.contains(&method.as_str()) {
+                        return Err(format!("Unknown practice method: {} (expected one of {})", method, PRACTICE_METHODS.join(", ")));
+                    }
+                    options.method = Some(method.clone());
+                }
+                "--transcript" => {
+                    let path = iter.next().ok_or("--transcript requires a file path")?;
+                    options.transcript = Some(PathBuf::from(path));
+                }
+                "--bank" => {
+                    let path = iter.next().ok_or("--bank requires a question bank file")?;
+                    options.question_bank = Some(PathBuf::from(path));
+                }
+                "--warm-up" => {
+                    let count = iter.next().ok_or("--warm-up requires a number of questions")?;
+                    options.warm_up = Some(count.parse().map_err(|_| format!("Invalid warm-up length: {}", count))?);
                 }
-                Err(_) => {
-                    output.push_str("Error calculating step-by-step display");
+                "--time-limit" => {
+                    let seconds = iter.next().ok_or("--time-limit requires a number of seconds")?;
+                    let seconds: u64 = seconds.parse().map_err(|_| format!("Invalid time limit: {}", seconds))?;
+                    options.time_limit = Some(Duration::from_secs(seconds));
                 }
+                "--time-precision" => {
+                    let precision = iter.next().ok_or("--time-precision requires a number of decimal places")?;
+                    options.time_format = Some(DurationFormat::parse(precision)?);
+                }
+                other => return Err(format!("Unknown practice option: {}", other)),
             }
-            output.push('\n');
         }
+
+        let terminal_only = options.show_work
+            || options.bell
+            || options.review
+            || options.transcript.is_some()
+            || options.time_limit.is_some()
+            || options.time_format.is_some()
+            || options.question_bank.is_some()
+            || options.ranges
+            || options.weighted
+            || options.spread
+            || options.warm_up.is_some();
+        if options.review && options.question_bank.is_some() {
+            return Err("--review re-serves missed problems, so it doesn't combine with --bank".to_string());
+        }
+        if options.review && options.ranges {
+            return Err("--review re-serves missed problems, so it doesn't combine with --ranges".to_string());
+        }
+        if options.ranges && options.method.as_deref().is_some_and(|method| method != TableBasedApproximation::NAME) {
+            return Err("--ranges combines ranges by their geometric mean, so it only works with table-based".to_string());
+        }
+        if options.weighted && (options.review || options.ranges || options.method.is_some()) {
+            return Err("--weighted is its own method, so it doesn't combine with --review, --ranges, --method, or --harmonic".to_string());
+        }
+        if options.spread && (options.ranges || options.weighted) {
+            return Err("--spread estimates the spread of point guesses, so it doesn't combine with --ranges or --weighted".to_string());
+        }
+        if options.frontend_protocol && terminal_only {
+            return Err("--frontend-protocol only combines with --method, --harmonic, --tag, --note, --profile, and --no-history".to_string());
+        }
+
+        Ok(options)
     }
+}
 
-    output
+/// Format a question from a question bank, shown above the team's guesses
+pub fn format_question_display(question: &Question) -> String {
+    match &question.category {
+        Some(category) => format!("Question ({}): {}\n", category, question.text),
+        None => format!("Question: {}\n", question.text),
+    }
 }
 
-/// Format numbers with thousands separators for display
-fn format_number(n: u64) -> String {
-    let s = n.to_string();
-    let mut result = String::new();
+/// Number of terminal bells rung for each answer tier, so results can be told apart without reading
+fn bell_count(evaluation: &AnswerEvaluation) -> usize {
+    match evaluation {
+        AnswerEvaluation::Correct => 1,
+        AnswerEvaluation::Excellent => 2,
+        AnswerEvaluation::Incorrect | AnswerEvaluation::GaveUp => 3,
+    }
+}
 
-    for (i, c) in s.chars().rev().enumerate() {
-        if i > 0 && i % 3 == 0 {
-            result.push(',');
+/// Ring the terminal bell in the pattern for an answer tier
+fn ring_bell(evaluation: &AnswerEvaluation) {
+    for i in 0..bell_count(evaluation) {
+        if i > 0 {
+            // Consecutive bells merge into one sound without a pause
+            std::thread::sleep(Duration::from_millis(200));
         }
-        result.push(c);
+        print!("\x07");
+        io::stdout().flush().unwrap();
     }
+}
 
-    result.chars().rev().collect()
+/// Short display name for an answer tier
+fn evaluation_label(evaluation: &AnswerEvaluation) -> &'static str {
+    match evaluation {
+        AnswerEvaluation::Correct => "Correct",
+        AnswerEvaluation::Excellent => "Excellent",
+        AnswerEvaluation::Incorrect => "Incorrect",
+        AnswerEvaluation::GaveUp => "Gave up",
+    }
 }
 
-/// Parse user input as u64, handling validation
-fn parse_user_input(input: &str) -> Result<u64, String> {
-    let trimmed = input.trim();
+/// Format the end-of-session table of rounds, with each round's time and the running total, and aggregate statistics
+pub fn format_session_summary(stats: &SessionStats, time_format: &DurationFormat) -> String {
+    let mut output = String::new();
+    if stats.rounds().is_empty() {
+        return output;
+    }
+
+    output.push_str("Session Summary\n");
+    output.push_str("===============\n");
+
+    let answers: Vec<String> = stats
+        .rounds()
+        .iter()
+        .map(|r| if r.evaluation == AnswerEvaluation::GaveUp { "-".to_string() } else { format_number(r.user_answer) })
+        .collect();
+    let answer_width = answers.iter().map(|a| a.len()).max().unwrap_or(0).max("Answer".len());
+    let times: Vec<String> = stats.rounds().iter().map(|r| time_format.format(r.duration)).collect();
+    let time_width = times.iter().map(|t| t.len()).max().unwrap_or(0).max("Time".len());
+    let elapsed: Vec<String> = stats
+        .rounds()
+        .iter()
+        .scan(Duration::ZERO, |total, r| {
+            *total += r.duration;
+            Some(time_format.format(*total))
+        })
+        .collect();
+    let elapsed_width = elapsed.iter().map(|t| t.len()).max().unwrap_or(0).max("Elapsed".len());
 
-    if trimmed.is_empty() {
-        return Err("Please enter a number".to_string());
+    output.push_str(&format!(
+        "Round  {:>answer_width$}  {:<9}  {:>time_width$}  {:>elapsed_width$}  Hints\n",
+        "Answer", "Tier", "Time", "Elapsed"
+    ));
+    for (i, round) in stats.rounds().iter().enumerate() {
+        output.push_str(&format!(
+            "{:>5}  {:>answer_width$}  {:<9}  {:>time_width$}  {:>elapsed_width$}  {:>5}\n",
+            i + 1,
+            answers[i],
+            evaluation_label(&round.evaluation),
+            times[i],
+            elapsed[i],
+            round.hints_used
+        ));
     }
+    output.push('\n');
 
-    // Remove commas for parsing
-    let cleaned = trimmed.replace(',', "");
+    output.push_str(&format!("Rounds played: {}\n", stats.rounds().len()));
+    output.push_str(&format!(
+        "Correct: {}, Excellent: {}, Incorrect: {}, Gave up: {}\n",
+        stats.count(&AnswerEvaluation::Correct),
+        stats.count(&AnswerEvaluation::Excellent),
+        stats.count(&AnswerEvaluation::Incorrect),
+        stats.count(&AnswerEvaluation::GaveUp)
+    ));
+    if let Some(accuracy) = stats.accuracy() {
+        output.push_str(&format!("Accuracy: {:.1}%\n", accuracy * 100.0));
+    }
+    if let Some(average) = stats.average_duration() {
+        output.push_str(&format!("Average time: {}\n", time_format.format(average)));
+    }
+    if let Some(fastest) = stats.fastest_duration() {
+        output.push_str(&format!("Fastest time: {}\n", time_format.format(fastest)));
+    }
+    output.push_str(&format!("Total time: {}\n", time_format.format(stats.total_duration())));
 
-    match cleaned.parse::<u64>() {
-        Ok(value) => {
-            if value == 0 {
-                Err("Please enter a positive number".to_string())
-            } else {
-                Ok(value)
+    output
+}
+
+
+/// What the user entered at the answer prompt
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnswerInput {
+    Answer(u64),
+    Hint,
+    GiveUp,
+    Skip,
+}
+
+/// Parse the answer prompt, recognizing the `?`, `g`, and `s` shortcuts before numeric answers
+fn parse_answer_input(input: &str) -> Result<AnswerInput, String> {
+    match input.trim().to_lowercase().as_str() {
+        "?" => Ok(AnswerInput::Hint),
+        "g" => Ok(AnswerInput::GiveUp),
+        "s" => Ok(AnswerInput::Skip),
+        _ => parse_user_input(input).map(AnswerInput::Answer),
+    }
+}
+
+/// Prompt user for input with validation and retry
+///
+/// With a time limit on an interactive terminal, a live countdown since `started` is shown above the prompt.
+pub fn prompt_for_answer(transcript: &mut Transcript, time_limit: Option<Duration>, started: Instant) -> AnswerInput {
+    let countdown = time_limit.filter(|_| countdown::is_supported());
+
+    loop {
+        if let Some(limit) = countdown {
+            countdown::start_status_line(limit, started);
+        }
+        transcript.output("Enter your estimated geometric mean (? hint, g give up, s skip): ");
+
+        let read = match countdown {
+            Some(limit) => countdown::read_line_with_countdown(limit, started),
+            None => {
+                let mut input = String::new();
+                io::stdin().read_line(&mut input).map(|_| input)
+            }
+        };
+        let Ok(input) = read else {
+            transcript.output("Error reading input. Please try again.\n");
+            continue;
+        };
+        transcript.input(&input);
+
+        match parse_answer_input(&input) {
+            Ok(value) => return value,
+            Err(error) => {
+                transcript.output(&format!("Invalid input: {}. Please try again.\n", error));
             }
         }
-        Err(_) => {
-            if cleaned.contains('.') {
-                Err("Please enter a whole number (no decimals)".to_string())
-            } else if cleaned.starts_with('-') {
-                Err("Please enter a positive number".to_string())
-            } else {
-                Err("Please enter a valid number".to_string())
+    }
+}
+
+/// Play out the answer prompt for an active problem, handling hints until the user answers, gives up, or skips
+///
+/// Returns `None` when the problem was skipped.
+pub fn play_round<T, E>(
+    transcript: &mut Transcript,
+    mut active_session: ActiveSession<T, E>,
+    time_limit: Option<Duration>,
+) -> Option<PracticeResult<E>>
+where
+    T: Timer,
+    E: EstimateGeometricMean,
+{
+    let started = Instant::now();
+
+    loop {
+        match prompt_for_answer(transcript, time_limit, started) {
+            AnswerInput::Answer(user_answer) => return Some(active_session.submit_answer(user_answer)),
+            AnswerInput::GiveUp => return Some(active_session.give_up()),
+            AnswerInput::Skip => {
+                active_session.skip();
+                transcript.output("Skipped.\n");
+                return None;
             }
+            AnswerInput::Hint => match active_session.hint() {
+                Some(hint) => transcript.output(&format_hint(&hint)),
+                None => transcript.output("No more hints available.\n"),
+            },
         }
     }
 }
 
-/// Prompt user for input with validation and retry
-fn prompt_for_answer() -> u64 {
+/// Prompt for an estimate of the guesses' spread, as a factor, until one parses
+pub fn prompt_for_spread(transcript: &mut Transcript) -> f64 {
     loop {
-        print!("Enter your estimated geometric mean: ");
-        io::stdout().flush().unwrap();
+        transcript.output("Estimate the spread of the guesses, as a factor (e.g. 3 for ×3): ");
 
         let mut input = String::new();
         if io::stdin().read_line(&mut input).is_err() {
-            println!("Error reading input. Please try again.");
+            transcript.output("Error reading input. Please try again.\n");
             continue;
         }
+        transcript.input(&input);
 
-        match parse_user_input(&input) {
-            Ok(value) => return value,
-            Err(error) => {
-                println!("Invalid input: {}. Please try again.", error);
-            }
+        match spread::parse_spread(&input) {
+            Ok(estimate) => return estimate,
+            Err(error) => transcript.output(&format!("Invalid input: {}. Please try again.\n", error)),
         }
     }
 }
 
 /// Prompt user for continue/exit choice
-fn prompt_for_continue() -> bool {
+pub fn prompt_for_continue(transcript: &mut Transcript) -> bool {
     loop {
-        print!("Continue with another problem? (y/n): ");
-        io::stdout().flush().unwrap();
+        transcript.output("Continue with another problem? (y/n): ");
 
         let mut input = String::new();
         if io::stdin().read_line(&mut input).is_err() {
-            println!("Error reading input. Please try again.");
+            transcript.output("Error reading input. Please try again.\n");
             continue;
         }
+        transcript.input(&input);
 
         match input.trim().to_lowercase().as_str() {
             "y" | "yes" => return true,
             "n" | "no" => return false,
             _ => {
-                println!("Please enter 'y' for yes or 'n' for no.");
+                transcript.output("Please enter 'y' for yes or 'n' for no.\n");
             }
         }
     }
 }
 
+/// Append a finished round to the practice history, warning rather than failing on errors
+pub fn record_history<E: MethodMetadata>(result: &PracticeResult<E>, session: &SessionContext) {
+    if let Err(e) = try_record_history(result, session) {
+        println!("Warning: could not record practice history: {}", e);
+    }
+}
+
+/// Append a finished round to the practice history
+pub fn try_record_history<E: MethodMetadata>(
+    result: &PracticeResult<E>,
+    session: &SessionContext,
+) -> Result<(), history::HistoryError> {
+    let Some(path) = history::default_history_path() else {
+        return Ok(());
+    };
+
+    let record = HistoryRecord::from_result(result, session, history::unix_timestamp_now());
+    history::append_record(&path, &record)
+}
+
+/// Add a missed round's problem to the review bank, warning rather than failing on errors
+pub fn bank_missed_problem<E: MethodMetadata>(result: &PracticeResult<E>) {
+    if let Err(e) = try_bank_missed_problem(result) {
+        println!("Warning: could not save missed problem for review: {}", e);
+    }
+}
+
+/// Add a missed round's problem to the review bank
+pub fn try_bank_missed_problem<E: MethodMetadata>(result: &PracticeResult<E>) -> Result<(), review::ReviewError> {
+    let Some(path) = review::default_bank_path() else {
+        return Ok(());
+    };
+
+    let mut bank = review::load(&path)?;
+    bank.add_miss(&result.problem(), E::NAME);
+    review::save(&path, &bank)
+}
+
+/// Problems being re-served in a `--review` session, and the position of the next one to serve
+///
+/// Only problems missed with the session's method are served; the rest stay banked for their own method.
+struct ReviewQueue {
+    path: PathBuf,
+    bank: ReviewBank,
+    method: &'static str,
+    next: usize,
+}
+
+impl ReviewQueue {
+    fn load(method: &'static str) -> Result<Self, String> {
+        let path = review::default_bank_path().ok_or("Could not determine the review bank location")?;
+        let bank = review::load(&path).map_err(|e| e.to_string())?;
+        Ok(ReviewQueue { path, bank, method, next: 0 })
+    }
+
+    /// Bank indices of the problems missed with this session's method
+    fn pending(&self) -> Vec<usize> {
+        (0..self.bank.problems().len()).filter(|&i| self.bank.problems()[i].method == self.method).collect()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pending().is_empty()
+    }
+
+    /// The next problem to serve, cycling through the bank, along with a progress label
+    fn next_problem(&mut self) -> (Problem, String) {
+        let pending = self.pending();
+        let position = pending.iter().position(|&i| i >= self.next).unwrap_or(0);
+        self.next = pending[position];
+
+        let missed = &self.bank.problems()[self.next];
+        let label = format!(
+            "Review problem {} of {} (answered correctly {} of {} times)\n",
+            position + 1,
+            pending.len(),
+            missed.correct_answers,
+            review::REQUIRED_CORRECT_ANSWERS
+        );
+        (missed.problem(), label)
+    }
+
+    fn skip(&mut self) {
+        self.next += 1;
+    }
+
+    /// Record the outcome for the problem just served, returning whether it was retired
+    fn record(&mut self, evaluation: &AnswerEvaluation) -> bool {
+        let retired = self.bank.record_review(self.next, evaluation.is_success());
+        if !retired {
+            // A retired problem is removed, so the next one has already moved into its place
+            self.next += 1;
+        }
+        if let Err(e) = review::save(&self.path, &self.bank) {
+            println!("Warning: could not save review progress: {}", e);
+        }
+        retired
+    }
+}
+
+/// Load the user's config file along with the practice configuration it describes
+pub fn load_practice_config() -> Result<(UserConfig, PracticeModeConfig), String> {
+    let user_config = config::load_default().map_err(|e| e.to_string())?;
+    let practice_config = user_config.practice_config().map_err(|e| e.to_string())?;
+    Ok((user_config, practice_config))
+}
+
 /// Run the practice mode CLI
-pub fn run_practice_mode() {
-    println!("Practice Mode - Table-Based Geometric Mean");
-    println!("=========================================");
-    println!();
+pub fn run_practice_mode(options: PracticeOptions) {
+    // New users take the tutorial first; a config that fails to load is reported by the session itself
+    let user_config = config::load_default();
+    let unlocked = user_config.as_ref().map_or(true, |user_config| practice_unlocked(user_config, has_practice_history()));
+    if !unlocked {
+        println!("{}", LOCKED_MESSAGE);
+        return;
+    }
+
+    let warm_up = options.warm_up.unwrap_or_else(|| user_config.map_or(0, |user_config| user_config.warm_up_questions));
+    if warm_up > 0 {
+        run_warm_up(warm_up, &options.time_format.unwrap_or_else(DurationFormat::from_config));
+    }
 
-    // Fixed configuration as specified in the plan
-    let config = PracticeModeConfig::new(4, 4.0, 10, 1_000_000_000).unwrap();
+    if options.weighted {
+        run_practice_session::<ConfidenceWeightedTable>(options, "Practice Mode - Confidence-Weighted Geometric Mean");
+        return;
+    }
+    match options.method.as_deref().unwrap_or(TableBasedApproximation::NAME) {
+        ReciprocalTableApproximation::NAME => {
+            run_practice_session::<ReciprocalTableApproximation>(options, "Practice Mode - Reciprocal-Table Harmonic Mean")
+        }
+        MedianMethod::NAME => run_practice_session::<MedianMethod>(options, "Practice Mode - Median"),
+        LowerQuartileMethod::NAME => run_practice_session::<LowerQuartileMethod>(options, "Practice Mode - Lower Quartile"),
+        UpperQuartileMethod::NAME => run_practice_session::<UpperQuartileMethod>(options, "Practice Mode - Upper Quartile"),
+        _ => run_practice_session::<TableBasedApproximation>(options, "Practice Mode - Table-Based Geometric Mean"),
+    }
+}
+
+/// Run practice mode with the estimation method `E`
+fn run_practice_session<E>(options: PracticeOptions, title: &str)
+where
+    E: EstimateGeometricMean + EstimateGeometricMeanStepByStep + MethodMetadata,
+    E::StepByStep: std::fmt::Display,
+{
+    let mut transcript = match &options.transcript {
+        Some(path) => match Transcript::create(path) {
+            Ok(transcript) => transcript,
+            Err(e) => {
+                println!("Error creating transcript {}: {}", path.display(), e);
+                return;
+            }
+        },
+        None => Transcript::disabled(),
+    };
+
+    transcript.output(&format!("{}\n{}\n", title, "=".repeat(title.chars().count())));
+    transcript.output("\n");
+
+    // Configuration comes from the user's config file, defaulting to the original expert settings
+    let (user_config, config) = match load_practice_config() {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            transcript.output(&format!("Error loading configuration: {}\n", e));
+            return;
+        }
+    };
+
+    let time_limit = options.time_limit.or(user_config.time_limit());
+    let time_format = match options.time_format.map_or_else(|| DurationFormat::new(user_config.time_precision), Ok) {
+        Ok(time_format) => time_format,
+        Err(e) => {
+            transcript.output(&format!("Error loading configuration: {}\n", e));
+            return;
+        }
+    };
+    let bell = options.bell || user_config.bell;
+    let keep_history = user_config.history && !options.no_history;
+
+    let question_bank = match &options.question_bank {
+        Some(path) => match questions::load(path) {
+            Ok(bank) => Some(bank),
+            Err(e) => {
+                transcript.output(&format!("Error loading {}: {}\n", path.display(), e));
+                return;
+            }
+        },
+        None => None,
+    };
 
     // Use system-generated seed for variety
-    let mut rng = StdRng::from_entropy();
+    let mut rng = rng::from_entropy();
     let timer = SystemTimer;
+    let mut stats = SessionStats::new();
+    let (mut spreads_close, mut spreads_estimated) = (0, 0);
+    let mut history_session = SessionContext::starting_now(&config);
+    history_session.tags = options.tags.clone();
+    history_session.note = options.note.clone();
+    history_session.profile = options.profile.clone();
+
+    // Review mode re-serves missed problems instead of generating new ones
+    let mut review_queue = None;
+    if options.review {
+        match ReviewQueue::load(E::NAME) {
+            Ok(queue) if queue.is_empty() => {
+                transcript.output("No missed problems to review.\n");
+                return;
+            }
+            Ok(queue) => review_queue = Some(queue),
+            Err(e) => {
+                transcript.output(&format!("Error loading review bank: {}\n", e));
+                return;
+            }
+        }
+    }
 
     loop {
+        // A bank question fixes the true answer, and the team's guesses are drawn around it
+        let bank_answer = question_bank.as_ref().map(|bank| {
+            let question = bank.random_question(&mut rng);
+            transcript.output(&format_question_display(question));
+            question.answer
+        });
+        let bank_problem = bank_answer
+            .filter(|_| !options.ranges && !options.weighted)
+            .map(|answer| Problem::generate_around(&mut rng, &config, answer));
+
+        // Range and confidence practice pose their own kind of problem around the true answer
+        let posed_problem = (options.ranges || options.weighted).then(|| {
+            let answer = bank_answer.unwrap_or_else(|| config.random_answer(&mut rng));
+            if options.ranges {
+                RangeProblem::generate_around(&mut rng, &config, answer).map(PosedProblem::Ranges)
+            } else {
+                WeightedProblem::generate_around(&mut rng, &config, answer).map(PosedProblem::Weighted)
+            }
+        });
+        let posed_problem = match posed_problem.transpose() {
+            Ok(posed_problem) => posed_problem,
+            Err(e) => {
+                transcript.output(&format!("Error generating problem: {}\n", e));
+                return;
+            }
+        };
+        let bank_problem = posed_problem.as_ref().map(|problem| Ok(problem.to_problem())).or(bank_problem);
+
         // Create new session for each problem
-        let session: PracticeSession<Ready, _, _, TableBasedApproximation> =
-            PracticeSession::new(&mut rng, timer);
+        let session: PracticeSession<Ready, _, _, E> = PracticeSession::new(&mut rng, timer);
 
         // Start problem
-        let (guesses, active_session) = match session.start(config.clone()) {
+        let started = match (review_queue.as_mut(), bank_problem) {
+            (Some(queue), _) => {
+                let (problem, label) = queue.next_problem();
+                transcript.output(&label);
+                session.start_with_problem(problem)
+            }
+            (None, Some(problem)) => problem.and_then(|problem| session.start_with_problem(problem)),
+            (None, None) => session.start(config.clone()),
+        };
+        let (guesses, active_session) = match started {
             Ok(result) => result,
             Err(e) => {
-                println!("Error generating problem: {}", e);
+                transcript.output(&format!("Error generating problem: {}\n", e));
                 return;
             }
         };
 
         // Display problem
-        print!("{}", format_problem_display(&guesses));
-        println!();
+        match &posed_problem {
+            Some(PosedProblem::Ranges(problem)) => transcript.output(&format_range_display(&problem.ranges)),
+            Some(PosedProblem::Weighted(problem)) => transcript.output(&format_weighted_display(&problem.guesses)),
+            None => transcript.output(&format_problem_display(&guesses)),
+        }
+        transcript.output("\n");
+
+        // Get user answer, hints, give up, or skip
+        let result = play_round(&mut transcript, active_session, time_limit);
+        transcript.output("\n");
 
-        // Get user answer
-        let user_answer = prompt_for_answer();
-        println!();
+        let Some(result) = result else {
+            if let Some(queue) = review_queue.as_mut() {
+                queue.skip();
+            }
+            continue;
+        };
+        if keep_history {
+            record_history(&result, &history_session);
+        }
+        stats.record(&result);
 
-        // Submit answer and get results
-        let result = active_session.submit_answer(user_answer);
+        // The spread is estimated after the mean, while the guesses are still on screen
+        let spread_result = options
+            .spread
+            .then(|| spread::grade_spread(&result.input_values, prompt_for_spread(&mut transcript)))
+            .and_then(Result::ok);
+        if let Some(spread_result) = &spread_result {
+            spreads_estimated += 1;
+            spreads_close += usize::from(spread_result.is_close());
+            transcript.output("\n");
+        }
 
         // Display results
-        print!("{}", format_results_display(&result));
-        println!();
+        if bell {
+            ring_bell(&result.evaluation);
+        }
+        let worked = posed_problem.as_ref().and_then(PosedProblem::worked_steps);
+        transcript.output(&format_worked_results_display(&result, &time_format, worked.as_deref()));
+        transcript.output("\n");
+        transcript.output(&format_reveal_display(&result));
+        if let Some(PosedProblem::Ranges(problem)) = &posed_problem {
+            transcript.output(&format_range_overlap(&problem.ranges, problem.correct_answer));
+        }
+        if let Some(spread_result) = &spread_result {
+            transcript.output(&format_spread_result(spread_result));
+        }
+        if options.show_work && result.evaluation.is_success() {
+            transcript.output("\n");
+            transcript.output(&format_step_by_step(&result, worked.as_deref()));
+        }
+        transcript.output("\n");
+
+        match review_queue.as_mut() {
+            Some(queue) => {
+                if queue.record(&result.evaluation) {
+                    transcript.output("Answered correctly twice; this problem is retired from review.\n\n");
+                }
+                if queue.is_empty() {
+                    transcript.output("Every missed problem has been reviewed!\n");
+                    break;
+                }
+            }
+            // Review serves point guesses, which would lose a weighted problem's confidences
+            None if keep_history && !options.weighted && !result.evaluation.is_success() => bank_missed_problem(&result),
+            None => {}
+        }
 
         // Check if user wants to continue
-        if !prompt_for_continue() {
+        if !prompt_for_continue(&mut transcript) {
             break;
         }
-        println!();
+        transcript.output("\n");
     }
 
-    println!("Thanks for practicing!");
+    transcript.output("\n");
+    transcript.output(&format_session_summary(&stats, &time_format));
+    transcript.output(&format_spread_summary(spreads_close, spreads_estimated));
+    transcript.output("\n");
+    transcript.output("Thanks for practicing!\n");
 }
 
 #[cfg(test)]
@@ -224,74 +996,80 @@ mod tests {
 
     #[test]
     fn test_format_results_display_correct() {
-        use crate::practice_mode::{PracticeResult, AnswerEvaluation};
+        use pen_and_paper_geometric_mean::practice_mode::{PracticeResult, AnswerEvaluation};
         use std::marker::PhantomData;
 
         // Create a mock result for testing
         let result = PracticeResult {
             user_answer: 420,
-            exact_geometric_mean: 387.4,
+            exact_mean: 387.4,
             estimation_result: 400,
             duration: Duration::from_millis(12300),
             evaluation: AnswerEvaluation::Correct,
             input_values: vec![25.0, 400.0],
-            estimation_method: PhantomData::<crate::table_based::TableBasedApproximation>,
+            correct_answer: 400,
+            hints_used: 0,
+            estimation_method: PhantomData::<pen_and_paper_geometric_mean::table_based::TableBasedApproximation>,
         };
 
-        let output = format_results_display(&result);
+        let output = format_results_display(&result, &DurationFormat::default());
 
         assert!(output.contains("Your answer: 420"));
         assert!(output.contains("Exact geometric mean: 387.4"));
         assert!(output.contains("Estimation method result: 400"));
-        assert!(output.contains("Time taken: 12.3 seconds"));
+        assert!(output.contains("Time taken: 12.3s"));
         assert!(output.contains("✓ CORRECT! You calculated the estimation method properly."));
     }
 
     #[test]
     fn test_format_results_display_excellent() {
-        use crate::practice_mode::{PracticeResult, AnswerEvaluation};
+        use pen_and_paper_geometric_mean::practice_mode::{PracticeResult, AnswerEvaluation};
         use std::marker::PhantomData;
 
         let result = PracticeResult {
             user_answer: 410,
-            exact_geometric_mean: 417.3,
+            exact_mean: 417.3,
             estimation_result: 400,
             duration: Duration::from_millis(5100),
             evaluation: AnswerEvaluation::Excellent,
             input_values: vec![25.0, 400.0],
-            estimation_method: PhantomData::<crate::table_based::TableBasedApproximation>,
+            correct_answer: 400,
+            hints_used: 0,
+            estimation_method: PhantomData::<pen_and_paper_geometric_mean::table_based::TableBasedApproximation>,
         };
 
-        let output = format_results_display(&result);
+        let output = format_results_display(&result, &DurationFormat::default());
 
         assert!(output.contains("Your answer: 410"));
         assert!(output.contains("Exact geometric mean: 417.3"));
         assert!(output.contains("Estimation method result: 400"));
-        assert!(output.contains("Time taken: 5.1 seconds"));
+        assert!(output.contains("Time taken: 5.1s"));
         assert!(output.contains("★ EXCELLENT! Your answer is closer to the exact value than the estimation method!"));
     }
 
     #[test]
     fn test_format_results_display_incorrect() {
-        use crate::practice_mode::{PracticeResult, AnswerEvaluation};
+        use pen_and_paper_geometric_mean::practice_mode::{PracticeResult, AnswerEvaluation};
         use std::marker::PhantomData;
 
         let result = PracticeResult {
             user_answer: 2000,
-            exact_geometric_mean: 346.4,
+            exact_mean: 346.4,
             estimation_result: 400,
             duration: Duration::from_millis(8700),
             evaluation: AnswerEvaluation::Incorrect,
             input_values: vec![25.0, 400.0],
-            estimation_method: PhantomData::<crate::table_based::TableBasedApproximation>,
+            correct_answer: 400,
+            hints_used: 0,
+            estimation_method: PhantomData::<pen_and_paper_geometric_mean::table_based::TableBasedApproximation>,
         };
 
-        let output = format_results_display(&result);
+        let output = format_results_display(&result, &DurationFormat::default());
 
         assert!(output.contains("Your answer: 2,000"));
         assert!(output.contains("Exact geometric mean: 346.4"));
         assert!(output.contains("Estimation method result: 400"));
-        assert!(output.contains("Time taken: 8.7 seconds"));
+        assert!(output.contains("Time taken: 8.7s"));
         assert!(output.contains("You have calculated the estimation method incorrectly."));
         assert!(output.contains("Step-by-step calculation:"));
         assert!(output.contains("========================"));
@@ -336,6 +1114,306 @@ mod tests {
         assert!(parse_user_input("0").unwrap_err().contains("Please enter a positive number"));
     }
 
+    #[test]
+    fn test_format_step_by_step() {
+        use pen_and_paper_geometric_mean::practice_mode::{PracticeResult, AnswerEvaluation};
+        use std::marker::PhantomData;
+
+        let result = PracticeResult {
+            user_answer: 100,
+            exact_mean: 100.0,
+            estimation_result: 100,
+            duration: Duration::from_millis(4000),
+            evaluation: AnswerEvaluation::Correct,
+            input_values: vec![25.0, 400.0],
+            correct_answer: 400,
+            hints_used: 0,
+            estimation_method: PhantomData::<pen_and_paper_geometric_mean::table_based::TableBasedApproximation>,
+        };
+
+        let output = format_step_by_step(&result, None);
+
+        let expected = "Step-by-step calculation:\n========================\nInput values: [25, 400]\n\n1. Convert each value to log representation:\n   25 → 1.4\n   400 → 2.6\n\n2. Calculate average of log representations:\n   (1.4 + 2.6) ÷ 2 = 4.0 ÷ 2 = 2.0\n\n3. Convert back to final estimate:\n   2.0 → 100\n\nFinal estimation: 100\n";
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_format_reveal_display() {
+        use pen_and_paper_geometric_mean::practice_mode::{PracticeResult, AnswerEvaluation};
+        use std::marker::PhantomData;
+
+        let result = PracticeResult {
+            user_answer: 100,
+            exact_mean: 880.0,
+            estimation_result: 1000,
+            duration: Duration::from_millis(4000),
+            evaluation: AnswerEvaluation::Correct,
+            input_values: vec![150.0, 1100.0, 6000.0],
+            correct_answer: 1000,
+            hints_used: 0,
+            estimation_method: PhantomData::<pen_and_paper_geometric_mean::table_based::TableBasedApproximation>,
+        };
+
+        let output = format_reveal_display(&result);
+
+        let expected = "The true answer was: 1,000\nThe exact geometric mean was 12.0% below the true answer\nClosest guess: teammate 2 with 1,100\n";
+        assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn test_format_session_summary() {
+        use pen_and_paper_geometric_mean::practice_mode::{PracticeResult, AnswerEvaluation};
+        use std::marker::PhantomData;
+
+        let result = |user_answer, evaluation, millis| PracticeResult {
+            user_answer,
+            exact_mean: 100.0,
+            estimation_result: 100,
+            duration: Duration::from_millis(millis),
+            evaluation,
+            input_values: vec![25.0, 400.0],
+            correct_answer: 400,
+            hints_used: 0,
+            estimation_method: PhantomData::<pen_and_paper_geometric_mean::table_based::TableBasedApproximation>,
+        };
+
+        let mut stats = SessionStats::new();
+        assert_eq!(format_session_summary(&stats, &DurationFormat::default()), "");
+
+        stats.record(&result(400, AnswerEvaluation::Correct, 12300));
+        stats.record(&result(2_000_000, AnswerEvaluation::Incorrect, 8700));
+
+        let expected = "Session Summary\n\
+                        ===============\n\
+                        Round     Answer  Tier        Time  Elapsed  Hints\n\
+                        \x20   1        400  Correct    12.3s    12.3s      0\n\
+                        \x20   2  2,000,000  Incorrect   8.7s    21.0s      0\n\
+                        \n\
+                        Rounds played: 2\n\
+                        Correct: 1, Excellent: 0, Incorrect: 1, Gave up: 0\n\
+                        Accuracy: 50.0%\n\
+                        Average time: 10.5s\n\
+                        Fastest time: 8.7s\n\
+                        Total time: 21.0s\n";
+        assert_eq!(format_session_summary(&stats, &DurationFormat::default()), expected);
+    }
+
+    #[test]
+    fn test_format_results_display_gave_up() {
+        use pen_and_paper_geometric_mean::practice_mode::{PracticeResult, AnswerEvaluation};
+        use std::marker::PhantomData;
+
+        let result = PracticeResult {
+            user_answer: 0,
+            exact_mean: 100.0,
+            estimation_result: 100,
+            duration: Duration::from_millis(3000),
+            evaluation: AnswerEvaluation::GaveUp,
+            input_values: vec![25.0, 400.0],
+            correct_answer: 400,
+            hints_used: 2,
+            estimation_method: PhantomData::<pen_and_paper_geometric_mean::table_based::TableBasedApproximation>,
+        };
+
+        let output = format_results_display(&result, &DurationFormat::default());
+
+        assert!(output.contains("Your answer: (gave up)"));
+        assert!(output.contains("You gave up."));
+        assert!(output.contains("Step-by-step calculation:"));
+        assert!(output.contains("25 → 1.4"));
+    }
+
+    #[test]
+    fn test_format_hint() {
+        assert_eq!(format_hint(&Hint::DigitCount(4)), "Hint: the estimate has 4 digits\n");
+        assert_eq!(format_hint(&Hint::LeadingDigits(16)), "Hint: the estimate starts with 16\n");
+    }
+
+    #[test]
+    fn test_parse_answer_input_shortcuts() {
+        assert_eq!(parse_answer_input("?\n"), Ok(AnswerInput::Hint));
+        assert_eq!(parse_answer_input(" g "), Ok(AnswerInput::GiveUp));
+        assert_eq!(parse_answer_input("S"), Ok(AnswerInput::Skip));
+        assert_eq!(parse_answer_input("1,250"), Ok(AnswerInput::Answer(1250)));
+        assert!(parse_answer_input("gg").is_err());
+        assert!(parse_answer_input("").is_err());
+    }
+
+    #[test]
+    fn test_bell_patterns_are_distinct() {
+        let counts = [
+            bell_count(&AnswerEvaluation::Correct),
+            bell_count(&AnswerEvaluation::Excellent),
+            bell_count(&AnswerEvaluation::Incorrect),
+        ];
+        assert!(counts.iter().all(|&c| c > 0));
+        assert!(counts[0] != counts[1] && counts[1] != counts[2] && counts[0] != counts[2]);
+    }
+
+    #[test]
+    fn test_parse_practice_options() {
+        let args = |values: &[&str]| values.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(PracticeOptions::parse(&args(&[])), Ok(PracticeOptions::default()));
+        assert!(PracticeOptions::parse(&args(&["--show-work"])).unwrap().show_work);
+        assert!(PracticeOptions::parse(&args(&["--review"])).unwrap().review);
+
+        let options = PracticeOptions::parse(&args(&["--tag", "warmup", "--note", "pre-quiz", "--tag", "log-linear"])).unwrap();
+        assert_eq!(options.tags, vec!["warmup".to_string(), "log-linear".to_string()]);
+        assert_eq!(options.note, Some("pre-quiz".to_string()));
+        assert!(PracticeOptions::parse(&args(&["--tag"])).is_err());
+        assert!(PracticeOptions::parse(&args(&["--note"])).is_err());
+
+        let options = PracticeOptions::parse(&args(&["--profile", "alice", "--no-history"])).unwrap();
+        assert_eq!(options.profile, Some("alice".to_string()));
+        assert!(options.no_history);
+        assert!(PracticeOptions::parse(&args(&["--profile"])).is_err());
+        assert!(PracticeOptions::parse(&args(&["--bogus"])).is_err());
+
+        let options = PracticeOptions::parse(&args(&["--transcript", "session.jsonl", "--show-work"])).unwrap();
+        assert_eq!(options.transcript, Some(PathBuf::from("session.jsonl")));
+        assert!(options.show_work);
+        assert!(PracticeOptions::parse(&args(&["--transcript"])).is_err());
+
+        let options = PracticeOptions::parse(&args(&["--time-limit", "45"])).unwrap();
+        assert_eq!(options.time_limit, Some(Duration::from_secs(45)));
+        assert!(PracticeOptions::parse(&args(&["--time-limit"])).is_err());
+        assert!(PracticeOptions::parse(&args(&["--time-limit", "soon"])).is_err());
+
+        let options = PracticeOptions::parse(&args(&["--time-precision", "2"])).unwrap();
+        assert_eq!(options.time_format, Some(DurationFormat::new(2).unwrap()));
+        assert!(PracticeOptions::parse(&args(&["--time-precision", "7"])).is_err());
+        assert!(PracticeOptions::parse(&args(&["--time-precision"])).is_err());
+
+        assert_eq!(PracticeOptions::parse(&args(&["--warm-up", "6"])).unwrap().warm_up, Some(6));
+        assert!(PracticeOptions::parse(&args(&["--warm-up", "-1"])).is_err());
+        assert!(PracticeOptions::parse(&args(&["--frontend-protocol", "--warm-up", "0"])).is_err());
+        assert!(PracticeOptions::parse(&args(&["--bell"])).unwrap().bell);
+
+        let options = PracticeOptions::parse(&args(&["--frontend-protocol", "--tag", "gui"])).unwrap();
+        assert!(options.frontend_protocol);
+        assert!(PracticeOptions::parse(&args(&["--frontend-protocol", "--time-limit", "30"])).is_err());
+        let options = PracticeOptions::parse(&args(&["--frontend-protocol", "--harmonic"])).unwrap();
+        assert_eq!(options.method.as_deref(), Some("reciprocal-table"));
+        assert_eq!(PracticeOptions::parse(&args(&["--method", "median"])).unwrap().method.as_deref(), Some("median"));
+        assert!(PracticeOptions::parse(&args(&["--method", "mode"])).is_err());
+        assert!(PracticeOptions::parse(&args(&["--method"])).is_err());
+
+        let options = PracticeOptions::parse(&args(&["--bank", "league.toml"])).unwrap();
+        assert_eq!(options.question_bank, Some(PathBuf::from("league.toml")));
+        assert!(PracticeOptions::parse(&args(&["--bank"])).is_err());
+        assert!(PracticeOptions::parse(&args(&["--bank", "league.toml", "--review"])).is_err());
+        assert!(PracticeOptions::parse(&args(&["--bank", "league.toml", "--frontend-protocol"])).is_err());
+
+        assert!(PracticeOptions::parse(&args(&["--ranges", "--bank", "league.toml"])).unwrap().ranges);
+        assert!(PracticeOptions::parse(&args(&["--ranges", "--method", "table-based"])).unwrap().ranges);
+        assert!(PracticeOptions::parse(&args(&["--ranges", "--harmonic"])).is_err());
+        assert!(PracticeOptions::parse(&args(&["--ranges", "--review"])).is_err());
+        assert!(PracticeOptions::parse(&args(&["--ranges", "--frontend-protocol"])).is_err());
+
+        assert!(PracticeOptions::parse(&args(&["--weighted", "--bank", "league.toml"])).unwrap().weighted);
+        assert!(PracticeOptions::parse(&args(&["--weighted", "--ranges"])).is_err());
+        assert!(PracticeOptions::parse(&args(&["--weighted", "--method", "table-based"])).is_err());
+        assert!(PracticeOptions::parse(&args(&["--weighted", "--review"])).is_err());
+        assert!(PracticeOptions::parse(&args(&["--weighted", "--frontend-protocol"])).is_err());
+
+        assert!(PracticeOptions::parse(&args(&["--spread", "--bank", "league.toml"])).unwrap().spread);
+        assert!(PracticeOptions::parse(&args(&["--spread", "--ranges"])).is_err());
+        assert!(PracticeOptions::parse(&args(&["--spread", "--weighted"])).is_err());
+        assert!(PracticeOptions::parse(&args(&["--spread", "--frontend-protocol"])).is_err());
+    }
+
+    #[test]
+    fn test_format_spread_result() {
+        let guesses = [50.0, 200.0];
+        assert_eq!(
+            format_spread_result(&spread::grade_spread(&guesses, 2.0).unwrap()),
+            "Spread: you said ×2, and the guesses' geometric standard deviation is ×2.00, spot on\n\
+             With 2 guesses that spread, their geometric mean typically misses the true answer by ×1.48\n"
+        );
+        assert!(format_spread_result(&spread::grade_spread(&guesses, 1.6).unwrap()).contains(", within one table step (slightly narrow)\n"));
+        assert!(format_spread_result(&spread::grade_spread(&guesses, 5.0).unwrap()).contains(", 4 table steps too wide\n"));
+
+        assert_eq!(format_spread_summary(0, 0), "");
+        assert_eq!(format_spread_summary(3, 4), "Spread estimates within one table step: 3 of 4\n");
+    }
+
+    #[test]
+    fn test_format_range_display() {
+        let ranges = [GuessRange { low: 200, high: 500 }, GuessRange { low: 1_000, high: 2_500 }];
+        assert_eq!(format_range_display(&ranges), "Here are the team's ranges:\n  1. 200 – 500\n  2. 1,000 – 2,500\n");
+        assert_eq!(
+            format_range_overlap(&[GuessRange { low: 100, high: 400 }, GuessRange { low: 200, high: 800 }], 300),
+            "Every range overlaps from 200 to 400, with a geometric midpoint of 282.8; the true answer 300 was inside it\n"
+        );
+        assert_eq!(
+            format_weighted_display(&[WeightedGuess { guess: 2_000, confidence: 3 }]),
+            "Here are the team's guesses, each with a confidence from 1 to 5:\n  1. 2,000 (confidence 3)\n"
+        );
+        assert_eq!(format_range_overlap(&ranges, 700), "The ranges don't all overlap, so averaging their midpoints is the only estimate\n");
+    }
+
+    #[test]
+    fn test_format_question_display() {
+        let mut question = Question {
+            text: "How many bones are in the adult human body?".to_string(),
+            answer: 206,
+            category: None,
+            source: None,
+        };
+        assert_eq!(format_question_display(&question), "Question: How many bones are in the adult human body?\n");
+        question.category = Some("science".to_string());
+        assert_eq!(
+            format_question_display(&question),
+            "Question (science): How many bones are in the adult human body?\n"
+        );
+    }
+
+    #[test]
+    fn test_harmonic_results_name_the_harmonic_mean() {
+        use pen_and_paper_geometric_mean::practice_mode::{PracticeResult, AnswerEvaluation};
+        use std::marker::PhantomData;
+
+        let result = PracticeResult {
+            user_answer: 50,
+            exact_mean: 40.0,
+            estimation_result: 50,
+            duration: Duration::from_millis(4000),
+            evaluation: AnswerEvaluation::Correct,
+            input_values: vec![30.0, 60.0],
+            correct_answer: 50,
+            hints_used: 0,
+            estimation_method: PhantomData::<ReciprocalTableApproximation>,
+        };
+
+        assert!(format_results_display(&result, &DurationFormat::default()).contains("Exact harmonic mean: 40.0\n"));
+        assert!(format_reveal_display(&result).contains("The exact harmonic mean was 20.0% below the true answer\n"));
+    }
+
+    #[test]
+    fn test_review_queue_serves_only_its_method() {
+        let mut bank = ReviewBank::default();
+        let problem = |guesses: Vec<u64>| Problem { guesses, correct_answer: 100 };
+        bank.add_miss(&problem(vec![10, 1000]), TableBasedApproximation::NAME);
+        bank.add_miss(&problem(vec![30, 60]), ReciprocalTableApproximation::NAME);
+        bank.add_miss(&problem(vec![20, 500]), TableBasedApproximation::NAME);
+        bank.add_miss(&problem(vec![40, 80]), ReciprocalTableApproximation::NAME);
+
+        let mut queue =
+            ReviewQueue { path: PathBuf::new(), bank, method: ReciprocalTableApproximation::NAME, next: 0 };
+        let (first, label) = queue.next_problem();
+        assert_eq!(first.guesses, vec![30, 60]);
+        assert!(label.starts_with("Review problem 1 of 2"));
+
+        queue.skip();
+        let (second, label) = queue.next_problem();
+        assert_eq!(second.guesses, vec![40, 80]);
+        assert!(label.starts_with("Review problem 2 of 2"));
+
+        queue.skip();
+        assert_eq!(queue.next_problem().0.guesses, vec![30, 60]);
+    }
+
     // Property test: All integers converted to strings parse without error
     #[cfg(test)]
     mod property_tests {
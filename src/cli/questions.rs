@@ -0,0 +1,59 @@
+use std::fs;
+use std::path::Path;
+
+use pen_and_paper_geometric_mean::questions::{self, QuestionBank};
+
+/// Convert a CSV of questions and answers into a bank, merging into the bank if it already exists
+fn run_import(args: &[String]) -> Result<(), String> {
+    let [source, destination] = args else {
+        return Err("import requires a CSV file and a .toml or .json bank to write".to_string());
+    };
+    let destination = Path::new(destination);
+
+    let contents = fs::read_to_string(source).map_err(|e| format!("{}: {}", source, e))?;
+    let import = questions::import_csv(&contents).map_err(|e| format!("{}: {}", source, e))?;
+
+    let mut bank = if destination.exists() {
+        questions::load(destination).map_err(|e| format!("{}: {}", destination.display(), e))?
+    } else {
+        QuestionBank { questions: Vec::new() }
+    };
+    let imported = import.questions.len();
+    let added = bank.merge(import.questions);
+    let skipped = import.duplicates + imported - added;
+    questions::save(destination, &bank).map_err(|e| format!("{}: {}", destination.display(), e))?;
+
+    println!(
+        "Imported {} new question{} into {}, skipping {} duplicate{}",
+        added,
+        if added == 1 { "" } else { "s" },
+        destination.display(),
+        skipped,
+        if skipped == 1 { "" } else { "s" }
+    );
+    Ok(())
+}
+
+fn print_usage() {
+    println!("Usage:");
+    println!("  cargo run questions import <file.csv> <bank.toml|bank.json> - Add a CSV's questions to a question bank");
+    println!();
+    println!("The CSV needs a header row with question and answer columns, and may have category and source columns.");
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    match args.first().map(|s| s.as_str()) {
+        Some("import") => run_import(&args[1..]),
+        _ => {
+            print_usage();
+            Ok(())
+        }
+    }
+}
+
+/// Run the `questions` subcommand
+pub fn run_questions(args: &[String]) {
+    if let Err(e) = run(args) {
+        println!("{}", e);
+    }
+}
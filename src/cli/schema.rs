@@ -0,0 +1,128 @@
+use serde::{Deserialize, Serialize};
+
+/// Version of the frontend protocol's events, bumped on incompatible changes
+pub const FRONTEND_SCHEMA_VERSION: u32 = 1;
+
+/// Version of the events recorded in session transcripts, bumped on incompatible changes
+pub const TRANSCRIPT_SCHEMA_VERSION: u32 = 1;
+
+/// Version of the JSON comparison report, bumped on incompatible changes
+pub const COMPARISON_SCHEMA_VERSION: u32 = 1;
+
+/// A JSON document stamped with the version of its schema, alongside the document's own fields
+///
+/// Adding a field is compatible; renaming, removing, or changing the meaning of one needs a new version.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Versioned<T> {
+    pub schema_version: u32,
+    #[serde(flatten)]
+    pub body: T,
+}
+
+impl<T> Versioned<T> {
+    pub fn new(schema_version: u32, body: T) -> Self {
+        Versioned { schema_version, body }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    //! Compatibility tests: each schema's current output is pinned to fixtures under `fixtures/schema/v<version>`.
+    //!
+    //! If one of these fails, the JSON changed shape.
+    //! Adding a field only needs the fixture updated; any other change needs the version bumped and a new fixture directory,
+    //! leaving the old fixtures in place as a record of what earlier consumers expect.
+
+    use super::*;
+    use crate::cli::compare::{MethodComparison, format_comparison_json};
+    use crate::cli::frontend::Event;
+    use crate::cli::transcript::{EventKind, TranscriptEvent};
+    use pen_and_paper_geometric_mean::evaluation::Results;
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn fixture(version: u32, file_name: &str) -> String {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(format!("fixtures/schema/v{}/{}", version, file_name));
+        fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e))
+    }
+
+    fn to_json_lines<T: Serialize>(documents: &[T]) -> String {
+        documents.iter().map(|document| serde_json::to_string(document).unwrap() + "\n").collect()
+    }
+
+    fn sample_events() -> Vec<Versioned<Event>> {
+        let events = vec![
+            Event::Ready { protocol_version: FRONTEND_SCHEMA_VERSION, method: "table-based" },
+            Event::Problem { guesses: vec![150, 2500, 800, 45] },
+            Event::Hint { digit_count: Some(3), leading_digits: None },
+            Event::Hint { digit_count: None, leading_digits: Some(4) },
+            Event::NoMoreHints,
+            Event::Result {
+                answer: Some(400),
+                evaluation: "correct",
+                exact_geometric_mean: 393.5,
+                estimate: 400,
+                correct_answer: 350,
+                duration_ms: 12300,
+                hints_used: 1,
+                steps: Some("Final estimation: 400".to_string()),
+            },
+            Event::Skipped,
+            Event::Summary { rounds: 1, accuracy: Some(1.0), average_duration_ms: Some(12300) },
+            Event::Error { message: "No problem in progress; send new_problem first".to_string() },
+            Event::Warning { message: "Could not record practice history".to_string() },
+        ];
+        events.into_iter().map(|event| Versioned::new(FRONTEND_SCHEMA_VERSION, event)).collect()
+    }
+
+    fn sample_transcript() -> Vec<Versioned<TranscriptEvent>> {
+        vec![
+            TranscriptEvent { elapsed_ms: 0, kind: EventKind::Output, text: "Enter your estimated geometric mean: ".to_string() },
+            TranscriptEvent { elapsed_ms: 1500, kind: EventKind::Input, text: "42\n".to_string() },
+        ]
+        .into_iter()
+        .map(|event| Versioned::new(TRANSCRIPT_SCHEMA_VERSION, event))
+        .collect()
+    }
+
+    fn sample_comparison() -> Vec<MethodComparison> {
+        let results = |error: f64| Results {
+            mean_absolute_relative_error: error,
+            worst_case_error: error * 2.0,
+            worst_case_overestimate: error,
+            overall_bias: error / 2.0 - 0.0625,
+            total_tests: 100,
+        };
+        vec![
+            MethodComparison { name: "exact", results: results(0.0) },
+            MethodComparison { name: "table-based", results: results(0.0625) },
+        ]
+    }
+
+    #[test]
+    fn test_frontend_events_match_schema() {
+        assert_eq!(to_json_lines(&sample_events()), fixture(FRONTEND_SCHEMA_VERSION, "frontend-events.jsonl"));
+    }
+
+    #[test]
+    fn test_transcript_matches_schema() {
+        let fixture = fixture(TRANSCRIPT_SCHEMA_VERSION, "transcript.jsonl");
+        assert_eq!(to_json_lines(&sample_transcript()), fixture);
+
+        let parsed: Vec<Versioned<TranscriptEvent>> =
+            fixture.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+        assert_eq!(parsed, sample_transcript());
+    }
+
+    #[test]
+    fn test_comparison_matches_schema() {
+        assert_eq!(format_comparison_json(&sample_comparison()), fixture(COMPARISON_SCHEMA_VERSION, "comparison.json"));
+    }
+
+    #[test]
+    fn test_schema_version_leads_every_document() {
+        let lines = to_json_lines(&sample_events()) + &to_json_lines(&sample_transcript());
+        assert!(lines.lines().all(|line| line.starts_with("{\"schema_version\":1,")));
+        assert!(format_comparison_json(&sample_comparison()).starts_with("{\n  \"schema_version\": 1,"));
+    }
+}
@@ -0,0 +1,347 @@
+use std::path::PathBuf;
+
+use pen_and_paper_geometric_mean::drills;
+use pen_and_paper_geometric_mean::practice_mode::{ANSWER_RANGE, PracticeModeConfig};
+use pen_and_paper_geometric_mean::questions;
+use pen_and_paper_geometric_mean::rng;
+use pen_and_paper_geometric_mean::simulation::{
+    DuelOutcome, GameConfig, ScoringRule, SimulationReport, Strategy, default_strategies, duel_grid, simulate,
+    strategy_by_name,
+};
+
+use crate::cli::practice_mode::format_number;
+
+/// Uncertainty levels a duel is played at, in natural log units
+const DUEL_SPREADS: [f64; 4] = [0.5, 1.0, 1.5, 2.0];
+
+/// Team sizes a duel is played at
+const DUEL_TEAM_SIZES: [usize; 4] = [2, 4, 6, 8];
+
+/// Options for `cargo run simulate`
+#[derive(Debug, Clone, PartialEq)]
+pub struct SimulateOptions {
+    pub games: usize,
+    pub questions: usize,
+    pub team_size: usize,
+    pub log_std_dev: f64,
+    pub scoring: ScoringRule,
+    /// Fixed seed for a reproducible run, or `None` for a fresh one
+    pub seed: Option<u64>,
+    /// Question bank to take true answers from, instead of random ones
+    pub question_bank: Option<PathBuf>,
+}
+
+impl Default for SimulateOptions {
+    fn default() -> Self {
+        SimulateOptions {
+            games: 1000,
+            questions: 10,
+            team_size: 4,
+            log_std_dev: 1.0,
+            scoring: ScoringRule::WithinFraction(0.1),
+            seed: None,
+            question_bank: None,
+        }
+    }
+}
+
+/// Parse a scoring rule: `closest`, or `within:<percent>` such as `within:10`
+fn parse_scoring(value: &str) -> Result<ScoringRule, String> {
+    if value == "closest" {
+        return Ok(ScoringRule::ClosestWins);
+    }
+    value
+        .strip_prefix("within:")
+        .and_then(|percent| percent.trim_end_matches('%').parse::<f64>().ok())
+        .filter(|percent| percent.is_finite() && *percent >= 0.0)
+        .map(|percent| ScoringRule::WithinFraction(percent / 100.0))
+        .ok_or_else(|| format!("Unknown scoring rule: {} (use closest or within:<percent>)", value))
+}
+
+fn parse_value<T: std::str::FromStr>(option: &str, value: Option<&String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("{} requires a value", option))?;
+    value.parse().map_err(|_| format!("Invalid value for {}: {}", option, value))
+}
+
+impl SimulateOptions {
+    /// Parse the arguments following `simulate`
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut options = SimulateOptions::default();
+
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--games" => options.games = parse_value(arg, iter.next())?,
+                "--questions" => options.questions = parse_value(arg, iter.next())?,
+                "--team-size" => options.team_size = parse_value(arg, iter.next())?,
+                "--spread" => options.log_std_dev = parse_value(arg, iter.next())?,
+                "--seed" => options.seed = Some(parse_value(arg, iter.next())?),
+                "--scoring" => {
+                    let value = iter.next().ok_or("--scoring requires a rule")?;
+                    options.scoring = parse_scoring(value)?;
+                }
+                "--bank" => {
+                    let path = iter.next().ok_or("--bank requires a question bank file")?;
+                    options.question_bank = Some(PathBuf::from(path));
+                }
+                other => return Err(format!("Unknown simulate option: {}", other)),
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+fn describe_scoring(scoring: &ScoringRule) -> String {
+    match scoring {
+        ScoringRule::WithinFraction(fraction) => {
+            format!("a point for answers within {}%", drills::format_number(fraction * 100.0))
+        }
+        ScoringRule::ClosestWins => "a point for the closest answer".to_string(),
+    }
+}
+
+/// Format the report as a table of strategies, best win rate first
+pub fn format_simulation_report(report: &SimulationReport, config: &GameConfig) -> String {
+    let mut output = String::new();
+    output.push_str("Game Simulation\n");
+    output.push_str("===============\n");
+    output.push_str(&format!(
+        "{} games of {} questions, teams of {}, {}\n\n",
+        format_number(report.games as u64),
+        config.questions,
+        config.problems.team_size,
+        describe_scoring(&config.scoring)
+    ));
+
+    let mut outcomes: Vec<_> = report.outcomes.iter().collect();
+    outcomes.sort_by(|a, b| b.win_rate.total_cmp(&a.win_rate));
+
+    let name_width = outcomes.iter().map(|o| o.name.len()).chain(["Strategy".len()]).max().unwrap_or(0);
+    output.push_str(&format!("{:<name_width$}  {:>8}  {:>15}\n", "Strategy", "Win rate", "Points per game"));
+    for outcome in outcomes {
+        output.push_str(&format!(
+            "{:<name_width$}  {:>7.1}%  {:>15.2}\n",
+            outcome.name,
+            outcome.win_rate * 100.0,
+            outcome.mean_points
+        ));
+    }
+
+    output
+}
+
+/// Simulate games between teams using each aggregation strategy and print their win rates
+pub fn run_simulate(args: &[String]) {
+    let options = match SimulateOptions::parse(args) {
+        Ok(options) => options,
+        Err(e) => {
+            println!("{}", e);
+            return;
+        }
+    };
+
+    let problems = match PracticeModeConfig::new(options.team_size, options.log_std_dev, ANSWER_RANGE.0, ANSWER_RANGE.1) {
+        Ok(problems) => problems,
+        Err(e) => {
+            println!("{}", e);
+            return;
+        }
+    };
+    let answers = match &options.question_bank {
+        Some(path) => match questions::load(path) {
+            Ok(bank) => Some(bank.questions.iter().map(|question| question.answer).collect()),
+            Err(e) => {
+                println!("Error loading {}: {}", path.display(), e);
+                return;
+            }
+        },
+        None => None,
+    };
+    let config = GameConfig { questions: options.questions, problems, scoring: options.scoring, answers };
+
+    let mut rng = match options.seed {
+        Some(seed) => rng::seeded(seed),
+        None => rng::from_entropy(),
+    };
+
+    match simulate(&mut rng, &default_strategies(), &config, options.games) {
+        Ok(report) => print!("{}", format_simulation_report(&report, &config)),
+        Err(e) => println!("{}", e),
+    }
+}
+
+/// Options for `cargo run duel`
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuelOptions {
+    pub first: String,
+    pub second: String,
+    pub duels: usize,
+    pub seed: Option<u64>,
+}
+
+impl DuelOptions {
+    /// Parse the arguments following `duel`: two strategy names, then options
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let names: Vec<&str> = default_strategies().iter().map(|s| s.name).collect();
+        let usage = format!("Usage: cargo run duel <first> <second> [--duels <n>] [--seed <n>]\nStrategies: {}", names.join(", "));
+
+        let (first, second, rest) = match args {
+            [first, second, rest @ ..] if !first.starts_with("--") && !second.starts_with("--") => (first, second, rest),
+            _ => return Err(usage),
+        };
+        let mut options = DuelOptions { first: first.clone(), second: second.clone(), duels: 2_000, seed: None };
+
+        let mut iter = rest.iter();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--duels" => options.duels = parse_value(arg, iter.next())?,
+                "--seed" => options.seed = Some(parse_value(arg, iter.next())?),
+                other => return Err(format!("Unknown duel option: {}", other)),
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+/// Format duel outcomes as a table with one row per uncertainty level and team size
+pub fn format_duel_report(first: &str, second: &str, outcomes: &[DuelOutcome]) -> String {
+    let mut output = String::new();
+    output.push_str("Closest-Answer Duel\n");
+    output.push_str("===================\n");
+    if let Some(outcome) = outcomes.first() {
+        output.push_str(&format!(
+            "{} vs {}, {} duels per row, ties count as half a win\n",
+            first,
+            second,
+            format_number(outcome.duels as u64)
+        ));
+    }
+    output.push('\n');
+
+    output.push_str(&format!("{:>6}  {:>4}  {:>8}  {:>15}\n", "Spread", "Team", "Win rate", "95% CI"));
+    for outcome in outcomes {
+        let (low, high) = outcome.confidence_interval;
+        output.push_str(&format!(
+            "{:>6.1}  {:>4}  {:>7.1}%  {:>15}\n",
+            outcome.log_std_dev,
+            outcome.team_size,
+            outcome.win_probability * 100.0,
+            format!("{:.1}% - {:.1}%", low * 100.0, high * 100.0)
+        ));
+    }
+
+    output
+}
+
+fn find_strategy(name: &str) -> Result<Strategy, String> {
+    strategy_by_name(name).ok_or_else(|| format!("Unknown strategy: {}", name))
+}
+
+/// Duel two strategies on single closest-answer questions across uncertainty levels and team sizes
+pub fn run_duel(args: &[String]) {
+    let duel = DuelOptions::parse(args).and_then(|options| {
+        let first = find_strategy(&options.first)?;
+        let second = find_strategy(&options.second)?;
+        Ok((options, first, second))
+    });
+    let (options, first, second) = match duel {
+        Ok(duel) => duel,
+        Err(e) => {
+            println!("{}", e);
+            return;
+        }
+    };
+
+    let mut rng = match options.seed {
+        Some(seed) => rng::seeded(seed),
+        None => rng::from_entropy(),
+    };
+
+    match duel_grid(&mut rng, first, second, &DUEL_SPREADS, &DUEL_TEAM_SIZES, ANSWER_RANGE, options.duels) {
+        Ok(outcomes) => print!("{}", format_duel_report(first.name, second.name, &outcomes)),
+        Err(e) => println!("{}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pen_and_paper_geometric_mean::simulation::StrategyOutcome;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_simulate_options() {
+        assert_eq!(SimulateOptions::parse(&[]), Ok(SimulateOptions::default()));
+
+        let options =
+            SimulateOptions::parse(&args(&["--games", "50", "--scoring", "closest", "--seed", "7", "--spread", "0.5"]))
+                .unwrap();
+        assert_eq!(options.games, 50);
+        assert_eq!(options.scoring, ScoringRule::ClosestWins);
+        assert_eq!(options.seed, Some(7));
+        assert_eq!(options.log_std_dev, 0.5);
+        let options = SimulateOptions::parse(&args(&["--bank", "league.json"])).unwrap();
+        assert_eq!(options.question_bank, Some(PathBuf::from("league.json")));
+
+        assert_eq!(parse_scoring("within:25%"), Ok(ScoringRule::WithinFraction(0.25)));
+        assert!(parse_scoring("within:-5").is_err());
+        assert!(SimulateOptions::parse(&args(&["--games"])).is_err());
+        assert!(SimulateOptions::parse(&args(&["--games", "many"])).is_err());
+        assert!(SimulateOptions::parse(&args(&["--teams"])).is_err());
+    }
+
+    #[test]
+    fn test_format_simulation_report() {
+        let report = SimulationReport {
+            games: 1000,
+            outcomes: vec![
+                StrategyOutcome { name: "arithmetic-mean".to_string(), win_rate: 0.125, mean_points: 2.5 },
+                StrategyOutcome { name: "exact".to_string(), win_rate: 0.875, mean_points: 4.25 },
+            ],
+        };
+        let config = GameConfig {
+            questions: 10,
+            problems: PracticeModeConfig::new(4, 1.0, 10, 1_000_000).unwrap(),
+            scoring: ScoringRule::WithinFraction(0.1),
+            answers: None,
+        };
+
+        let expected = "Game Simulation\n===============\n1,000 games of 10 questions, teams of 4, a point for answers within 10%\n\n\
+                        Strategy         Win rate  Points per game\n\
+                        exact               87.5%             4.25\n\
+                        arithmetic-mean     12.5%             2.50\n";
+        assert_eq!(format_simulation_report(&report, &config), expected);
+    }
+
+    #[test]
+    fn test_parse_duel_options() {
+        let options = DuelOptions::parse(&args(&["table-based", "median", "--duels", "100"])).unwrap();
+        assert_eq!((options.first.as_str(), options.second.as_str()), ("table-based", "median"));
+        assert_eq!(options.duels, 100);
+
+        assert!(DuelOptions::parse(&args(&["table-based"])).unwrap_err().starts_with("Usage:"));
+        assert!(DuelOptions::parse(&args(&["table-based", "--seed", "1"])).is_err());
+        assert!(find_strategy("abacus").is_err());
+    }
+
+    #[test]
+    fn test_format_duel_report() {
+        let outcomes = [DuelOutcome {
+            log_std_dev: 1.0,
+            team_size: 4,
+            duels: 2_000,
+            win_probability: 0.565,
+            confidence_interval: (0.54, 0.584),
+        }];
+
+        let expected = "Closest-Answer Duel\n===================\ntable-based vs median, 2,000 duels per row, ties count as half a win\n\n\
+                        Spread  Team  Win rate           95% CI\n\
+                        \x20  1.0     4     56.5%    54.0% - 58.4%\n";
+        assert_eq!(format_duel_report("table-based", "median", &outcomes), expected);
+    }
+}
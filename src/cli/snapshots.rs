@@ -0,0 +1,114 @@
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use pen_and_paper_geometric_mean::table_based::TableBasedApproximation;
+use pen_and_paper_geometric_mean::traits::EstimateGeometricMeanStepByStep;
+
+use super::cheat_sheet::{format_log_linear_cheat_sheet, format_table_based_cheat_sheet};
+use super::compare::{DEFAULT_WIDTH, format_comparison_report};
+
+/// Where the golden fixtures live within the crate, and where `snapshots` writes by default, relative to the current
+/// directory so the binary doesn't carry the path it was built at
+const FIXTURE_DIR: &str = "fixtures/snapshots";
+
+/// Problems whose worked solutions are snapshotted, taken from the README's examples
+const STEP_BY_STEP_EXAMPLES: [&[u64]; 4] = [&[300, 10000, 900, 70], &[80, 80, 80, 800], &[10, 10, 100], &[25, 400]];
+
+/// A canonical human-facing output and the fixture file it is compared against
+pub struct Snapshot {
+    pub file_name: &'static str,
+    pub contents: String,
+}
+
+/// Render the worked table-based solution to every example problem, separated by blank lines
+fn format_step_by_step_examples() -> String {
+    let mut output = String::new();
+    for (i, guesses) in STEP_BY_STEP_EXAMPLES.iter().enumerate() {
+        let values: Vec<f64> = guesses.iter().map(|&g| g as f64).collect();
+        let steps = TableBasedApproximation::estimate_geometric_mean_steps(&values).expect("example problems are valid");
+        if i > 0 {
+            output.push('\n');
+        }
+        writeln!(output, "{}", steps).expect("writing to a String cannot fail");
+    }
+    output
+}
+
+/// Every output protected by the snapshot tests, rendered fresh
+pub fn canonical_snapshots() -> Vec<Snapshot> {
+    vec![
+        Snapshot { file_name: "comparison-report.txt", contents: format_comparison_report(DEFAULT_WIDTH) },
+        Snapshot { file_name: "table-based-step-by-step.txt", contents: format_step_by_step_examples() },
+        Snapshot { file_name: "log-linear-cheat-sheet.txt", contents: format_log_linear_cheat_sheet() },
+        Snapshot { file_name: "table-based-cheat-sheet.txt", contents: format_table_based_cheat_sheet() },
+    ]
+}
+
+/// Write every snapshot into `dir`, creating it if needed
+fn export_snapshots(dir: &Path) -> Result<(), String> {
+    fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+    for snapshot in canonical_snapshots() {
+        let path = dir.join(snapshot.file_name);
+        fs::write(&path, snapshot.contents).map_err(|e| format!("{}: {}", path.display(), e))?;
+        println!("Wrote {}", path.display());
+    }
+    Ok(())
+}
+
+/// Regenerate the golden fixtures, into the given directory, or by default the fixtures of the crate `cargo run` is
+/// run from
+///
+/// Run this after an intentional change to a human-facing format, and review the fixture diff.
+pub fn run_snapshots(args: &[String]) {
+    let dir = match args {
+        // Outside the crate, don't scatter fixture directories wherever the binary happens to run
+        [] if Path::new(FIXTURE_DIR).is_dir() => PathBuf::from(FIXTURE_DIR),
+        [] => {
+            println!("No {} here; run from the crate's root, or give a directory: cargo run snapshots <dir>", FIXTURE_DIR);
+            return;
+        }
+        [dir] => PathBuf::from(dir),
+        _ => {
+            println!("Usage: cargo run snapshots [dir]");
+            return;
+        }
+    };
+
+    if let Err(e) = export_snapshots(&dir) {
+        println!("Failed to write snapshots: {}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The fixtures were generated with ChaCha12 drawing the comparison corpus, so builds without it draw different ones
+    #[cfg(feature = "chacha")]
+    #[test]
+    fn test_outputs_match_golden_fixtures() {
+        for snapshot in canonical_snapshots() {
+            let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(FIXTURE_DIR).join(snapshot.file_name);
+            let expected = fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+            assert!(
+                snapshot.contents == expected,
+                "{} no longer matches its fixture; if the change is intended, run `cargo run snapshots` and review the diff\n\
+                 --- expected\n{}\n--- actual\n{}",
+                snapshot.file_name,
+                expected,
+                snapshot.contents,
+            );
+        }
+    }
+
+    #[test]
+    fn test_export_writes_every_snapshot() {
+        let dir = std::env::temp_dir().join(format!("snapshots-test-{}", std::process::id()));
+        export_snapshots(&dir).unwrap();
+        for snapshot in canonical_snapshots() {
+            assert_eq!(fs::read_to_string(dir.join(snapshot.file_name)).unwrap(), snapshot.contents);
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,62 @@
+/// Block characters from lowest to highest, one per eighth of the range
+const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Render values as a row of blocks scaled from the smallest to the largest, with a space for each missing value
+///
+/// When every value is the same, every block is the lowest.
+pub fn sparkline(values: &[Option<f64>]) -> String {
+    let present = values.iter().flatten().copied();
+    let min = present.clone().fold(f64::INFINITY, f64::min);
+    let max = present.fold(f64::NEG_INFINITY, f64::max);
+
+    values
+        .iter()
+        .map(|value| match value {
+            Some(value) if max > min => {
+                let level = ((value - min) / (max - min) * (BLOCKS.len() - 1) as f64).round() as usize;
+                BLOCKS[level.min(BLOCKS.len() - 1)]
+            }
+            Some(_) => BLOCKS[0],
+            None => ' ',
+        })
+        .collect()
+}
+
+/// Count values into `bins` equal bins from `low` to `high`, with anything beyond either end in the nearest bin
+pub fn histogram(values: impl IntoIterator<Item = f64>, low: f64, high: f64, bins: usize) -> Vec<usize> {
+    let mut counts = vec![0; bins];
+    if bins == 0 {
+        return counts;
+    }
+    for value in values {
+        let bin = ((value - low) / (high - low) * bins as f64).floor().clamp(0.0, (bins - 1) as f64) as usize;
+        counts[bin] += 1;
+    }
+    counts
+}
+
+/// A histogram rendered as a sparkline, so its shape fits on one line
+pub fn mini_histogram(values: impl IntoIterator<Item = f64>, low: f64, high: f64, bins: usize) -> String {
+    let counts: Vec<Option<f64>> = histogram(values, low, high, bins).into_iter().map(|count| Some(count as f64)).collect();
+    sparkline(&counts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparkline() {
+        assert_eq!(sparkline(&[Some(0.0), Some(7.0), Some(3.5), None, Some(1.0)]), "▁█▅ ▂");
+        assert_eq!(sparkline(&[Some(2.0), Some(2.0)]), "▁▁");
+        assert_eq!(sparkline(&[None]), " ");
+        assert_eq!(sparkline(&[]), "");
+    }
+
+    #[test]
+    fn test_histogram() {
+        assert_eq!(histogram([0.05, 0.15, 0.17, 0.9, -1.0], 0.0, 0.4, 4), vec![2, 2, 0, 1]);
+        assert_eq!(mini_histogram([0.05, 0.15, 0.17, 0.9, -1.0], 0.0, 0.4, 4), "██▁▅");
+        assert!(histogram([1.0], 0.0, 1.0, 0).is_empty());
+    }
+}
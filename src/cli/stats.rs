@@ -0,0 +1,304 @@
+use pen_and_paper_geometric_mean::config;
+use pen_and_paper_geometric_mean::history::{self, HistoryRecord};
+use pen_and_paper_geometric_mean::stats::{self, MethodStats, Trend, WeeklyStats};
+use std::time::Duration;
+
+use super::duration::DurationFormat;
+use super::sparkline::sparkline;
+
+fn trend_label(trend: Option<Trend>) -> &'static str {
+    match trend {
+        Some(Trend::Faster) => "faster",
+        Some(Trend::Steady) => "steady",
+        Some(Trend::Slower) => "slower",
+        None => "",
+    }
+}
+
+/// Format a table of accuracy and median solve time per week, with each week's trend against the last
+pub fn format_weekly_stats(weeks: &[WeeklyStats], time_format: &DurationFormat) -> String {
+    let mut output = String::new();
+    output.push_str("Weekly Trends\n");
+    output.push_str("=============\n");
+
+    if weeks.is_empty() {
+        output.push_str("No practice history yet.\n");
+        return output;
+    }
+
+    output.push_str(&format!("{:<10}  {:>6}  {:>8}  {:>11}  Trend\n", "Week of", "Rounds", "Accuracy", "Median time"));
+    for week in weeks {
+        let line = format!(
+            "{:<10}  {:>6}  {:>7.1}%  {:>11}  {}",
+            history::format_date(week.week_start),
+            week.rounds,
+            week.accuracy * 100.0,
+            format_time(week.median_duration, time_format),
+            trend_label(week.trend)
+        );
+        output.push_str(line.trim_end());
+        output.push('\n');
+    }
+
+    if weeks.len() > 1 {
+        let medians: Vec<Option<f64>> = weeks.iter().map(|week| week.median_duration.map(|d| d.as_secs_f64())).collect();
+        let line = format!("Median time trend, oldest to newest: {}", sparkline(&medians));
+        output.push_str(line.trim_end());
+        output.push('\n');
+    }
+
+    output
+}
+
+fn format_time(duration: Option<Duration>, time_format: &DurationFormat) -> String {
+    match duration {
+        Some(duration) => time_format.format(duration),
+        None => "-".to_string(),
+    }
+}
+
+/// Format a table comparing accuracy and solve times across estimation methods
+pub fn format_method_stats(methods: &[MethodStats], time_format: &DurationFormat) -> String {
+    let mut output = String::new();
+    output.push_str("By Method\n");
+    output.push_str("=========\n");
+
+    if methods.is_empty() {
+        output.push_str("No practice history yet.\n");
+        return output;
+    }
+
+    let method_width = methods.iter().map(|m| m.method.len()).chain(["Method".len()]).max().unwrap();
+    output.push_str(&format!(
+        "{:<method_width$}  {:>6}  {:>8}  {:>11}  {:>7}\n",
+        "Method", "Rounds", "Accuracy", "Median time", "Fastest"
+    ));
+    for method in methods {
+        output.push_str(&format!(
+            "{:<method_width$}  {:>6}  {:>7.1}%  {:>11}  {:>7}\n",
+            method.method,
+            method.rounds,
+            method.accuracy * 100.0,
+            format_time(method.median_duration, time_format),
+            format_time(method.fastest_duration, time_format)
+        ));
+    }
+
+    output
+}
+
+/// Command line options for `stats`, restricting which sessions are included
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StatsOptions {
+    /// Only include sessions carrying every one of these tags
+    pub tags: Vec<String>,
+    /// Only include sessions whose note contains this text, ignoring case
+    pub note: Option<String>,
+    /// Only include sessions recorded under this profile
+    pub profile: Option<String>,
+}
+
+impl StatsOptions {
+    /// Parse the arguments following `stats`
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut options = StatsOptions::default();
+
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--tag" => {
+                    let tag = iter.next().ok_or("--tag requires a label")?;
+                    options.tags.push(tag.clone());
+                }
+                "--note" => {
+                    let note = iter.next().ok_or("--note requires text")?;
+                    options.note = Some(note.clone());
+                }
+                "--profile" => {
+                    let profile = iter.next().ok_or("--profile requires a name")?;
+                    options.profile = Some(profile.clone());
+                }
+                other => return Err(format!("Unknown stats option: {}", other)),
+            }
+        }
+
+        Ok(options)
+    }
+
+    /// Whether a record belongs to a session selected by these options
+    pub fn matches(&self, record: &HistoryRecord) -> bool {
+        let tagged = self.tags.iter().all(|tag| record.has_tag(tag));
+        let noted = self.note.as_ref().is_none_or(|text| {
+            record.note.as_ref().is_some_and(|note| note.to_lowercase().contains(&text.to_lowercase()))
+        });
+        let profiled = self.profile.is_none() || record.profile == self.profile;
+        tagged && noted && profiled
+    }
+
+    /// Describe the active filters, if any
+    fn description(&self) -> Option<String> {
+        let mut filters: Vec<String> = Vec::new();
+        if let Some(profile) = &self.profile {
+            filters.push(format!("profile \"{}\"", profile));
+        }
+        filters.extend(self.tags.iter().map(|tag| format!("tag \"{}\"", tag)));
+        if let Some(note) = &self.note {
+            filters.push(format!("note containing \"{}\"", note));
+        }
+
+        if filters.is_empty() {
+            None
+        } else {
+            Some(format!("Showing sessions with {}\n", filters.join(" and ")))
+        }
+    }
+}
+
+fn load_history() -> Result<Vec<HistoryRecord>, String> {
+    let path = history::default_history_path().ok_or("Could not determine the history file location")?;
+    history::load_records(&path).map_err(|e| e.to_string())
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let options = StatsOptions::parse(args)?;
+    let records: Vec<HistoryRecord> = load_history()?.into_iter().filter(|r| options.matches(r)).collect();
+
+    if let Some(description) = options.description() {
+        println!("{}", description);
+    }
+    let user_config = config::load_default().unwrap_or_default();
+    let time_format = DurationFormat::new(user_config.time_precision)?;
+    print!("{}", format_weekly_stats(&stats::weekly_stats(&records), &time_format));
+    println!();
+    print!("{}", format_method_stats(&stats::method_stats(&records), &time_format));
+    Ok(())
+}
+
+/// Run the `stats` subcommand
+pub fn run_stats(args: &[String]) {
+    if let Err(e) = run(args) {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_format_weekly_stats() {
+        let weeks = vec![
+            WeeklyStats {
+                week_start: 1_759_708_800,
+                rounds: 12,
+                accuracy: 0.75,
+                median_duration: Some(Duration::from_millis(32_100)),
+                trend: None,
+            },
+            WeeklyStats {
+                week_start: 1_760_313_600,
+                rounds: 3,
+                accuracy: 1.0,
+                median_duration: Some(Duration::from_secs(28)),
+                trend: Some(Trend::Faster),
+            },
+            WeeklyStats {
+                week_start: 1_760_918_400,
+                rounds: 1,
+                accuracy: 0.0,
+                median_duration: None,
+                trend: None,
+            },
+        ];
+
+        let expected = "Weekly Trends\n\
+                        =============\n\
+                        Week of     Rounds  Accuracy  Median time  Trend\n\
+                        2025-10-06      12     75.0%        32.1s\n\
+                        2025-10-13       3    100.0%        28.0s  faster\n\
+                        2025-10-20       1      0.0%            -\n\
+                        Median time trend, oldest to newest: █▁\n";
+        assert_eq!(format_weekly_stats(&weeks, &DurationFormat::default()), expected);
+    }
+
+    #[test]
+    fn test_format_weekly_stats_empty() {
+        assert!(format_weekly_stats(&[], &DurationFormat::default()).contains("No practice history yet."));
+        let one_week = WeeklyStats { week_start: 0, rounds: 1, accuracy: 1.0, median_duration: None, trend: None };
+        assert!(!format_weekly_stats(&[one_week], &DurationFormat::default()).contains("trend"));
+    }
+
+    #[test]
+    fn test_format_method_stats() {
+        let methods = vec![
+            MethodStats {
+                method: "log-linear".to_string(),
+                rounds: 2,
+                accuracy: 0.5,
+                median_duration: Some(Duration::from_secs(60)),
+                fastest_duration: Some(Duration::from_millis(45_500)),
+            },
+            MethodStats {
+                method: "table-based".to_string(),
+                rounds: 10,
+                accuracy: 0.9,
+                median_duration: None,
+                fastest_duration: None,
+            },
+        ];
+
+        let expected = "By Method\n\
+                        =========\n\
+                        Method       Rounds  Accuracy  Median time  Fastest\n\
+                        log-linear        2     50.0%       1:00.0    45.5s\n\
+                        table-based      10     90.0%            -        -\n";
+        assert_eq!(format_method_stats(&methods, &DurationFormat::default()), expected);
+    }
+
+    fn record(tags: &[&str], note: Option<&str>) -> HistoryRecord {
+        HistoryRecord {
+            evaluation: pen_and_paper_geometric_mean::practice_mode::AnswerEvaluation::Correct,
+            method: "table-based".to_string(),
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            note: note.map(|n| n.to_string()),
+            ..HistoryRecord::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_stats_options() {
+        let args = |values: &[&str]| values.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(StatsOptions::parse(&args(&[])), Ok(StatsOptions::default()));
+        let options = StatsOptions::parse(&args(&["--tag", "warmup", "--note", "quiz"])).unwrap();
+        assert_eq!(options.tags, vec!["warmup".to_string()]);
+        assert_eq!(options.note, Some("quiz".to_string()));
+        let options = StatsOptions::parse(&args(&["--profile", "alice"])).unwrap();
+        assert_eq!(options.profile, Some("alice".to_string()));
+        assert!(StatsOptions::parse(&args(&["--tag"])).is_err());
+        assert!(StatsOptions::parse(&args(&["--verbose"])).is_err());
+    }
+
+    #[test]
+    fn test_stats_options_filtering() {
+        let everything = StatsOptions::default();
+        assert!(everything.matches(&record(&[], None)));
+
+        let tagged = StatsOptions { tags: vec!["Warmup".to_string(), "log-linear".to_string()], ..StatsOptions::default() };
+        assert!(tagged.matches(&record(&["warmup", "log-linear", "evening"], None)));
+        assert!(!tagged.matches(&record(&["warmup"], None)));
+
+        let noted = StatsOptions { note: Some("QUIZ".to_string()), ..StatsOptions::default() };
+        assert!(noted.matches(&record(&[], Some("Pre-quiz warmup"))));
+        assert!(!noted.matches(&record(&[], Some("relaxed"))));
+        assert!(!noted.matches(&record(&[], None)));
+
+        let profiled = StatsOptions { profile: Some("alice".to_string()), ..StatsOptions::default() };
+        let mut alice = record(&[], None);
+        alice.profile = Some("alice".to_string());
+        assert!(profiled.matches(&alice));
+        assert!(!profiled.matches(&record(&[], None)));
+    }
+}
@@ -0,0 +1,150 @@
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::time::Instant;
+
+use crate::cli::schema::{TRANSCRIPT_SCHEMA_VERSION, Versioned};
+
+/// Whether a transcript event was shown to the user or entered by them
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventKind {
+    Output,
+    Input,
+}
+
+/// One line of a session transcript, timed relative to the start of the session
+///
+/// Recorded stamped with [`TRANSCRIPT_SCHEMA_VERSION`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptEvent {
+    pub elapsed_ms: u64,
+    pub kind: EventKind,
+    pub text: String,
+}
+
+/// Console output that is optionally mirrored, along with user input, to a JSON-lines transcript file
+///
+/// Every event carries its offset from the start of the session, so a transcript can be replayed with its original pacing.
+pub struct Transcript {
+    sink: Option<Box<dyn Write>>,
+    start: Instant,
+}
+
+impl Transcript {
+    /// A transcript that only prints to the console
+    pub fn disabled() -> Self {
+        Transcript { sink: None, start: Instant::now() }
+    }
+
+    /// A transcript that prints to the console and records to a new file at `path`
+    pub fn create(path: &Path) -> io::Result<Self> {
+        let file = File::create(path)?;
+        Ok(Self::recording_to(Box::new(BufWriter::new(file))))
+    }
+
+    fn recording_to(sink: Box<dyn Write>) -> Self {
+        Transcript { sink: Some(sink), start: Instant::now() }
+    }
+
+    /// Show text to the user, recording it
+    pub fn output(&mut self, text: &str) {
+        print!("{}", text);
+        io::stdout().flush().unwrap();
+        self.record(EventKind::Output, text);
+    }
+
+    /// Record text the user entered
+    pub fn input(&mut self, text: &str) {
+        self.record(EventKind::Input, text);
+    }
+
+    fn record(&mut self, kind: EventKind, text: &str) {
+        let Some(sink) = self.sink.as_mut() else {
+            return;
+        };
+
+        let event = TranscriptEvent {
+            elapsed_ms: self.start.elapsed().as_millis() as u64,
+            kind,
+            text: text.to_string(),
+        };
+
+        let written = serde_json::to_string(&Versioned::new(TRANSCRIPT_SCHEMA_VERSION, event))
+            .map_err(io::Error::other)
+            .and_then(|line| writeln!(sink, "{}", line))
+            .and_then(|_| sink.flush());
+
+        if let Err(e) = written {
+            println!("Warning: transcript recording stopped: {}", e);
+            self.sink = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    /// Shared in-memory sink so tests can inspect what was recorded
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn recorded_events(buffer: &SharedBuffer) -> Vec<TranscriptEvent> {
+        String::from_utf8(buffer.0.borrow().clone())
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str::<Versioned<TranscriptEvent>>(line).unwrap())
+            .inspect(|versioned| assert_eq!(versioned.schema_version, TRANSCRIPT_SCHEMA_VERSION))
+            .map(|versioned| versioned.body)
+            .collect()
+    }
+
+    #[test]
+    fn test_records_output_and_input_in_order() {
+        let buffer = SharedBuffer::default();
+        let mut transcript = Transcript::recording_to(Box::new(buffer.clone()));
+
+        transcript.output("Enter your estimated geometric mean: ");
+        transcript.input("400\n");
+        transcript.output("Results:\n");
+
+        let events = recorded_events(&buffer);
+        let kinds: Vec<EventKind> = events.iter().map(|e| e.kind).collect();
+        let texts: Vec<&str> = events.iter().map(|e| e.text.as_str()).collect();
+
+        assert_eq!(kinds, vec![EventKind::Output, EventKind::Input, EventKind::Output]);
+        assert_eq!(texts, vec!["Enter your estimated geometric mean: ", "400\n", "Results:\n"]);
+        assert!(events.windows(2).all(|pair| pair[0].elapsed_ms <= pair[1].elapsed_ms));
+    }
+
+    #[test]
+    fn test_event_json_format() {
+        let event = TranscriptEvent { elapsed_ms: 1500, kind: EventKind::Input, text: "42\n".to_string() };
+        assert_eq!(
+            serde_json::to_string(&event).unwrap(),
+            "{\"elapsed_ms\":1500,\"kind\":\"input\",\"text\":\"42\\n\"}"
+        );
+    }
+
+    #[test]
+    fn test_disabled_transcript_records_nothing() {
+        let mut transcript = Transcript::disabled();
+        transcript.input("ignored");
+        assert!(transcript.sink.is_none());
+    }
+}
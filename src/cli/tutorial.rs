@@ -0,0 +1,131 @@
+use std::io::{self, Write};
+
+use pen_and_paper_geometric_mean::config::{self, UserConfig};
+use pen_and_paper_geometric_mean::history;
+use pen_and_paper_geometric_mean::tutorial::{Feedback, Tutorial};
+
+/// Shown instead of practice until the tutorial is done
+pub const LOCKED_MESSAGE: &str = "New here? Run `cargo run tutorial` first for a guided walk through the table method; \
+practice unlocks when you finish it.\nIf you already know the method, `cargo run tutorial --skip` unlocks practice straight away.";
+
+/// Practice is open once the tutorial is done, and to anyone who has practiced before it existed
+pub fn practice_unlocked(user_config: &UserConfig, has_history: bool) -> bool {
+    user_config.tutorial_completed || has_history
+}
+
+/// Whether any practice rounds are recorded, without reading them all
+pub fn has_practice_history() -> bool {
+    history::default_history_path()
+        .and_then(|path| std::fs::metadata(path).ok())
+        .is_some_and(|metadata| metadata.len() > 0)
+}
+
+/// Record that the tutorial is done, so practice is unlocked
+fn mark_completed() -> Result<(), String> {
+    let path = config::default_config_path().ok_or("Could not determine the config file location")?;
+    config::load(&path)
+        .and_then(|mut user_config| {
+            user_config.tutorial_completed = true;
+            config::save(&path, &user_config)
+        })
+        .map_err(|e| e.to_string())
+}
+
+/// Format the response to an entry
+pub fn format_feedback(feedback: &Feedback) -> String {
+    match feedback {
+        Feedback::Correct(confirmation) => format!("✓ {}\n", confirmation),
+        Feedback::Incorrect(explanation) => format!("✗ Not quite. {}\nTry again.\n", explanation),
+        Feedback::Invalid(error) => format!("Invalid input: {}. Please try again.\n", error),
+    }
+}
+
+/// Read one entry, or `None` at end of input
+fn read_entry() -> Option<String> {
+    print!("> ");
+    io::stdout().flush().unwrap();
+
+    let mut input = String::new();
+    match io::stdin().read_line(&mut input) {
+        Ok(0) | Err(_) => None,
+        Ok(_) => Some(input),
+    }
+}
+
+/// Walk through the table method step by step, unlocking practice at the end; `--skip` unlocks it straight away
+pub fn run_tutorial(args: &[String]) {
+    match args {
+        [] => {}
+        [flag] if flag == "--skip" => {
+            match mark_completed() {
+                Ok(()) => println!("Tutorial skipped; practice is unlocked."),
+                Err(e) => println!("Could not save progress: {}", e),
+            }
+            return;
+        }
+        _ => {
+            println!("Usage: cargo run tutorial [--skip]");
+            return;
+        }
+    }
+
+    println!("Tutorial - Table-Based Geometric Mean");
+    println!("=====================================");
+    println!();
+    println!("The table gives what a number starts with at each tenth of its log:");
+    println!("  0.0 → 1   0.1 → 1.25   0.2 → 1.6   0.3 → 2   0.4 → 2.5");
+    println!("  0.5 → 3   0.6 → 4      0.7 → 5     0.8 → 6   0.9 → 8");
+    println!("Enter each answer as a number, like 2.5 for a log or 300 for an estimate.");
+
+    let mut tutorial = Tutorial::default();
+    while let Some(step) = tutorial.current() {
+        if let Some(introduction) = &step.introduction {
+            println!();
+            println!("{}", introduction);
+        }
+        println!();
+        println!("{}", step.prompt());
+
+        loop {
+            let Some(entry) = read_entry() else {
+                println!();
+                println!("Tutorial stopped; run it again to start over.");
+                return;
+            };
+            let feedback = tutorial.submit(&entry);
+            print!("{}", format_feedback(&feedback));
+            if matches!(feedback, Feedback::Correct(_)) {
+                break;
+            }
+        }
+    }
+
+    println!();
+    println!("That's the whole method: convert, add, divide rounding up, and convert back.");
+    match mark_completed() {
+        Ok(()) => println!("Practice is unlocked; start with `cargo run practice`, or `cargo run calibrate` to find your level."),
+        Err(e) => println!("Could not save progress: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_practice_unlocked() {
+        let new_user = UserConfig::default();
+        assert!(!practice_unlocked(&new_user, false));
+        assert!(practice_unlocked(&new_user, true));
+        assert!(practice_unlocked(&UserConfig { tutorial_completed: true, ..UserConfig::default() }, false));
+    }
+
+    #[test]
+    fn test_format_feedback() {
+        assert_eq!(format_feedback(&Feedback::Correct("Right, 3.3.".to_string())), "✓ Right, 3.3.\n");
+        assert_eq!(
+            format_feedback(&Feedback::Incorrect("Round up.".to_string())),
+            "✗ Not quite. Round up.\nTry again.\n"
+        );
+    }
+}
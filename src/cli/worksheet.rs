@@ -0,0 +1,287 @@
+use std::path::{Path, PathBuf};
+
+use pen_and_paper_geometric_mean::advisor::parse_uncertainty;
+use pen_and_paper_geometric_mean::exact::ExactGeometricMean;
+use pen_and_paper_geometric_mean::practice_mode::{ANSWER_RANGE, PracticeModeConfig, Problem};
+use pen_and_paper_geometric_mean::questions::{self, Question};
+use pen_and_paper_geometric_mean::rng;
+use pen_and_paper_geometric_mean::table_based::TableBasedApproximation;
+use pen_and_paper_geometric_mean::traits::EstimateGeometricMean;
+
+use super::latex::{format_answer_key_latex, format_worksheet_latex, standalone};
+use super::practice_mode::{format_number, format_question_display};
+
+/// Options for `worksheet`
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorksheetOptions {
+    pub problems: usize,
+    pub team_size: usize,
+    pub log_std_dev: f64,
+    pub seed: Option<u64>,
+    /// Question bank whose true answers the team's guesses are drawn around, shown above each problem
+    pub question_bank: Option<PathBuf>,
+    /// Write a PDF here, with the answer key on its own page, instead of printing
+    pub pdf: Option<PathBuf>,
+    /// Write a LaTeX fragment here, with a step-by-step answer key, instead of printing
+    pub latex: Option<PathBuf>,
+    /// Wrap the LaTeX in a document that compiles on its own, rather than a fragment to `\input`
+    pub standalone: bool,
+}
+
+impl Default for WorksheetOptions {
+    fn default() -> Self {
+        WorksheetOptions {
+            problems: 10,
+            team_size: 4,
+            log_std_dev: 1.0,
+            seed: None,
+            question_bank: None,
+            pdf: None,
+            latex: None,
+            standalone: false,
+        }
+    }
+}
+
+impl WorksheetOptions {
+    /// Parse the arguments following `worksheet`
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        let mut options = WorksheetOptions::default();
+
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "--problems" => {
+                    let value = iter.next().ok_or("--problems requires a number of problems")?;
+                    options.problems = value
+                        .parse()
+                        .ok()
+                        .filter(|&problems| problems > 0)
+                        .ok_or_else(|| format!("Invalid number of problems: {}", value))?;
+                }
+                "--team-size" => {
+                    let value = iter.next().ok_or("--team-size requires a number of guesses")?;
+                    options.team_size = value.parse().map_err(|_| format!("Invalid team size: {}", value))?;
+                }
+                "--uncertainty" => {
+                    let value = iter.next().ok_or("--uncertainty requires a level or spread")?;
+                    options.log_std_dev = parse_uncertainty(value)?;
+                }
+                "--seed" => {
+                    let value = iter.next().ok_or("--seed requires a number")?;
+                    options.seed = Some(value.parse().map_err(|_| format!("Invalid seed: {}", value))?);
+                }
+                "--bank" => {
+                    let value = iter.next().ok_or("--bank requires a question bank file")?;
+                    options.question_bank = Some(PathBuf::from(value));
+                }
+                "--pdf" => {
+                    let value = iter.next().ok_or("--pdf requires a file path")?;
+                    options.pdf = Some(PathBuf::from(value));
+                }
+                "--latex" => {
+                    let value = iter.next().ok_or("--latex requires a file path")?;
+                    options.latex = Some(PathBuf::from(value));
+                }
+                "--standalone" => options.standalone = true,
+                other => return Err(format!("Unknown worksheet option: {}", other)),
+            }
+        }
+
+        if options.standalone && options.latex.is_none() {
+            return Err("--standalone only applies to --latex".to_string());
+        }
+
+        Ok(options)
+    }
+}
+
+/// Format the problems with a blank to fill in for each, leaving room to work
+///
+/// `questions` is empty without a question bank, and otherwise holds the question each problem was drawn around.
+pub fn format_worksheet(problems: &[Problem], questions: &[Question]) -> String {
+    let mut output = String::new();
+    output.push_str("Geometric Mean Worksheet\n");
+    output.push_str("========================\n");
+    output.push_str("Estimate the geometric mean of each team's guesses.\n");
+    for (i, problem) in problems.iter().enumerate() {
+        let guesses: Vec<String> = problem.guesses.iter().map(|&g| format_number(g)).collect();
+        match questions.get(i) {
+            Some(question) => output.push_str(&format!(
+                "\n{:>2}. {}    {}\n\n    Estimate: ____________\n",
+                i + 1,
+                format_question_display(question),
+                guesses.join(", ")
+            )),
+            None => output.push_str(&format!("\n{:>2}. {}\n\n    Estimate: ____________\n", i + 1, guesses.join(", "))),
+        }
+    }
+    output
+}
+
+/// Format the table-based answer and the exact geometric mean for each problem
+pub fn format_answer_key(problems: &[Problem]) -> String {
+    let mut output = String::new();
+    output.push_str("Answer Key\n");
+    output.push_str("==========\n");
+    output.push_str("     Table-based        Exact\n");
+    for (i, problem) in problems.iter().enumerate() {
+        let values: Vec<f64> = problem.guesses.iter().map(|&g| g as f64).collect();
+        let answer = |estimate: Option<f64>| estimate.map_or("-".to_string(), |e| format_number(e.round() as u64));
+        output.push_str(&format!(
+            "{:>2}. {:>12} {:>12}\n",
+            i + 1,
+            answer(TableBasedApproximation::estimate_geometric_mean(&values).ok()),
+            answer(ExactGeometricMean::estimate_geometric_mean(&values).ok())
+        ));
+    }
+    output
+}
+
+/// Write pages of text to a PDF, each starting on a new sheet
+#[cfg(feature = "pdf")]
+pub fn save_pdf(path: &Path, pages: &[String]) -> Result<(), String> {
+    let mut document = pen_and_paper_geometric_mean::pdf::PdfDocument::new();
+    for page in pages {
+        document.add_page(page);
+    }
+    document.save(path).map_err(|e| format!("Could not write {}: {}", path.display(), e))
+}
+
+#[cfg(not(feature = "pdf"))]
+pub fn save_pdf(_path: &Path, _pages: &[String]) -> Result<(), String> {
+    Err("PDF output needs the pdf feature: cargo run --features pdf".to_string())
+}
+
+/// Print a worksheet of practice problems and its answer key, or write both to a PDF
+pub fn run_worksheet(args: &[String]) {
+    let options = match WorksheetOptions::parse(args) {
+        Ok(options) => options,
+        Err(e) => {
+            println!("{}", e);
+            return;
+        }
+    };
+
+    let config = match PracticeModeConfig::new(options.team_size, options.log_std_dev, ANSWER_RANGE.0, ANSWER_RANGE.1) {
+        Ok(config) => config,
+        Err(e) => {
+            println!("{}", e);
+            return;
+        }
+    };
+    let question_bank = match &options.question_bank {
+        Some(path) => match questions::load(path) {
+            Ok(bank) => Some(bank),
+            Err(e) => {
+                println!("Error loading {}: {}", path.display(), e);
+                return;
+            }
+        },
+        None => None,
+    };
+
+    let mut rng = options.seed.map_or_else(rng::from_entropy, rng::seeded);
+    let mut drawn_questions = Vec::new();
+    let mut problems = Vec::with_capacity(options.problems);
+    for _ in 0..options.problems {
+        // A bank question fixes the true answer, and the team's guesses are drawn around it
+        let problem = match &question_bank {
+            Some(bank) => {
+                let question = bank.random_question(&mut rng).clone();
+                let problem = Problem::generate_around(&mut rng, &config, question.answer);
+                drawn_questions.push(question);
+                problem
+            }
+            None => Problem::generate(&mut rng, &config),
+        };
+        match problem {
+            Ok(problem) => problems.push(problem),
+            Err(e) => {
+                println!("{}", e);
+                return;
+            }
+        }
+    }
+
+    if let Some(path) = &options.latex {
+        let fragment = format!("{}\\newpage\n{}", format_worksheet_latex(&problems, &drawn_questions), format_answer_key_latex(&problems));
+        let latex = if options.standalone { standalone(&fragment) } else { fragment };
+        match std::fs::write(path, latex) {
+            Ok(()) => println!("Wrote a LaTeX worksheet of {} problem(s) to {}", problems.len(), path.display()),
+            Err(e) => println!("Could not write {}: {}", path.display(), e),
+        }
+    }
+
+    let (worksheet, answer_key) = (format_worksheet(&problems, &drawn_questions), format_answer_key(&problems));
+    match &options.pdf {
+        Some(path) => match save_pdf(path, &[worksheet, answer_key]) {
+            Ok(()) => println!("Wrote a worksheet of {} problem(s) to {}", problems.len(), path.display()),
+            Err(e) => println!("{}", e),
+        },
+        None if options.latex.is_none() => print!("{}\n{}", worksheet, answer_key),
+        None => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn problems() -> Vec<Problem> {
+        vec![
+            Problem { guesses: vec![2_000, 50, 350], correct_answer: 300 },
+            Problem { guesses: vec![9_001, 12_000], correct_answer: 10_000 },
+        ]
+    }
+
+    #[test]
+    fn test_parse_worksheet_options() {
+        let args = |values: &[&str]| values.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(WorksheetOptions::parse(&args(&[])), Ok(WorksheetOptions::default()));
+        assert_eq!(
+            WorksheetOptions::parse(&args(&["--problems", "20", "--team-size", "6", "--uncertainty", "high", "--seed", "7", "--pdf", "out.pdf"])),
+            Ok(WorksheetOptions {
+                problems: 20,
+                team_size: 6,
+                log_std_dev: 1.5,
+                seed: Some(7),
+                question_bank: None,
+                pdf: Some(PathBuf::from("out.pdf")),
+                latex: None,
+                standalone: false,
+            })
+        );
+        assert!(WorksheetOptions::parse(&args(&["--problems", "0"])).is_err());
+        assert!(WorksheetOptions::parse(&args(&["--pdf"])).is_err());
+        let banked = WorksheetOptions::parse(&args(&["--bank", "league.toml"])).unwrap();
+        assert_eq!(banked.question_bank, Some(PathBuf::from("league.toml")));
+        assert!(WorksheetOptions::parse(&args(&["--bank"])).is_err());
+        let latex = WorksheetOptions::parse(&args(&["--latex", "sheet.tex", "--standalone"])).unwrap();
+        assert_eq!((latex.latex, latex.standalone), (Some(PathBuf::from("sheet.tex")), true));
+        assert!(WorksheetOptions::parse(&args(&["--standalone"])).is_err());
+        assert!(WorksheetOptions::parse(&args(&["--bogus"])).is_err());
+    }
+
+    #[test]
+    fn test_format_worksheet() {
+        let worksheet = format_worksheet(&problems(), &[]);
+        assert!(worksheet.contains("\n 1. 2,000, 50, 350\n\n    Estimate: ____________\n"));
+        assert!(worksheet.contains("\n 2. 9,001, 12,000\n"));
+        // The true answers stay off the worksheet
+        assert!(!worksheet.contains("10,000"));
+
+        let question = Question { text: "How many steps up the Eiffel Tower?".to_string(), answer: 674, category: None, source: None };
+        let worksheet = format_worksheet(&problems()[..1], &[question]);
+        assert!(worksheet.contains("\n 1. Question: How many steps up the Eiffel Tower?\n    2,000, 50, 350\n\n    Estimate: ____________\n"));
+        assert!(!worksheet.contains("674"));
+    }
+
+    #[test]
+    fn test_format_answer_key() {
+        let key = format_answer_key(&problems());
+        assert!(key.contains("\n 1.          300          327\n"), "{}", key);
+        assert!(key.contains("\n 2.       10,000       10,393\n"), "{}", key);
+    }
+}
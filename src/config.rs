@@ -0,0 +1,195 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::practice_mode::{ConfigurationError, PracticeModeConfig};
+
+/// User preferences persisted between runs as JSON in the config directory
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UserConfig {
+    /// Name of the difficulty level the practice settings came from, if calibrated
+    pub difficulty: Option<String>,
+    pub team_size: usize,
+    pub log_std_dev: f64,
+    pub min_answer: u64,
+    pub max_answer: u64,
+    /// Optional per-problem time limit, shown as a live countdown while answering
+    pub time_limit_seconds: Option<u64>,
+    /// Ring the terminal bell after each answer
+    pub bell: bool,
+    /// Record practice rounds to history and missed problems for review; disable on shared machines
+    pub history: bool,
+    /// Whether the tutorial has been finished (or skipped), which unlocks practice for new users
+    pub tutorial_completed: bool,
+    /// Quick sums and divisions to answer before practice starts; 0 for none
+    pub warm_up_questions: usize,
+    /// Decimal places shown on solve times, from 0 to 3
+    pub time_precision: usize,
+}
+
+impl Default for UserConfig {
+    fn default() -> Self {
+        UserConfig {
+            difficulty: None,
+            team_size: 4,
+            log_std_dev: 4.0,
+            min_answer: 10,
+            max_answer: 1_000_000_000,
+            time_limit_seconds: None,
+            bell: false,
+            history: true,
+            tutorial_completed: false,
+            warm_up_questions: 0,
+            time_precision: 1,
+        }
+    }
+}
+
+impl UserConfig {
+    /// Validated practice mode configuration described by these settings
+    pub fn practice_config(&self) -> Result<PracticeModeConfig, ConfigurationError> {
+        PracticeModeConfig::new(self.team_size, self.log_std_dev, self.min_answer, self.max_answer)
+    }
+
+    pub fn time_limit(&self) -> Option<Duration> {
+        self.time_limit_seconds.map(Duration::from_secs)
+    }
+
+    /// Replace the practice settings, remembering which difficulty they came from
+    pub fn set_practice_config(&mut self, difficulty: &str, config: &PracticeModeConfig) {
+        self.difficulty = Some(difficulty.to_string());
+        self.team_size = config.team_size;
+        self.log_std_dev = config.log_std_dev;
+        self.min_answer = config.min_answer;
+        self.max_answer = config.max_answer;
+    }
+}
+
+/// Errors that can occur while reading or writing the config file
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(io::Error),
+    Malformed(String),
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Io(e) => write!(f, "Config file error: {}", e),
+            ConfigError::Malformed(message) => write!(f, "Malformed config file: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl From<io::Error> for ConfigError {
+    fn from(e: io::Error) -> Self {
+        ConfigError::Io(e)
+    }
+}
+
+/// Default location of the config file, inside the user's config directory
+pub fn default_config_path() -> Option<PathBuf> {
+    crate::paths::config_dir().map(|dir| dir.join("config.json"))
+}
+
+/// Load the config file from its default location, falling back to defaults when there is none
+pub fn load_default() -> Result<UserConfig, ConfigError> {
+    match default_config_path() {
+        Some(path) => load(&path),
+        None => Ok(UserConfig::default()),
+    }
+}
+
+/// Load the config file; a missing file yields the defaults
+pub fn load(path: &Path) -> Result<UserConfig, ConfigError> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(UserConfig::default()),
+        Err(e) => return Err(e.into()),
+    };
+
+    serde_json::from_str(&contents).map_err(|e| ConfigError::Malformed(e.to_string()))
+}
+
+/// Write the config file, creating its directory if necessary
+pub fn save(path: &Path, config: &UserConfig) -> Result<(), ConfigError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let contents = serde_json::to_string_pretty(config).map_err(|e| ConfigError::Malformed(e.to_string()))?;
+    fs::write(path, contents + "\n")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_config_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("geomean-config-test-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join("config.json")
+    }
+
+    #[test]
+    fn test_default_matches_original_fixed_configuration() {
+        let config = UserConfig::default().practice_config().unwrap();
+        assert_eq!(config, PracticeModeConfig::new(4, 4.0, 10, 1_000_000_000).unwrap());
+    }
+
+    #[test]
+    fn test_missing_file_yields_defaults() {
+        let path = temp_config_path("missing");
+        assert_eq!(load(&path).unwrap(), UserConfig::default());
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let path = temp_config_path("round-trip");
+        let mut config = UserConfig::default();
+        config.set_practice_config("Beginner", &PracticeModeConfig::new(2, 0.5, 10, 1000).unwrap());
+
+        save(&path, &config).unwrap();
+
+        assert_eq!(load(&path).unwrap(), config);
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_partial_file_fills_in_defaults() {
+        let path = temp_config_path("partial");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "{\"team_size\": 6}").unwrap();
+
+        let config = load(&path).unwrap();
+        assert_eq!(config.team_size, 6);
+        assert_eq!(config.max_answer, UserConfig::default().max_answer);
+        assert_eq!(config.time_limit(), None);
+        assert!(!config.bell);
+        assert!(config.history);
+        assert!(!config.tutorial_completed);
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_time_limit() {
+        let config = UserConfig { time_limit_seconds: Some(45), ..UserConfig::default() };
+        assert_eq!(config.time_limit(), Some(Duration::from_secs(45)));
+    }
+
+    #[test]
+    fn test_malformed_file_is_an_error() {
+        let path = temp_config_path("malformed");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "not json").unwrap();
+
+        assert!(matches!(load(&path), Err(ConfigError::Malformed(_))));
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,165 @@
+//! Formatting pieces shared by the step-by-step solutions, which write straight into the formatter without allocating.
+//!
+//! Frontends render thousands of solutions into reused buffers or output streams, so the `Display` impls avoid
+//! building intermediate strings for numbers and lists.
+
+use core::fmt::{self, Display, Formatter, Write};
+
+use crate::math;
+
+/// A value as a whole number when it is one, and in full otherwise
+#[derive(Clone, Copy)]
+pub(crate) struct Value(pub f64);
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if math::fract(self.0) == 0.0 { write!(f, "{}", self.0 as u64) } else { write!(f, "{}", self.0) }
+    }
+}
+
+/// A table log, stored in tenths, to one decimal place
+#[derive(Clone, Copy)]
+pub(crate) struct TableLog(pub i32);
+
+impl Display for TableLog {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.1}", self.0 as f64 / 10.0)
+    }
+}
+
+/// A value to at most four decimal places, without trailing zeros
+#[derive(Clone, Copy)]
+pub(crate) struct Decimal(pub f64);
+
+impl Display for Decimal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut buffer = StackBuffer::new();
+        write!(buffer, "{:.4}", self.0)?;
+        f.write_str(buffer.as_str().trim_end_matches('0').trim_end_matches('.'))
+    }
+}
+
+/// Longest `{:.4}` rendering of an f64: 309 digits for the largest, a sign, a point, and four decimals
+const DECIMAL_CAPACITY: usize = 320;
+
+/// Fixed-size text on the stack, for trimming a formatted number before writing it out
+struct StackBuffer {
+    bytes: [u8; DECIMAL_CAPACITY],
+    len: usize,
+}
+
+impl StackBuffer {
+    fn new() -> Self {
+        StackBuffer { bytes: [0; DECIMAL_CAPACITY], len: 0 }
+    }
+
+    fn as_str(&self) -> &str {
+        // Only whole `&str`s are ever copied in, so the contents are valid UTF-8
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+impl Write for StackBuffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes.get_mut(self.len..end).ok_or(fmt::Error)?.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Items written one after another with a separator between them
+pub(crate) struct Joined<I> {
+    items: I,
+    separator: &'static str,
+}
+
+/// Write `items` separated by `separator`, like joining their strings but without building any
+pub(crate) fn joined<I>(items: I, separator: &'static str) -> Joined<I::IntoIter>
+where
+    I: IntoIterator,
+    I::IntoIter: Clone,
+    I::Item: Display,
+{
+    Joined { items: items.into_iter(), separator }
+}
+
+impl<I> Display for Joined<I>
+where
+    I: Iterator + Clone,
+    I::Item: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, item) in self.items.clone().enumerate() {
+            if i > 0 {
+                f.write_str(self.separator)?;
+            }
+            Display::fmt(&item, f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use core::cell::Cell;
+    use std::alloc::{GlobalAlloc, Layout, System};
+
+    use crate::harmonic::ReciprocalTableApproximation;
+    use crate::log_linear::LogLinearApproximation;
+    use crate::median::UpperQuartileMethod;
+    use crate::table_based::TableBasedApproximation;
+    use crate::traits::EstimateGeometricMeanStepByStep;
+
+    /// Counts allocations made by the current thread, so tests running in parallel don't disturb each other
+    struct CountingAllocator;
+
+    std::thread_local! {
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+            // SAFETY: forwarded unchanged to the system allocator
+            unsafe { System.alloc(layout) }
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            // SAFETY: forwarded unchanged to the system allocator
+            unsafe { System.dealloc(ptr, layout) }
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    /// Allocations made while rendering `steps` into a buffer that already has room
+    fn allocations_rendering(steps: &impl Display) -> usize {
+        let mut buffer = alloc::string::String::with_capacity(4096);
+        let before = ALLOCATIONS.with(Cell::get);
+        write!(buffer, "{}", steps).unwrap();
+        ALLOCATIONS.with(Cell::get) - before
+    }
+
+    #[test]
+    fn test_steps_render_without_allocating() {
+        let values = [300.0, 10_000.0, 900.0, 70.0, 3.25];
+        assert_eq!(allocations_rendering(&TableBasedApproximation::estimate_geometric_mean_steps(&values).unwrap()), 0);
+        assert_eq!(allocations_rendering(&LogLinearApproximation::estimate_geometric_mean_steps(&values).unwrap()), 0);
+        assert_eq!(allocations_rendering(&ReciprocalTableApproximation::estimate_geometric_mean_steps(&values).unwrap()), 0);
+        assert_eq!(allocations_rendering(&UpperQuartileMethod::estimate_geometric_mean_steps(&values).unwrap()), 0);
+    }
+
+    #[test]
+    fn test_adapters_match_string_formatting() {
+        assert_eq!(format!("{} {} {}", Value(2_000.0), Value(2.5), Value(1e6)), "2000 2.5 1000000");
+        assert_eq!(format!("{} {} {}", TableLog(33), TableLog(-4), TableLog(0)), "3.3 -0.4 0.0");
+        assert_eq!(format!("{} {} {} {}", Decimal(3.05), Decimal(4.0), Decimal(2.84712), Decimal(0.00001)), "3.05 4 2.8471 0");
+        assert_eq!(format!("{}", Decimal(f64::MAX)).len(), 309);
+        assert_eq!(format!("[{}]", joined([1, 2, 3], ", ")), "[1, 2, 3]");
+        assert_eq!(format!("[{}]", joined(core::iter::empty::<i32>(), ", ")), "[]");
+    }
+}
@@ -0,0 +1,184 @@
+//! Internal consistency checks, for confirming a build behaves as intended after building from source or porting.
+//!
+//! The checks are quick and deterministic: table and log-linear conversions round-trip, the README's worked examples
+//! give their documented answers, and on a fixed corpus every procedure stays within its analytical error bound.
+//! A failure usually points at platform floating point differences in logarithms or rounding.
+
+use crate::error_budget::{PROCEDURES, Procedure};
+use crate::exact::geometric_mean;
+use crate::log_linear::{convert_from_log_linear, convert_to_log_linear};
+use crate::methods::estimate_by_name;
+use crate::practice_mode::{PracticeModeConfig, Problem};
+use crate::rng;
+use crate::table_based::{MULTIPLIERS, TableBasedApproximation, log_representation_to_number, number_to_log_representation};
+use crate::traits::EstimateGeometricMean;
+
+/// Worked examples with documented answers: the method, the values, and the answer
+pub const KNOWN_CASES: [(&str, &[f64], f64); 9] = [
+    ("exact", &[25.0, 400.0], 100.0),
+    ("exact", &[2.0, 8.0], 4.0),
+    ("log-linear", &[300.0, 10_000.0, 900.0, 70.0], 750.0),
+    ("log-linear", &[80.0, 80.0, 80.0, 800.0], 100.0),
+    ("table-based", &[2_000.0, 50.0, 350.0], 300.0),
+    ("table-based", &[25.0, 400.0], 100.0),
+    ("table-based", &[80.0, 800.0, 9_000.0, 30.0], 400.0),
+    ("auto-shortcut", &[2.0, 8.0, 10.0, 1_000.0], 20.0),
+    ("auto-shortcut", &[2_000.0, 50.0, 350.0], 300.0),
+];
+
+/// Values whose log-linear form reads back to themselves
+const LOG_LINEAR_VALUES: [f64; 7] = [1.0, 7.0, 70.0, 300.0, 2_847.0, 12_345.0, 99_999.0];
+
+/// Powers of ten the table conversions are checked across
+const TABLE_MAGNITUDES: i32 = 7;
+
+const CORPUS_SEED: u64 = 7;
+const CORPUS_SIZE: usize = 1_000;
+const CORPUS_TEAM_SIZE: usize = 4;
+
+/// Tolerance for comparing floating point results that should agree
+const EPSILON: f64 = 1e-9;
+
+/// The outcome of one check
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    pub name: &'static str,
+    /// What went wrong, one entry per failing case; empty when the check passed
+    pub failures: Vec<String>,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+fn close(actual: f64, expected: f64) -> bool {
+    (actual - expected).abs() <= EPSILON * expected.abs().max(1.0)
+}
+
+/// Every table entry, at every magnitude, converts to a log and back to itself
+fn check_table_round_trip() -> Check {
+    let failures = (0..TABLE_MAGNITUDES * 10)
+        .filter_map(|scaled_log| {
+            let number = log_representation_to_number(scaled_log);
+            let back = number_to_log_representation(number);
+            (back != scaled_log).then(|| format!("{} → {} → {}", scaled_log, number, back))
+        })
+        .collect();
+    Check { name: "table conversions round-trip", failures }
+}
+
+fn check_log_linear_round_trip() -> Check {
+    let failures = LOG_LINEAR_VALUES
+        .iter()
+        .filter_map(|&value| {
+            let log = convert_to_log_linear(value);
+            let back = convert_from_log_linear(log);
+            (!close(back, value)).then(|| format!("{} → {} → {}", value, log, back))
+        })
+        .collect();
+    Check { name: "log-linear conversions round-trip", failures }
+}
+
+fn check_known_cases() -> Check {
+    let failures = KNOWN_CASES
+        .iter()
+        .filter_map(|&(method, values, expected)| match estimate_by_name(method, values) {
+            Some(Ok(actual)) if close(actual, expected) => None,
+            Some(Ok(actual)) => Some(format!("{} of {:?} gave {}, expected {}", method, values, actual, expected)),
+            Some(Err(e)) => Some(format!("{} of {:?} failed: {}", method, values, e)),
+            None => Some(format!("no method named {}", method)),
+        })
+        .collect();
+    Check { name: "known cases", failures }
+}
+
+/// Practice-style team guesses, the same on every run
+fn corpus() -> Vec<Vec<f64>> {
+    let config = PracticeModeConfig::new(CORPUS_TEAM_SIZE, 1.5, 10, 1_000_000).expect("corpus config is valid");
+    let mut rng = rng::seeded(CORPUS_SEED);
+    (0..CORPUS_SIZE)
+        .map(|_| {
+            let problem = Problem::generate(&mut rng, &config).expect("corpus problems are valid");
+            problem.guesses.iter().map(|&g| g as f64).collect()
+        })
+        .collect()
+}
+
+/// Every procedure's error against the exact geometric mean stays within its analytical bound
+fn check_error_bounds(corpus: &[Vec<f64>]) -> Check {
+    let mut failures = Vec::new();
+    for guesses in corpus {
+        let Ok(exact) = geometric_mean(guesses) else {
+            failures.push(format!("exact failed on {:?}", guesses));
+            continue;
+        };
+        for procedure in PROCEDURES {
+            let bound = procedure.error_bound(guesses.len());
+            match procedure.estimate(guesses) {
+                Some(estimate) if (estimate - exact).abs() / exact <= bound + EPSILON => {}
+                Some(estimate) => failures.push(format!(
+                    "{} gave {} for {:?}, beyond its {:.1}% bound of {}",
+                    procedure.name(),
+                    estimate,
+                    guesses,
+                    bound * 100.0,
+                    exact
+                )),
+                None => failures.push(format!("{} failed on {:?}", procedure.name(), guesses)),
+            }
+        }
+    }
+    Check { name: "errors within bounds", failures }
+}
+
+/// The error budget's table procedure and the table-based method agree
+fn check_table_procedures_agree(corpus: &[Vec<f64>]) -> Check {
+    let failures = corpus
+        .iter()
+        .filter_map(|guesses| {
+            let procedure = Procedure::Table(&MULTIPLIERS).estimate(guesses);
+            let method = TableBasedApproximation::estimate_geometric_mean(guesses).ok();
+            (procedure != method).then(|| format!("{:?}: {:?} and {:?}", guesses, procedure, method))
+        })
+        .collect();
+    Check { name: "table implementations agree", failures }
+}
+
+/// Run every check, in order from the simplest
+pub fn run_checks() -> Vec<Check> {
+    let corpus = corpus();
+    vec![
+        check_table_round_trip(),
+        check_log_linear_round_trip(),
+        check_known_cases(),
+        check_error_bounds(&corpus),
+        check_table_procedures_agree(&corpus),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_check_passes() {
+        for check in run_checks() {
+            assert!(check.passed(), "{}: {:?}", check.name, check.failures);
+        }
+    }
+
+    #[test]
+    fn test_failures_are_reported() {
+        let check = check_error_bounds(&[vec![]]);
+        assert_eq!(check.failures, vec!["exact failed on []".to_string()]);
+        assert!(!check.passed());
+    }
+
+    #[test]
+    fn test_corpus_is_fixed() {
+        assert_eq!(corpus(), corpus());
+        assert!(corpus().iter().all(|guesses| guesses.len() == CORPUS_TEAM_SIZE));
+    }
+}
@@ -0,0 +1,817 @@
+//! Quick-fire drills for the sub-skills behind the pen-and-paper methods.
+//!
+//! Where practice mode works a whole problem, a drill isolates one step, like the plain mental addition of averaging
+//! large round numbers.
+//! Each question carries its exact answer and a tolerance, and is timed and graded with the same [`Timer`] and
+//! [`AnswerEvaluation`] tiers as practice mode.
+
+use rand::Rng;
+use std::time::Duration;
+
+use crate::fermi::FermiProblem;
+use crate::practice_mode::{AnswerEvaluation, Timer};
+use crate::table_based::{MULTIPLIERS, log_representation_to_number, number_to_log_representation};
+
+/// How far from the exact answer a drill answer may be and still count as Correct
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Tolerance {
+    /// Within this fraction of the exact answer, e.g. 0.01 for 1%
+    Relative(f64),
+    /// Within this distance of the exact answer
+    Absolute(f64),
+    /// Within the looser of a distance and a fraction of the exact answer, for answers that range from small to large
+    AbsoluteOrRelative(f64, f64),
+    /// Within this factor of the exact answer either way, e.g. 1.5 for anything from two thirds to one and a half times
+    Factor(f64),
+}
+
+impl Tolerance {
+    pub fn accepts(&self, answer: f64, exact: f64) -> bool {
+        // Leave room for floating point error in answers given exactly at the boundary
+        let within = |difference: f64, allowed: f64| difference <= allowed * (1.0 + 1e-9);
+        match *self {
+            Tolerance::Relative(fraction) => within((answer - exact).abs(), fraction * exact.abs()),
+            Tolerance::Absolute(distance) => within((answer - exact).abs(), distance),
+            Tolerance::AbsoluteOrRelative(distance, fraction) => {
+                within((answer - exact).abs(), distance.max(fraction * exact.abs()))
+            }
+            Tolerance::Factor(factor) => answer > 0.0 && exact > 0.0 && within((answer / exact).max(exact / answer), factor),
+        }
+    }
+}
+
+/// A single drill question: what to show, the exact answer, and what counts as close enough
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DrillQuestion {
+    pub prompt: String,
+    pub exact_answer: f64,
+    pub tolerance: Tolerance,
+    /// The worked solution, shown when the answer is missed
+    pub working: String,
+}
+
+impl DrillQuestion {
+    pub fn evaluate(&self, answer: f64) -> AnswerEvaluation {
+        if self.tolerance.accepts(answer, self.exact_answer) {
+            AnswerEvaluation::Correct
+        } else {
+            AnswerEvaluation::Incorrect
+        }
+    }
+}
+
+/// The kinds of drill available
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DrillKind {
+    /// Average three to five round numbers of the same order of magnitude, from thousands to billions
+    ArithmeticMean,
+    /// Say by what percent an answer missed the exact value
+    PercentError,
+    /// Say how many times too high or too low an answer was
+    FactorError,
+    /// Estimate log10 of a number to one decimal place with the table
+    Log10,
+    /// Estimate the square root of a large number with the table
+    SquareRoot,
+    /// Estimate the cube root of a large number with the table
+    CubeRoot,
+    /// Estimate the square or cube of a number with the table
+    Power,
+    /// Combine a Fermi problem's factors, each guessed by the team, into one estimate
+    Fermi,
+    /// Add three to six one-decimal numbers, as when summing table logs
+    DecimalSum,
+    /// Divide a one-decimal sum by 3 to 6, as when averaging table logs
+    SmallDivision,
+}
+
+impl DrillKind {
+    pub const ALL: [DrillKind; 10] = [
+        DrillKind::ArithmeticMean,
+        DrillKind::PercentError,
+        DrillKind::FactorError,
+        DrillKind::Log10,
+        DrillKind::SquareRoot,
+        DrillKind::CubeRoot,
+        DrillKind::Power,
+        DrillKind::Fermi,
+        DrillKind::DecimalSum,
+        DrillKind::SmallDivision,
+    ];
+
+    /// The quick arithmetic the methods need, served alternately as a warm-up before practice
+    pub const WARM_UPS: [DrillKind; 2] = [DrillKind::DecimalSum, DrillKind::SmallDivision];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            DrillKind::ArithmeticMean => "arithmetic-mean",
+            DrillKind::PercentError => "percent-error",
+            DrillKind::FactorError => "factor-error",
+            DrillKind::Log10 => "log10",
+            DrillKind::SquareRoot => "square-root",
+            DrillKind::CubeRoot => "cube-root",
+            DrillKind::Power => "power",
+            DrillKind::Fermi => "fermi",
+            DrillKind::DecimalSum => "decimal-sum",
+            DrillKind::SmallDivision => "small-division",
+        }
+    }
+
+    /// Whether questions of this kind can be made from an answer and the exact value, such as a past practice round
+    pub fn uses_past_answers(&self) -> bool {
+        matches!(self, DrillKind::PercentError | DrillKind::FactorError)
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.name() == name)
+    }
+
+    /// Generate a random question of this kind
+    pub fn generate<R: Rng>(&self, rng: &mut R) -> DrillQuestion {
+        match self {
+            DrillKind::ArithmeticMean => arithmetic_mean_question(rng),
+            DrillKind::PercentError => {
+                let (answer, exact) = random_miss(rng, 0.3);
+                percent_error_question(answer, exact)
+            }
+            DrillKind::FactorError => {
+                let (answer, exact) = random_miss(rng, 1.0);
+                factor_error_question(answer, exact)
+            }
+            DrillKind::Log10 => log10_question(round_to_two_figures(10_f64.powf(rng.gen_range(0.0..9.0)))),
+            DrillKind::SquareRoot => root_question(round_to_two_figures(10_f64.powf(rng.gen_range(2.0..12.0))), 2),
+            DrillKind::CubeRoot => root_question(round_to_two_figures(10_f64.powf(rng.gen_range(3.0..15.0))), 3),
+            DrillKind::Power => {
+                power_question(round_to_two_figures(10_f64.powf(rng.gen_range(1.0..5.0))), rng.gen_range(2..=3))
+            }
+            DrillKind::Fermi => loop {
+                // Rarely, the team's guesses push a quotient below 1, which the table can't represent
+                if let Some(question) = fermi_question(&FermiProblem::generate(rng, FERMI_TEAM_SIZE, FERMI_LOG_STD_DEV)) {
+                    break question;
+                }
+            },
+            DrillKind::DecimalSum => {
+                let count = rng.gen_range(WARM_UP_COUNTS);
+                decimal_sum_question(&random_logs(rng, count as usize))
+            }
+            DrillKind::SmallDivision => {
+                let divisor = rng.gen_range(WARM_UP_COUNTS);
+                small_division_question(random_logs(rng, divisor as usize).iter().sum(), divisor)
+            }
+        }
+    }
+
+    /// A question about a specific answer and exact value, for the kinds that [use past answers](Self::uses_past_answers)
+    pub fn question_for_answer(&self, answer: f64, exact: f64) -> Option<DrillQuestion> {
+        match self {
+            DrillKind::PercentError => Some(percent_error_question(answer, exact)),
+            DrillKind::FactorError => Some(factor_error_question(answer, exact)),
+            _ => None,
+        }
+    }
+}
+
+/// Relative tolerance for averages: close enough to be useful, without demanding long division
+const ARITHMETIC_MEAN_TOLERANCE: f64 = 0.01;
+
+fn arithmetic_mean_question<R: Rng>(rng: &mut R) -> DrillQuestion {
+    let count = rng.gen_range(3..=5);
+    let zeros = rng.gen_range(3..=9);
+    let scale = 10_u64.pow(zeros - 1);
+
+    // Two significant figures, as numbers are said aloud at the table: 3,500,000 rather than 3,517,204
+    let values: Vec<u64> = (0..count).map(|_| rng.gen_range(10..=99) * scale).collect();
+    let sum: u64 = values.iter().sum();
+    let exact_answer = sum as f64 / count as f64;
+
+    let listed: Vec<String> = values.iter().map(|&v| format!("  {}\n", format_number(v as f64))).collect();
+    let terms: Vec<String> = values.iter().map(|&v| format_number(v as f64)).collect();
+
+    DrillQuestion {
+        prompt: format!("Average these numbers:\n{}", listed.concat()),
+        exact_answer,
+        tolerance: Tolerance::Relative(ARITHMETIC_MEAN_TOLERANCE),
+        working: format!(
+            "Sum: {} = {}\nAverage: {} ÷ {} = {}\n",
+            terms.join(" + "),
+            format_number(sum as f64),
+            format_number(sum as f64),
+            count,
+            format_number(exact_answer)
+        ),
+    }
+}
+
+/// How many numbers a warm-up adds, and what it divides by: the team sizes the methods are usually worked for
+const WARM_UP_COUNTS: core::ops::RangeInclusive<i32> = 3..=6;
+
+/// Warm-ups are answered to the nearest tenth, as the methods work in tenths
+const WARM_UP_TOLERANCE: Tolerance = Tolerance::Absolute(0.05);
+
+/// Random one-decimal numbers, in tenths, from 0.0 to 9.9 like the table logs of trivia answers
+fn random_logs<R: Rng>(rng: &mut R, count: usize) -> Vec<i32> {
+    (0..count).map(|_| rng.gen_range(0..100)).collect()
+}
+
+/// Ask for the sum of one-decimal numbers, given in tenths, with the whole parts and tenths added separately as the
+/// working
+pub fn decimal_sum_question(tenths: &[i32]) -> DrillQuestion {
+    let terms: Vec<String> = tenths.iter().map(|&t| format_scaled_log(t)).collect();
+    let whole: i32 = tenths.iter().map(|t| t / 10).sum();
+    let fractions: Vec<String> = tenths.iter().map(|t| (t % 10).to_string()).collect();
+    let fraction_sum: i32 = tenths.iter().map(|t| t % 10).sum();
+    let sum: i32 = tenths.iter().sum();
+
+    DrillQuestion {
+        prompt: format!("Add: {}\n", terms.join(" + ")),
+        exact_answer: sum as f64 / 10.0,
+        tolerance: WARM_UP_TOLERANCE,
+        working: format!(
+            "Whole parts: {}\nTenths: {} = {} tenths = {}\nSum: {} + {} = {}\n",
+            whole,
+            fractions.join(" + "),
+            fraction_sum,
+            format_scaled_log(fraction_sum),
+            whole,
+            format_scaled_log(fraction_sum),
+            format_scaled_log(sum)
+        ),
+    }
+}
+
+/// Ask for a one-decimal sum, given in tenths, divided by a small count, to the nearest tenth
+pub fn small_division_question(sum_tenths: i32, divisor: i32) -> DrillQuestion {
+    let exact_answer = sum_tenths as f64 / 10.0 / divisor as f64;
+    let rounded_up = (sum_tenths + divisor - 1) / divisor;
+    let rounding = if sum_tenths % divisor == 0 {
+        String::new()
+    } else {
+        format!(" (the table method rounds this up to {})", format_scaled_log(rounded_up))
+    };
+
+    DrillQuestion {
+        prompt: format!("Divide to the nearest tenth: {} ÷ {}\n", format_scaled_log(sum_tenths), divisor),
+        exact_answer,
+        // Wide enough for the table method's rounded-up tenth as well as the nearest one
+        tolerance: Tolerance::Absolute((rounded_up as f64 / 10.0 - exact_answer).max(0.05)),
+        working: format!(
+            "{} ÷ {} = {}{}\n",
+            format_scaled_log(sum_tenths),
+            divisor,
+            format_number(exact_answer),
+            rounding
+        ),
+    }
+}
+
+/// A warm-up of `count` questions, alternating between the [warm-up kinds](DrillKind::WARM_UPS)
+pub fn warm_up<R: Rng>(rng: &mut R, count: usize) -> Vec<DrillQuestion> {
+    DrillKind::WARM_UPS.iter().cycle().take(count).map(|kind| kind.generate(rng)).collect()
+}
+
+/// Round to two significant figures, as a number would be said aloud
+fn round_to_two_figures(value: f64) -> f64 {
+    let scale = 10_f64.powi(value.abs().log10().floor() as i32 - 1);
+    (value / scale).round() * scale
+}
+
+/// A made-up answer and exact value: the exact value between 10 and 100,000, missed by up to `10^max_log_error` either way
+fn random_miss<R: Rng>(rng: &mut R, max_log_error: f64) -> (f64, f64) {
+    let exact = round_to_two_figures(10_f64.powf(rng.gen_range(1.0..5.0)));
+    let answer = round_to_two_figures(exact * 10_f64.powf(rng.gen_range(-max_log_error..max_log_error)));
+    (answer, exact)
+}
+
+/// Percent error within 5 points, or a tenth of itself once it passes 50%, counts as Correct
+const PERCENT_ERROR_TOLERANCE: Tolerance = Tolerance::AbsoluteOrRelative(5.0, 0.1);
+
+/// Factor error within 5% counts as Correct
+const FACTOR_ERROR_TOLERANCE: Tolerance = Tolerance::Relative(0.05);
+
+/// Ask by what percent `answer` missed `exact`, positive when too high
+pub fn percent_error_question(answer: f64, exact: f64) -> DrillQuestion {
+    let difference = answer - exact;
+    let percent = difference / exact * 100.0;
+
+    DrillQuestion {
+        prompt: format!(
+            "Your answer: {}\nExact value: {}\nBy what percent was your answer off? (negative if too low)\n",
+            format_number(answer),
+            format_number(exact)
+        ),
+        exact_answer: percent,
+        tolerance: PERCENT_ERROR_TOLERANCE,
+        working: format!(
+            "({} - {}) ÷ {} = {} ÷ {} = {:+.1}%\n",
+            format_number(answer),
+            format_number(exact),
+            format_number(exact),
+            format_number(difference),
+            format_number(exact),
+            percent
+        ),
+    }
+}
+
+/// Ask how many times too high or too low `answer` was compared to `exact`, always at least 1
+pub fn factor_error_question(answer: f64, exact: f64) -> DrillQuestion {
+    let (larger, smaller, direction) =
+        if answer >= exact { (answer, exact, "too high") } else { (exact, answer, "too low") };
+    let factor = larger / smaller;
+
+    DrillQuestion {
+        prompt: format!(
+            "Your answer: {}\nExact value: {}\nHow many times too high or too low was your answer? (e.g. x1.5)\n",
+            format_number(answer),
+            format_number(exact)
+        ),
+        exact_answer: factor,
+        tolerance: FACTOR_ERROR_TOLERANCE,
+        working: format!("{} ÷ {} = ×{} {}\n", format_number(larger), format_number(smaller), format_number(factor), direction),
+    }
+}
+
+/// Within a tenth of the true logarithm counts as Correct, so both the table's entry and the rounded value pass
+const LOG10_TOLERANCE: Tolerance = Tolerance::Absolute(0.1);
+
+/// Ask for log10 of `value` (at least 1) to one decimal place, with the table lookup as the working
+pub fn log10_question(value: f64) -> DrillQuestion {
+    let scaled_log = number_to_log_representation(value);
+    let zeros = scaled_log / 10;
+    let tenths = scaled_log % 10;
+    let leading_digits = value / 10_f64.powi(zeros);
+    let exact_answer = value.log10();
+
+    DrillQuestion {
+        prompt: format!("Estimate log10 of {} to one decimal place.\n", format_number(value)),
+        exact_answer,
+        tolerance: LOG10_TOLERANCE,
+        working: format!(
+            "Zeros: {} = {} × 10^{} → {}\nTable: {} is at least {} → .{}\nlog10 ≈ {}.{} (exactly {:.3})\n",
+            format_number(value),
+            format_number(leading_digits),
+            zeros,
+            zeros,
+            format_number(leading_digits),
+            MULTIPLIERS[tenths as usize],
+            tenths,
+            zeros,
+            tenths,
+            exact_answer
+        ),
+    }
+}
+
+/// Format a scaled log, in tenths, as a decimal, e.g. 35 as 3.5
+fn format_scaled_log(scaled_log: i32) -> String {
+    format!("{}.{}", scaled_log / 10, scaled_log % 10)
+}
+
+/// The table method's error on a root or power, floored so that the exact answer rounded to the nearest table entry
+/// (a factor of at most 1.13 either way) always passes
+fn table_tolerance(estimate: f64, exact: f64) -> Tolerance {
+    Tolerance::Factor((estimate / exact).max(exact / estimate).max(1.15))
+}
+
+fn root_name(degree: i32) -> &'static str {
+    match degree {
+        2 => "square root",
+        3 => "cube root",
+        _ => "root",
+    }
+}
+
+/// Ask for the square (`degree` 2) or cube (`degree` 3) root of `value` (at least 1), graded as generously as the
+/// table method's own answer: look up the scaled log, divide it, round to the nearest tenth, and look it back up
+pub fn root_question(value: f64, degree: i32) -> DrillQuestion {
+    let scaled_log = number_to_log_representation(value);
+    let divided = scaled_log as f64 / degree as f64;
+    let rounded = divided.round() as i32;
+    let estimate = log_representation_to_number(rounded);
+    let exact_answer = value.powf(1.0 / degree as f64);
+
+    DrillQuestion {
+        prompt: format!("Estimate the {} of {}.\n", root_name(degree), format_number(value)),
+        exact_answer,
+        tolerance: table_tolerance(estimate, exact_answer),
+        working: format!(
+            "Table: {} → {}\nDivide by {}: {} ÷ {} = {} → {}\nTable: {} → {}\nExact: {}\n",
+            format_number(value),
+            format_scaled_log(scaled_log),
+            degree,
+            format_scaled_log(scaled_log),
+            degree,
+            format_number(divided / 10.0),
+            format_scaled_log(rounded),
+            format_scaled_log(rounded),
+            format_number(estimate),
+            format_number(exact_answer)
+        ),
+    }
+}
+
+/// Ask for `base` (at least 1) raised to `exponent`, graded as generously as the table method's own answer: look up
+/// the scaled log, multiply it, and look it back up
+pub fn power_question(base: f64, exponent: i32) -> DrillQuestion {
+    let scaled_log = number_to_log_representation(base);
+    let multiplied = scaled_log * exponent;
+    let estimate = log_representation_to_number(multiplied);
+    let exact_answer = base.powi(exponent);
+
+    DrillQuestion {
+        prompt: format!("Estimate {} to the power of {}.\n", format_number(base), exponent),
+        exact_answer,
+        tolerance: table_tolerance(estimate, exact_answer),
+        working: format!(
+            "Table: {} → {}\nMultiply by {}: {} × {} = {}\nTable: {} → {}\nExact: {}\n",
+            format_number(base),
+            format_scaled_log(scaled_log),
+            exponent,
+            format_scaled_log(scaled_log),
+            exponent,
+            format_scaled_log(multiplied),
+            format_scaled_log(multiplied),
+            format_number(estimate),
+            format_number(exact_answer)
+        ),
+    }
+}
+
+/// Guesses per factor in generated Fermi problems
+const FERMI_TEAM_SIZE: usize = 3;
+
+/// Spread of the team's guesses for each factor, tighter than practice mode since each factor is a simpler question
+const FERMI_LOG_STD_DEV: f64 = 0.3;
+
+/// Ask for the estimate of a Fermi problem, graded as generously as the table method's own answer, or `None` when the
+/// table can't work it
+pub fn fermi_question(problem: &FermiProblem) -> Option<DrillQuestion> {
+    let steps = problem.steps().ok()?;
+    let exact_answer = problem.exact().ok()?;
+
+    let factors: Vec<String> = problem
+        .factors
+        .iter()
+        .enumerate()
+        .map(|(i, factor)| {
+            let symbol = if i == 0 { " " } else { factor.operation.symbol() };
+            let guesses: Vec<String> = factor.guesses.iter().map(|&g| format_number(g as f64)).collect();
+            format!("  {} {}: {}\n", symbol, factor.label, guesses.join(", "))
+        })
+        .collect();
+
+    Some(DrillQuestion {
+        prompt: format!("{}\n{}Combine the team's guesses into one estimate.\n", problem.question, factors.concat()),
+        exact_answer,
+        tolerance: table_tolerance(steps.estimate(), exact_answer),
+        working: format!("{}\n\nExact: {}\n", steps, format_number(exact_answer)),
+    })
+}
+
+/// Format a number with thousands separators, keeping up to two decimal places when it isn't whole
+pub fn format_number(value: f64) -> String {
+    let rounded = (value * 100.0).round() / 100.0;
+    let grouped = crate::practice_mode::format_number(rounded.trunc().abs() as u64);
+
+    let fraction = format!("{:.2}", rounded.fract().abs());
+    let fraction = fraction.trim_start_matches('0').trim_end_matches('0').trim_end_matches('.');
+    let sign = if rounded < 0.0 { "-" } else { "" };
+    format!("{}{}{}", sign, grouped, fraction)
+}
+
+/// Parse a drill answer, which may be negative or have decimals
+///
+/// Thousands separators, a trailing `%`, and a leading `x` or `×` for factors are ignored.
+pub fn parse_drill_answer(input: &str) -> Result<f64, String> {
+    let cleaned = input.trim().replace(',', "");
+    let cleaned = cleaned.strip_suffix('%').unwrap_or(&cleaned);
+    let cleaned = cleaned.strip_prefix(['x', 'X', '×']).unwrap_or(cleaned).trim();
+
+    if cleaned.is_empty() {
+        return Err("Please enter a number".to_string());
+    }
+    match cleaned.parse::<f64>() {
+        Ok(value) if value.is_finite() => Ok(value),
+        _ => Err("Please enter a valid number".to_string()),
+    }
+}
+
+/// A drill question being answered, timed from when it was shown
+pub struct ActiveDrill<T: Timer> {
+    question: DrillQuestion,
+    start_instant: T::Instant,
+    timer: T,
+}
+
+/// The outcome of a drill question
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DrillResult {
+    pub question: DrillQuestion,
+    /// `None` when the user gave up
+    pub user_answer: Option<f64>,
+    pub evaluation: AnswerEvaluation,
+    pub duration: Duration,
+}
+
+impl<T: Timer> ActiveDrill<T> {
+    /// Start timing a question
+    pub fn start(question: DrillQuestion, timer: T) -> Self {
+        let start_instant = timer.now();
+        ActiveDrill { question, start_instant, timer }
+    }
+
+    pub fn question(&self) -> &DrillQuestion {
+        &self.question
+    }
+
+    pub fn submit_answer(self, user_answer: f64) -> DrillResult {
+        let evaluation = self.question.evaluate(user_answer);
+        self.into_result(Some(user_answer), evaluation)
+    }
+
+    pub fn give_up(self) -> DrillResult {
+        self.into_result(None, AnswerEvaluation::GaveUp)
+    }
+
+    fn into_result(self, user_answer: Option<f64>, evaluation: AnswerEvaluation) -> DrillResult {
+        DrillResult {
+            duration: self.timer.elapsed(self.start_instant),
+            question: self.question,
+            user_answer,
+            evaluation,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[derive(Clone)]
+    struct FixedTimer;
+
+    impl Timer for FixedTimer {
+        type Instant = ();
+
+        fn now(&self) -> Self::Instant {}
+
+        fn elapsed(&self, _start: Self::Instant) -> Duration {
+            Duration::from_secs(3)
+        }
+    }
+
+    #[test]
+    fn test_tolerance() {
+        assert!(Tolerance::Relative(0.01).accepts(1_010_000.0, 1_000_000.0));
+        assert!(!Tolerance::Relative(0.01).accepts(1_020_000.0, 1_000_000.0));
+        assert!(Tolerance::Absolute(0.1).accepts(3.3, 3.4));
+        assert!(!Tolerance::Absolute(0.1).accepts(3.2, 3.4));
+        assert!(Tolerance::AbsoluteOrRelative(5.0, 0.1).accepts(-21.0, -26.0));
+        assert!(Tolerance::AbsoluteOrRelative(5.0, 0.1).accepts(185.0, 200.0));
+        assert!(!Tolerance::AbsoluteOrRelative(5.0, 0.1).accepts(175.0, 200.0));
+    }
+
+    #[test]
+    fn test_percent_error_question() {
+        let question = percent_error_question(1200.0, 1000.0);
+        assert_eq!(question.exact_answer, 20.0);
+        assert_eq!(question.prompt, "Your answer: 1,200\nExact value: 1,000\nBy what percent was your answer off? (negative if too low)\n");
+        assert_eq!(question.working, "(1,200 - 1,000) ÷ 1,000 = 200 ÷ 1,000 = +20.0%\n");
+        assert_eq!(question.evaluate(parse_drill_answer("24%").unwrap()), AnswerEvaluation::Correct);
+        assert_eq!(question.evaluate(-20.0), AnswerEvaluation::Incorrect);
+
+        assert_eq!(percent_error_question(750.0, 1000.0).exact_answer, -25.0);
+    }
+
+    #[test]
+    fn test_factor_error_question() {
+        let question = factor_error_question(300.0, 1200.0);
+        assert_eq!(question.exact_answer, 4.0);
+        assert_eq!(question.working, "1,200 ÷ 300 = ×4 too low\n");
+        assert_eq!(question.evaluate(parse_drill_answer("x4.1").unwrap()), AnswerEvaluation::Correct);
+        assert_eq!(question.evaluate(parse_drill_answer("×3.5").unwrap()), AnswerEvaluation::Incorrect);
+        assert_eq!(factor_error_question(1500.0, 1000.0).exact_answer, 1.5);
+    }
+
+    #[test]
+    fn test_log10_question() {
+        let question = log10_question(3700.0);
+        assert_eq!(question.prompt, "Estimate log10 of 3,700 to one decimal place.\n");
+        assert_eq!(question.working, "Zeros: 3,700 = 3.7 × 10^3 → 3\nTable: 3.7 is at least 3 → .5\nlog10 ≈ 3.5 (exactly 3.568)\n");
+        assert_eq!(question.evaluate(3.5), AnswerEvaluation::Correct);
+        assert_eq!(question.evaluate(3.6), AnswerEvaluation::Correct);
+        assert_eq!(question.evaluate(3.4), AnswerEvaluation::Incorrect);
+
+        assert!(log10_question(1.0).working.contains("log10 ≈ 0.0"));
+    }
+
+    #[test]
+    fn test_generated_log10_questions_accept_the_table() {
+        for digits in 10..100 {
+            for zeros in 0..9 {
+                let value = round_to_two_figures(digits as f64 * 10_f64.powi(zeros - 1));
+                let table_answer = number_to_log_representation(value) as f64 / 10.0;
+                assert_eq!(log10_question(value).evaluate(table_answer), AnswerEvaluation::Correct, "{}", value);
+            }
+        }
+    }
+
+    #[test]
+    fn test_factor_tolerance() {
+        assert!(Tolerance::Factor(1.5).accepts(150.0, 100.0));
+        assert!(Tolerance::Factor(1.5).accepts(100.0, 150.0));
+        assert!(!Tolerance::Factor(1.5).accepts(160.0, 100.0));
+        assert!(!Tolerance::Factor(1.5).accepts(-100.0, 100.0));
+    }
+
+    #[test]
+    fn test_root_question() {
+        let question = root_question(3700.0, 2);
+        assert_eq!(question.prompt, "Estimate the square root of 3,700.\n");
+        assert_eq!(question.working, "Table: 3,700 → 3.5\nDivide by 2: 3.5 ÷ 2 = 1.75 → 1.8\nTable: 1.8 → 60\nExact: 60.83\n");
+        assert_eq!(question.evaluate(60.0), AnswerEvaluation::Correct);
+        assert_eq!(question.evaluate(61.0), AnswerEvaluation::Correct);
+        assert_eq!(question.evaluate(40.0), AnswerEvaluation::Incorrect);
+
+        let question = root_question(8_000_000.0, 3);
+        assert_eq!(question.prompt, "Estimate the cube root of 8,000,000.\n");
+        assert_eq!(question.working, "Table: 8,000,000 → 6.9\nDivide by 3: 6.9 ÷ 3 = 2.3 → 2.3\nTable: 2.3 → 200\nExact: 200\n");
+    }
+
+    #[test]
+    fn test_power_question() {
+        let question = power_question(3700.0, 3);
+        assert_eq!(question.prompt, "Estimate 3,700 to the power of 3.\n");
+        assert_eq!(
+            question.working,
+            "Table: 3,700 → 3.5\nMultiply by 3: 3.5 × 3 = 10.5\nTable: 10.5 → 30,000,000,000\nExact: 50,653,000,000\n"
+        );
+        assert_eq!(question.evaluate(3e10), AnswerEvaluation::Correct);
+        assert_eq!(question.evaluate(5e10), AnswerEvaluation::Correct);
+        assert_eq!(question.evaluate(1e10), AnswerEvaluation::Incorrect);
+    }
+
+    #[test]
+    fn test_generated_roots_and_powers_accept_the_table() {
+        let mut rng = StdRng::seed_from_u64(17);
+        for kind in [DrillKind::SquareRoot, DrillKind::CubeRoot, DrillKind::Power] {
+            for _ in 0..200 {
+                let question = kind.generate(&mut rng);
+                let table_answer = question.working.lines().nth(2).unwrap().rsplit("→ ").next().unwrap();
+                let table_answer = parse_drill_answer(table_answer).unwrap();
+                assert_eq!(question.evaluate(table_answer), AnswerEvaluation::Correct, "{}", question.working);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fermi_question() {
+        use crate::fermi::{FermiFactor, Operation};
+
+        let problem = FermiProblem {
+            question: "How many hairs are on a human head?".to_string(),
+            factors: vec![
+                FermiFactor { label: "Scalp area".to_string(), operation: Operation::Multiply, guesses: vec![500, 800] },
+                FermiFactor { label: "Hairs per area".to_string(), operation: Operation::Multiply, guesses: vec![200] },
+            ],
+        };
+        let question = fermi_question(&problem).unwrap();
+        assert_eq!(
+            question.prompt,
+            "How many hairs are on a human head?\n    Scalp area: 500, 800\n  × Hairs per area: 200\nCombine the team's guesses into one estimate.\n"
+        );
+        assert!(question.working.ends_with("2.8 + 2.3 = 5.1\n\n3. Convert back:\n   5.1 → 125000\n\nExact: 126,491.11\n"));
+        assert_eq!(question.evaluate(125_000.0), AnswerEvaluation::Correct);
+        assert_eq!(question.evaluate(140_000.0), AnswerEvaluation::Correct);
+        assert_eq!(question.evaluate(100_000.0), AnswerEvaluation::Incorrect);
+    }
+
+    #[test]
+    fn test_generated_misses() {
+        let mut rng = StdRng::seed_from_u64(11);
+        for _ in 0..100 {
+            let percent = DrillKind::PercentError.generate(&mut rng);
+            assert!((-50.0..=100.0).contains(&percent.exact_answer), "{}", percent.exact_answer);
+            let factor = DrillKind::FactorError.generate(&mut rng);
+            assert!((1.0..=10.5).contains(&factor.exact_answer), "{}", factor.exact_answer);
+        }
+        assert_eq!(round_to_two_figures(34_567.0), 35_000.0);
+        assert_eq!(DrillKind::ArithmeticMean.question_for_answer(1.0, 2.0), None);
+    }
+
+    #[test]
+    fn test_drill_names_round_trip() {
+        for kind in DrillKind::ALL {
+            assert_eq!(DrillKind::from_name(kind.name()), Some(kind));
+        }
+        assert_eq!(DrillKind::from_name("long-division"), None);
+    }
+
+    #[test]
+    fn test_arithmetic_mean_question() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..100 {
+            let question = DrillKind::ArithmeticMean.generate(&mut rng);
+            let values: Vec<f64> = question
+                .prompt
+                .lines()
+                .skip(1)
+                .map(|line| parse_drill_answer(line).unwrap())
+                .collect();
+
+            assert!((3..=5).contains(&values.len()));
+            let digit_counts: Vec<usize> = values.iter().map(|v| (*v as u64).to_string().len()).collect();
+            assert!(digit_counts.iter().all(|&d| d == digit_counts[0] && d >= 4));
+            let mean = values.iter().sum::<f64>() / values.len() as f64;
+            assert_eq!(question.exact_answer, mean);
+            assert_eq!(question.evaluate(mean * 1.005), AnswerEvaluation::Correct);
+            assert_eq!(question.evaluate(mean * 1.02), AnswerEvaluation::Incorrect);
+        }
+    }
+
+    #[test]
+    fn test_decimal_sum_question() {
+        let question = decimal_sum_question(&[33, 17, 25]);
+        assert_eq!(question.prompt, "Add: 3.3 + 1.7 + 2.5\n");
+        assert_eq!(question.exact_answer, 7.5);
+        assert_eq!(question.working, "Whole parts: 6\nTenths: 3 + 7 + 5 = 15 tenths = 1.5\nSum: 6 + 1.5 = 7.5\n");
+        assert_eq!(question.evaluate(7.5), AnswerEvaluation::Correct);
+        assert_eq!(question.evaluate(7.4), AnswerEvaluation::Incorrect);
+    }
+
+    #[test]
+    fn test_small_division_question() {
+        let question = small_division_question(102, 4);
+        assert_eq!(question.prompt, "Divide to the nearest tenth: 10.2 ÷ 4\n");
+        assert_eq!(question.working, "10.2 ÷ 4 = 2.55 (the table method rounds this up to 2.6)\n");
+        assert_eq!(question.evaluate(2.6), AnswerEvaluation::Correct);
+        assert_eq!(question.evaluate(2.5), AnswerEvaluation::Correct);
+        assert_eq!(question.evaluate(2.7), AnswerEvaluation::Incorrect);
+        assert_eq!(small_division_question(75, 3).working, "7.5 ÷ 3 = 2.5\n");
+
+        let rounds_up_past_nearest = small_division_question(73, 3);
+        assert_eq!(rounds_up_past_nearest.evaluate(2.4), AnswerEvaluation::Correct);
+        assert_eq!(rounds_up_past_nearest.evaluate(2.5), AnswerEvaluation::Correct);
+        assert_eq!(rounds_up_past_nearest.evaluate(2.3), AnswerEvaluation::Incorrect);
+        assert_eq!(rounds_up_past_nearest.evaluate(2.6), AnswerEvaluation::Incorrect);
+    }
+
+    #[test]
+    fn test_warm_up() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let questions = warm_up(&mut rng, 5);
+        assert_eq!(questions.len(), 5);
+        for (i, question) in questions.iter().enumerate() {
+            let expected = if i % 2 == 0 { "Add: " } else { "Divide to the nearest tenth: " };
+            assert!(question.prompt.starts_with(expected), "{}", question.prompt);
+            // Answers are at most 6 × 9.9, to the tenth for sums
+            assert!((0.0..=59.4).contains(&question.exact_answer));
+            assert_eq!(question.evaluate(question.exact_answer), AnswerEvaluation::Correct);
+        }
+    }
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number(3_500_000.0), "3,500,000");
+        assert_eq!(format_number(1_233_333.333), "1,233,333.33");
+        assert_eq!(format_number(12.5), "12.5");
+        assert_eq!(format_number(-0.25), "-0.25");
+        assert_eq!(format_number(999.0), "999");
+    }
+
+    #[test]
+    fn test_parse_drill_answer() {
+        assert_eq!(parse_drill_answer(" 3,400,000 "), Ok(3_400_000.0));
+        assert_eq!(parse_drill_answer("3.4"), Ok(3.4));
+        assert_eq!(parse_drill_answer("-20%"), Ok(-20.0));
+        assert!(parse_drill_answer("").is_err());
+        assert!(parse_drill_answer("lots").is_err());
+        assert!(parse_drill_answer("inf").is_err());
+        assert_eq!(parse_drill_answer("x1.5"), Ok(1.5));
+        assert_eq!(parse_drill_answer("×2"), Ok(2.0));
+    }
+
+    #[test]
+    fn test_active_drill() {
+        let question = DrillQuestion {
+            prompt: "Average these numbers:\n  10\n  20\n".to_string(),
+            exact_answer: 15.0,
+            tolerance: Tolerance::Absolute(0.0),
+            working: String::new(),
+        };
+
+        let result = ActiveDrill::start(question.clone(), FixedTimer).submit_answer(15.0);
+        assert_eq!(result.evaluation, AnswerEvaluation::Correct);
+        assert_eq!(result.duration, Duration::from_secs(3));
+
+        let result = ActiveDrill::start(question, FixedTimer).give_up();
+        assert_eq!(result.evaluation, AnswerEvaluation::GaveUp);
+        assert_eq!(result.user_answer, None);
+    }
+}
@@ -0,0 +1,253 @@
+//! Which procedure meets a target error against the exact geometric mean, from analytical bounds and empirical data.
+//!
+//! Tables of logs trade memorization for accuracy: the coarser the table, the fewer entries to learn and the larger
+//! the rounding.
+//! Besides the repository's ten-entry table, the budget considers a five-entry table (every other entry) and a
+//! twenty-entry one (with round midpoints between the entries), worked the same way as the table-based method.
+//!
+//! Each procedure gets a worst-case bound, derived from how far its conversions can stray from true logs, and an
+//! empirical mean and 95th percentile over practice-style guesses.
+//! A procedure is guaranteed to meet a target under its bound, and typically meets it when 95% of answers do.
+
+use rand::Rng;
+
+use crate::exact::geometric_mean;
+use crate::log_linear::LogLinearApproximation;
+use crate::practice_mode::{ConfigurationError, PracticeModeConfig, Problem};
+use crate::rng;
+use crate::table_based::MULTIPLIERS;
+use crate::traits::EstimateGeometricMean;
+
+/// A five-entry table, every other entry of the ten-entry one, in fifths of a log
+pub const FIVE_ENTRY_TABLE: [f64; 5] = [1.0, 1.6, 2.5, 4.0, 6.0];
+
+/// A twenty-entry table, the ten-entry one with round numbers near each geometric midpoint, in twentieths of a log
+pub const TWENTY_ENTRY_TABLE: [f64; 20] =
+    [1.0, 1.1, 1.25, 1.4, 1.6, 1.8, 2.0, 2.2, 2.5, 2.8, 3.0, 3.5, 4.0, 4.5, 5.0, 5.6, 6.0, 7.0, 8.0, 9.0];
+
+/// A way of estimating the geometric mean by hand, in order of cost
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Procedure {
+    LogLinear,
+    /// The table-based method with a table of what numbers start with at each step of a log
+    Table(&'static [f64]),
+    Exact,
+}
+
+/// Every procedure the budget considers, cheapest first
+pub const PROCEDURES: [Procedure; 5] = [
+    Procedure::LogLinear,
+    Procedure::Table(&FIVE_ENTRY_TABLE),
+    Procedure::Table(&MULTIPLIERS),
+    Procedure::Table(&TWENTY_ENTRY_TABLE),
+    Procedure::Exact,
+];
+
+impl Procedure {
+    pub fn name(&self) -> String {
+        match self {
+            Procedure::LogLinear => "log-linear".to_string(),
+            Procedure::Table(table) if table.len() == MULTIPLIERS.len() => format!("{}-entry table (table-based)", table.len()),
+            Procedure::Table(table) => format!("{}-entry table", table.len()),
+            Procedure::Exact => "exact".to_string(),
+        }
+    }
+
+    /// Estimate the geometric mean of values that are all at least 1
+    pub fn estimate(&self, values: &[f64]) -> Option<f64> {
+        match self {
+            Procedure::LogLinear => LogLinearApproximation::estimate_geometric_mean(values).ok(),
+            Procedure::Table(table) => table_estimate(table, values),
+            Procedure::Exact => geometric_mean(values).ok(),
+        }
+    }
+
+    /// The largest relative error against the exact geometric mean for a team of `team_size`
+    ///
+    /// Table errors come from how far each entry strays from the true log at its step.
+    /// Converting a guess lands on the entry at or below it, so its log is read low by anything up to the gap to the
+    /// next entry; averaging rounds up by less than `(team_size - 1) / team_size` of a step; and converting back gives
+    /// the entry's value rather than the true power of ten.
+    /// Log-linear reads `m × 10^d` as `d + 1 + m / 10`, which strays from `d + 1 + log10(m)` by between
+    /// `1/ln 10 + log10(ln 10) - 1` (about -0.20) and 0.1, and converting back strays by between -0.1 and about 0.20.
+    pub fn error_bound(&self, team_size: usize) -> f64 {
+        let (low, high) = match self {
+            Procedure::LogLinear => {
+                let swing = 1.0 - std::f64::consts::LN_10.recip() - std::f64::consts::LN_10.log10();
+                (-0.1 - 0.1, swing + swing)
+            }
+            Procedure::Table(table) => table_log_error_range(table, team_size),
+            Procedure::Exact => (0.0, 0.0),
+        };
+        (10_f64.powf(high) - 1.0).max(1.0 - 10_f64.powf(low))
+    }
+}
+
+/// The table-based method with any table: average each guess's entry, rounding up, and convert back
+fn table_estimate(table: &[f64], values: &[f64]) -> Option<f64> {
+    if values.is_empty() || values.iter().any(|&v| v < 1.0) {
+        return None;
+    }
+    let steps = table.len() as i64;
+
+    let to_steps = |value: f64| {
+        let zeros = value.log10().floor();
+        let leading_digits = value / 10_f64.powf(zeros);
+        let entry = table.iter().rposition(|&m| leading_digits >= m).unwrap_or(0);
+        zeros as i64 * steps + entry as i64
+    };
+    let sum: i64 = values.iter().map(|&v| to_steps(v)).sum();
+    let count = values.len() as i64;
+    let average = (sum + count - 1) / count;
+
+    Some(table[(average % steps) as usize] * 10_f64.powi((average / steps) as i32))
+}
+
+/// How far, in decades, the table method's log can stray below and above the exact one
+fn table_log_error_range(table: &[f64], team_size: usize) -> (f64, f64) {
+    let step = 1.0 / table.len() as f64;
+    // How far each entry's value is from the true power of ten at its step
+    let entry_error = |i: usize| table[i].log10() - i as f64 * step;
+    // How far a guess's true log can be above its entry's step: anything up to the next entry
+    let read_low = |i: usize| table.get(i + 1).copied().unwrap_or(10.0).log10() - i as f64 * step;
+
+    let entry_min = (0..table.len()).map(entry_error).fold(f64::INFINITY, f64::min);
+    let entry_max = (0..table.len()).map(entry_error).fold(f64::NEG_INFINITY, f64::max);
+    let read_low_max = (0..table.len()).map(read_low).fold(f64::NEG_INFINITY, f64::max);
+
+    let rounding = step * (team_size.max(1) - 1) as f64 / team_size.max(1) as f64;
+    // The estimate strays by the rounding and its entry's error, less the average amount each guess was read low,
+    // which is at least the smallest entry error
+    (entry_min - read_low_max, rounding + entry_max - entry_min)
+}
+
+/// How one procedure measures up to the target
+#[derive(Debug, Clone, PartialEq)]
+pub struct BudgetLine {
+    pub procedure: Procedure,
+    /// Worst-case relative error against the exact geometric mean
+    pub bound: f64,
+    pub mean_error: f64,
+    pub percentile_95: f64,
+}
+
+/// How surely a procedure meets the target
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    /// The worst-case bound is within the target
+    Always,
+    /// At least 95% of empirical answers are within the target
+    Typically,
+    No,
+}
+
+impl BudgetLine {
+    pub fn verdict(&self, target: f64) -> Verdict {
+        if self.bound <= target {
+            Verdict::Always
+        } else if self.percentile_95 <= target {
+            Verdict::Typically
+        } else {
+            Verdict::No
+        }
+    }
+}
+
+/// Problems generated for the empirical data
+pub const EMPIRICAL_TESTS: usize = 2_000;
+const EMPIRICAL_SEED: u64 = 42;
+
+/// Measure every procedure for a team of `config.team_size` guessing with `config.log_std_dev`
+pub fn error_budget(config: &PracticeModeConfig) -> Result<Vec<BudgetLine>, ConfigurationError> {
+    measure(&mut rng::seeded(EMPIRICAL_SEED), config, EMPIRICAL_TESTS)
+}
+
+fn measure<R: Rng>(rng: &mut R, config: &PracticeModeConfig, tests: usize) -> Result<Vec<BudgetLine>, ConfigurationError> {
+    let mut errors = vec![Vec::with_capacity(tests); PROCEDURES.len()];
+    for _ in 0..tests {
+        let problem = Problem::generate(rng, config)?;
+        let guesses: Vec<f64> = problem.guesses.iter().map(|&g| g as f64).collect();
+        let Ok(exact) = geometric_mean(&guesses) else { continue };
+        for (procedure, errors) in PROCEDURES.iter().zip(errors.iter_mut()) {
+            if let Some(estimate) = procedure.estimate(&guesses) {
+                errors.push((estimate - exact).abs() / exact);
+            }
+        }
+    }
+
+    Ok(PROCEDURES
+        .iter()
+        .zip(errors)
+        .map(|(&procedure, mut errors)| {
+            errors.sort_by(f64::total_cmp);
+            let mean_error = errors.iter().sum::<f64>() / errors.len() as f64;
+            let percentile_95 = errors.get((errors.len() * 95).div_ceil(100).saturating_sub(1)).copied().unwrap_or(f64::NAN);
+            BudgetLine { procedure, bound: procedure.error_bound(config.team_size), mean_error, percentile_95 }
+        })
+        .collect())
+}
+
+/// The cheapest procedure that meets the target, and how surely
+pub fn cheapest_meeting(lines: &[BudgetLine], target: f64) -> Option<(&BudgetLine, Verdict)> {
+    lines.iter().map(|line| (line, line.verdict(target))).find(|(_, verdict)| *verdict != Verdict::No)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table_based::TableBasedApproximation;
+
+    #[test]
+    fn test_ten_entry_table_is_table_based() {
+        let mut rng = rng::seeded(3);
+        for _ in 0..1_000 {
+            let values: Vec<f64> = (0..rng.gen_range(1..=8)).map(|_| rng.gen_range(1.0..1e6_f64).round()).collect();
+            assert_eq!(
+                Procedure::Table(&MULTIPLIERS).estimate(&values),
+                TableBasedApproximation::estimate_geometric_mean(&values).ok(),
+                "{:?}",
+                values
+            );
+        }
+        assert_eq!(Procedure::Table(&FIVE_ENTRY_TABLE).estimate(&[25.0, 400.0]), Some(100.0));
+        assert_eq!(Procedure::Table(&TWENTY_ENTRY_TABLE).estimate(&[]), None);
+    }
+
+    #[test]
+    fn test_bounds_hold_empirically() {
+        let config = PracticeModeConfig::new(4, 1.5, 10, 1_000_000).unwrap();
+        let mut rng = rng::seeded(9);
+        for _ in 0..2_000 {
+            let problem = Problem::generate(&mut rng, &config).unwrap();
+            let guesses: Vec<f64> = problem.guesses.iter().map(|&g| g as f64).collect();
+            let exact = geometric_mean(&guesses).unwrap();
+            for procedure in PROCEDURES {
+                let error = (procedure.estimate(&guesses).unwrap() - exact).abs() / exact;
+                assert!(error <= procedure.error_bound(4) + 1e-9, "{} on {:?}", procedure.name(), guesses);
+            }
+        }
+    }
+
+    #[test]
+    fn test_finer_tables_have_tighter_bounds() {
+        let bounds: Vec<f64> = PROCEDURES[1..].iter().map(|p| p.error_bound(5)).collect();
+        assert!(bounds.windows(2).all(|pair| pair[0] > pair[1]), "{:?}", bounds);
+        assert_eq!(Procedure::Exact.error_bound(5), 0.0);
+        // A single guess is never rounded up
+        assert!(Procedure::Table(&MULTIPLIERS).error_bound(1) < Procedure::Table(&MULTIPLIERS).error_bound(5));
+    }
+
+    #[test]
+    fn test_error_budget() {
+        let config = PracticeModeConfig::new(5, 1.0, 10, 1_000_000).unwrap();
+        let lines = error_budget(&config).unwrap();
+        assert_eq!(lines.len(), PROCEDURES.len());
+        assert!(lines.iter().all(|line| line.mean_error <= line.percentile_95 && line.percentile_95 <= line.bound + 1e-9));
+
+        let (line, verdict) = cheapest_meeting(&lines, 0.25).unwrap();
+        assert_eq!(line.procedure, Procedure::Table(&MULTIPLIERS));
+        assert_eq!(verdict, Verdict::Typically);
+        assert_eq!(cheapest_meeting(&lines, 0.0).unwrap().0.procedure, Procedure::Exact);
+        assert_eq!(cheapest_meeting(&lines, 10.0).unwrap(), (&lines[0], Verdict::Always));
+    }
+}
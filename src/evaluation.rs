@@ -1,8 +1,12 @@
+use std::io::{self, BufRead};
+
 use rand::Rng;
 use crate::traits::EstimateGeometricMean;
 use crate::exact::geometric_mean;
+use crate::practice_mode::{PracticeModeConfig, Problem};
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Results {
     pub mean_absolute_relative_error: f64,
     pub worst_case_error: f64,
@@ -17,93 +21,251 @@ pub fn evaluate_estimate<R: Rng, T: EstimateGeometricMean>(
     max: f64,
     num_tests: usize
 ) -> Results {
-    let mut total_relative_error = 0.0;
-    let mut max_error = 0.0;
-    let mut max_overestimate = 0.0;
-    let mut total_signed_error = 0.0;
-    let mut valid_tests = 0;
-
-    for _ in 0..num_tests {
-        // Generate log-uniform distributed test case size
-        let test_size = rng.gen_range(1..=10);
-
-        // Generate log-uniform distributed values
-        let mut test_values = Vec::with_capacity(test_size);
-
-        for _ in 0..test_size {
-            let log_min = min.ln();
-            let log_max = max.ln();
-            let log_value = rng.gen_range(log_min..=log_max);
-            let value = log_value.exp();
-
-            test_values.push(value);
-        }
+    let mut test_cases = TestCases::new(rng, min, max, num_tests);
+    let mut totals = ErrorTotals::default();
+    while let Some(test_values) = test_cases.next_case() {
+        totals.add::<T>(test_values);
+    }
+    totals.results()
+}
+
+/// One test case's outcome, for charting how error varies rather than summarizing it
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sample {
+    /// Number of values in the test case
+    pub count: usize,
+    pub exact: f64,
+    /// Signed error relative to the exact geometric mean, positive for overestimates
+    pub relative_error: f64,
+}
+
+/// Evaluate an estimator on the same test cases as [`evaluate_estimate`], keeping every case's outcome
+pub fn sample_estimate<R: Rng, T: EstimateGeometricMean>(
+    rng: &mut R,
+    min: f64,
+    max: f64,
+    num_tests: usize,
+) -> Vec<Sample> {
+    let mut test_cases = TestCases::new(rng, min, max, num_tests);
+    let mut samples = Vec::with_capacity(num_tests);
+    while let Some(test_values) = test_cases.next_case() {
+        samples.extend(sample::<T>(test_values));
+    }
+    samples
+}
+
+/// The test cases [`evaluate_estimate`] draws, collected so several estimators can share them
+///
+/// Evaluating every estimator on one corpus guarantees identical inputs by construction, rather than by reseeding.
+pub fn random_corpus<R: Rng>(rng: &mut R, min: f64, max: f64, num_tests: usize) -> Vec<Vec<f64>> {
+    let mut test_cases = TestCases::new(rng, min, max, num_tests);
+    let mut corpus = Vec::with_capacity(num_tests);
+    while let Some(test_values) = test_cases.next_case() {
+        corpus.push(test_values.to_vec());
+    }
+    corpus
+}
+
+/// Evaluate an estimator on a fixed set of test cases, such as a [`random_corpus`]
+pub fn evaluate_corpus<T: EstimateGeometricMean>(corpus: &[Vec<f64>]) -> Results {
+    evaluate_test_cases::<T, _>(corpus.iter())
+}
+
+/// Evaluate an estimator on a fixed set of test cases, keeping every case's outcome
+pub fn sample_corpus<T: EstimateGeometricMean>(corpus: &[Vec<f64>]) -> Vec<Sample> {
+    corpus.iter().filter_map(|test_values| sample::<T>(test_values)).collect()
+}
+
+/// One test case's outcome, or `None` if either the exact method or the estimator can't handle it
+fn sample<T: EstimateGeometricMean>(test_values: &[f64]) -> Option<Sample> {
+    let exact = geometric_mean(test_values).ok()?;
+    let estimate = T::estimate_geometric_mean(test_values).ok()?;
+    Some(Sample { count: test_values.len(), exact, relative_error: (estimate - exact) / exact })
+}
+
+/// Largest number of values in a test case
+const MAX_TEST_SIZE: usize = 10;
+
+/// Test cases of 1 to 10 values, each drawn log-uniformly from `min` to `max`, into one reused buffer
+///
+/// Evaluation runs can draw millions of cases, so each is lent out in turn rather than allocated.
+struct TestCases<'a, R> {
+    rng: &'a mut R,
+    log_min: f64,
+    log_max: f64,
+    remaining: usize,
+    buffer: Vec<f64>,
+}
+
+impl<'a, R: Rng> TestCases<'a, R> {
+    fn new(rng: &'a mut R, min: f64, max: f64, num_tests: usize) -> Self {
+        TestCases { rng, log_min: min.ln(), log_max: max.ln(), remaining: num_tests, buffer: Vec::with_capacity(MAX_TEST_SIZE) }
+    }
+
+    /// The next test case, or `None` once all have been drawn
+    fn next_case(&mut self) -> Option<&[f64]> {
+        self.remaining = self.remaining.checked_sub(1)?;
+
+        // Generate log-uniform distributed test case size, then values
+        let test_size = self.rng.gen_range(1..=MAX_TEST_SIZE);
+        let (rng, log_min, log_max) = (&mut *self.rng, self.log_min, self.log_max);
+        self.buffer.clear();
+        self.buffer.extend((0..test_size).map(|_| rng.gen_range(log_min..=log_max).exp()));
+        Some(&self.buffer)
+    }
+}
+
+/// Evaluate an estimator on team guesses generated like practice mode's, rather than spread evenly over a range
+pub fn evaluate_on_problems<R: Rng, T: EstimateGeometricMean>(
+    rng: &mut R,
+    config: &PracticeModeConfig,
+    num_tests: usize,
+) -> Results {
+    let test_cases = (0..num_tests).filter_map(|_| {
+        let problem = Problem::generate(rng, config).ok()?;
+        Some(problem.guesses.iter().map(|&g| g as f64).collect())
+    });
 
+    evaluate_test_cases::<T, Vec<f64>>(test_cases)
+}
+
+fn evaluate_test_cases<T: EstimateGeometricMean, V: AsRef<[f64]>>(test_cases: impl Iterator<Item = V>) -> Results {
+    let mut totals = ErrorTotals::default();
+    for test_values in test_cases {
+        totals.add::<T>(test_values.as_ref());
+    }
+    totals.results()
+}
+
+/// Running totals of an estimator's errors, summarized by [`ErrorTotals::results`]
+///
+/// Test cases are added one at a time, so results can be aggregated over inputs that are never held in memory at once.
+#[derive(Debug, Default)]
+pub struct ErrorTotals {
+    total_relative_error: f64,
+    max_error: f64,
+    max_overestimate: f64,
+    total_signed_error: f64,
+    valid_tests: usize,
+}
+
+impl ErrorTotals {
+    /// Score one test case, skipping it if either the exact method or the estimator can't handle it
+    pub fn add<T: EstimateGeometricMean>(&mut self, test_values: &[f64]) {
         // Calculate exact geometric mean
-        let exact_result = match geometric_mean(&test_values) {
+        let exact_result = match geometric_mean(test_values) {
             Ok(result) => result,
-            Err(_) => continue, // Skip invalid test cases
+            Err(_) => return, // Skip invalid test cases
         };
 
         // Calculate estimate
-        let estimate_result = match T::estimate_geometric_mean(&test_values) {
+        let estimate_result = match T::estimate_geometric_mean(test_values) {
             Ok(result) => result,
-            Err(_) => continue, // Skip test cases that the estimator can't handle
+            Err(_) => return, // Skip test cases that the estimator can't handle
         };
 
         // Calculate relative error and signed error
         let relative_error = (estimate_result - exact_result).abs() / exact_result;
         let signed_relative_error = (estimate_result - exact_result) / exact_result;
 
-        total_relative_error += relative_error;
-        total_signed_error += signed_relative_error;
+        self.total_relative_error += relative_error;
+        self.total_signed_error += signed_relative_error;
 
         // Track worst case error
-        if relative_error > max_error {
-            max_error = relative_error;
+        if relative_error > self.max_error {
+            self.max_error = relative_error;
         }
 
         // Track worst case overestimate
-        if signed_relative_error > 0.0 && signed_relative_error > max_overestimate {
-            max_overestimate = signed_relative_error;
+        if signed_relative_error > 0.0 && signed_relative_error > self.max_overestimate {
+            self.max_overestimate = signed_relative_error;
         }
 
-        valid_tests += 1;
+        self.valid_tests += 1;
     }
 
-    let mean_absolute_relative_error = if valid_tests > 0 {
-        total_relative_error / valid_tests as f64
-    } else {
-        f64::NAN
-    };
+    /// The summary so far, with NaN errors when no test case was valid
+    pub fn results(&self) -> Results {
+        let per_test = |total: f64| if self.valid_tests > 0 { total / self.valid_tests as f64 } else { f64::NAN };
+        let worst = |max: f64| if self.valid_tests > 0 { max } else { f64::NAN };
+
+        Results {
+            mean_absolute_relative_error: per_test(self.total_relative_error),
+            worst_case_error: worst(self.max_error),
+            worst_case_overestimate: worst(self.max_overestimate),
+            overall_bias: per_test(self.total_signed_error),
+            total_tests: self.valid_tests,
+        }
+    }
+}
 
-    let worst_case_error = if valid_tests > 0 {
-        max_error
-    } else {
-        f64::NAN
-    };
+/// Errors that can occur while reading a corpus file
+#[derive(Debug)]
+pub enum CorpusError {
+    Io(io::Error),
+    MalformedLine { line: usize, message: String },
+}
 
-    let worst_case_overestimate = if valid_tests > 0 {
-        max_overestimate
-    } else {
-        f64::NAN
-    };
+impl std::fmt::Display for CorpusError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CorpusError::Io(e) => write!(f, "Corpus file error: {}", e),
+            CorpusError::MalformedLine { line, message } => write!(f, "Malformed corpus line {}: {}", line, message),
+        }
+    }
+}
 
-    let overall_bias = if valid_tests > 0 {
-        total_signed_error / valid_tests as f64
-    } else {
-        f64::NAN
-    };
+impl std::error::Error for CorpusError {}
 
-    Results {
-        mean_absolute_relative_error,
-        worst_case_error,
-        worst_case_overestimate,
-        overall_bias,
-        total_tests: valid_tests,
+impl From<io::Error> for CorpusError {
+    fn from(e: io::Error) -> Self {
+        CorpusError::Io(e)
     }
 }
 
+/// Call `f` with each test case of a corpus, one per line, returning how many there were
+///
+/// Values are separated by commas or whitespace, and blank lines and lines starting with `#` are skipped.
+/// Only one line is held at a time, in reused buffers, so corpora far larger than memory can be streamed from disk.
+pub fn for_each_corpus_case<R: BufRead>(mut reader: R, mut f: impl FnMut(&[f64])) -> Result<usize, CorpusError> {
+    let mut line = String::new();
+    let mut values = Vec::new();
+    let mut line_number = 0;
+    let mut cases = 0;
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(cases);
+        }
+        line_number += 1;
+
+        let content = line.trim();
+        if content.is_empty() || content.starts_with('#') {
+            continue;
+        }
+
+        values.clear();
+        for field in content.split(|c: char| c == ',' || c.is_whitespace()).filter(|field| !field.is_empty()) {
+            let value = field.parse::<f64>().map_err(|e| CorpusError::MalformedLine {
+                line: line_number,
+                message: format!("{:?}: {}", field, e),
+            })?;
+            values.push(value);
+        }
+        f(&values);
+        cases += 1;
+    }
+}
+
+/// Evaluate an estimator on a corpus streamed from `reader`, in the format [`for_each_corpus_case`] reads
+pub fn evaluate_corpus_reader<T: EstimateGeometricMean, R: BufRead>(reader: R) -> Result<Results, CorpusError> {
+    let mut totals = ErrorTotals::default();
+    for_each_corpus_case(reader, |test_values| totals.add::<T>(test_values))?;
+    Ok(totals.results())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -177,6 +339,70 @@ mod tests {
         assert!(results.worst_case_overestimate < 1e-14);
     }
 
+    #[test]
+    fn test_samples_match_results() {
+        use crate::table_based::TableBasedApproximation;
+
+        let results = evaluate_estimate::<_, TableBasedApproximation>(&mut StdRng::seed_from_u64(42), 1.0, 1000.0, 200);
+        let samples = sample_estimate::<_, TableBasedApproximation>(&mut StdRng::seed_from_u64(42), 1.0, 1000.0, 200);
+
+        assert_eq!(samples.len(), results.total_tests);
+        assert!(samples.iter().all(|sample| (1..=10).contains(&sample.count)));
+        let mean = samples.iter().map(|sample| sample.relative_error.abs()).sum::<f64>() / samples.len() as f64;
+        assert!((mean - results.mean_absolute_relative_error).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_corpus_matches_drawn_test_cases() {
+        use crate::log_linear::LogLinearApproximation;
+
+        let corpus = random_corpus(&mut StdRng::seed_from_u64(42), 1.0, 1000.0, 200);
+        assert_eq!(corpus.len(), 200);
+        let drawn = evaluate_estimate::<_, LogLinearApproximation>(&mut StdRng::seed_from_u64(42), 1.0, 1000.0, 200);
+        let shared = evaluate_corpus::<LogLinearApproximation>(&corpus);
+        assert_eq!(shared.total_tests, drawn.total_tests);
+        assert_eq!(shared.mean_absolute_relative_error, drawn.mean_absolute_relative_error);
+        assert_eq!(shared.worst_case_error, drawn.worst_case_error);
+        assert_eq!(
+            sample_corpus::<LogLinearApproximation>(&corpus),
+            sample_estimate::<_, LogLinearApproximation>(&mut StdRng::seed_from_u64(42), 1.0, 1000.0, 200)
+        );
+    }
+
+    #[test]
+    fn test_streamed_corpus_matches_corpus_in_memory() {
+        use crate::table_based::TableBasedApproximation;
+
+        let corpus = random_corpus(&mut StdRng::seed_from_u64(42), 1.0, 100_000.0, 500);
+        let text: String = corpus
+            .iter()
+            .map(|case| case.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(",") + "\n")
+            .collect();
+
+        let streamed = evaluate_corpus_reader::<TableBasedApproximation, _>(text.as_bytes()).unwrap();
+        let in_memory = evaluate_corpus::<TableBasedApproximation>(&corpus);
+        assert_eq!(streamed.total_tests, in_memory.total_tests);
+        assert_eq!(streamed.mean_absolute_relative_error, in_memory.mean_absolute_relative_error);
+        assert_eq!(streamed.worst_case_overestimate, in_memory.worst_case_overestimate);
+        assert_eq!(streamed.overall_bias, in_memory.overall_bias);
+    }
+
+    #[test]
+    fn test_corpus_format() {
+        let text = "# team guesses\n300, 10000 900 70\n\n  25\t400  \n2,-8\n";
+        let mut cases = Vec::new();
+        let count = for_each_corpus_case(text.as_bytes(), |values| cases.push(values.to_vec())).unwrap();
+        assert_eq!(count, 3);
+        assert_eq!(cases, vec![vec![300.0, 10_000.0, 900.0, 70.0], vec![25.0, 400.0], vec![2.0, -8.0]]);
+
+        // Cases no method can handle are read, then skipped when scoring
+        let results = evaluate_corpus_reader::<ExactGeometricMean, _>(text.as_bytes()).unwrap();
+        assert_eq!(results.total_tests, 2);
+
+        let error = for_each_corpus_case("1 2\n3 four\n".as_bytes(), |_| {}).unwrap_err();
+        assert!(matches!(error, CorpusError::MalformedLine { line: 2, .. }), "{}", error);
+    }
+
     #[quickcheck]
     fn prop_worst_case_error_bounds_mean_error(seed: u64) -> bool {
         let mut rng = StdRng::seed_from_u64(seed);
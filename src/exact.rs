@@ -1,11 +1,14 @@
+use crate::math;
+
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GeometricMeanError {
     EmptyInput,
     NonPositiveValue,
 }
 
-impl std::fmt::Display for GeometricMeanError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for GeometricMeanError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             GeometricMeanError::EmptyInput => write!(f, "Cannot calculate geometric mean of empty input"),
             GeometricMeanError::NonPositiveValue => write!(f, "Geometric mean requires all positive values"),
@@ -13,10 +16,16 @@ impl std::fmt::Display for GeometricMeanError {
     }
 }
 
-impl std::error::Error for GeometricMeanError {}
+impl core::error::Error for GeometricMeanError {}
 
 pub struct ExactGeometricMean;
 
+impl crate::traits::MethodMetadata for ExactGeometricMean {
+    const NAME: &'static str = "exact";
+    const DESCRIPTION: &'static str = "Multiplies the values and takes the root, computed through logarithms. \
+This is the answer every pen-and-paper method is graded against, and is not meant to be done by hand.";
+}
+
 impl crate::traits::EstimateGeometricMean for ExactGeometricMean {
     type Error = GeometricMeanError;
 
@@ -30,15 +39,143 @@ pub fn geometric_mean(values: &[f64]) -> Result<f64, GeometricMeanError> {
         return Err(GeometricMeanError::EmptyInput);
     }
 
+    let mut all_normal = true;
     for &value in values {
         if value <= 0.0 {
             return Err(GeometricMeanError::NonPositiveValue);
         }
+        all_normal &= value.is_normal();
     }
 
-    let log_sum: f64 = values.iter().map(|&x| x.ln()).sum();
+    // Subnormal, infinite, and NaN values don't split into a mantissa and exponent, so take their logs one by one
+    let log_sum = if all_normal { chunked_log_sum(values) } else { values.iter().map(|&x| math::ln(x)).sum() };
     let log_mean = log_sum / values.len() as f64;
-    Ok(log_mean.exp())
+    Ok(math::exp(log_mean))
+}
+
+/// The geometric standard deviation: the typical factor the values differ from their geometric mean by
+///
+/// It is `exp` of the standard deviation of the values' natural logs, taken over the values themselves rather than as
+/// a sample estimate, so it is always at least 1, and exactly 1 for a single value or identical values.
+pub fn geometric_std_dev(values: &[f64]) -> Result<f64, GeometricMeanError> {
+    let mean_log = math::ln(geometric_mean(values)?);
+    let variance = values
+        .iter()
+        .map(|&x| {
+            let deviation = math::ln(x) - mean_log;
+            deviation * deviation
+        })
+        .sum::<f64>()
+        / values.len() as f64;
+    Ok(math::exp(math::sqrt(variance)))
+}
+
+/// Values whose logarithm is taken at once: each of the lanes multiplies 16 mantissas below 2, so their product stays
+/// below 2^64, far from overflowing
+const CHUNK: usize = 64;
+
+/// Independent products per chunk, so the multiplications can run side by side in vector registers
+const LANES: usize = 4;
+
+const MANTISSA_BITS: u32 = 52;
+const MANTISSA_MASK: u64 = (1 << MANTISSA_BITS) - 1;
+const EXPONENT_BIAS: i64 = 1023;
+/// The bits of 1.0, which give a mantissa its exponent of zero
+const ONE_BITS: u64 = 0x3ff0_0000_0000_0000;
+
+/// A positive normal float as its mantissa, scaled into [1, 2), and its biased power of two
+fn split(x: f64) -> (f64, i64) {
+    let bits = x.to_bits();
+    (f64::from_bits(bits & MANTISSA_MASK | ONE_BITS), (bits >> MANTISSA_BITS) as i64)
+}
+
+/// The sum of natural logs of positive normal values, taking one logarithm per chunk rather than one per value
+///
+/// Each value splits into a mantissa and a power of two; the mantissas multiply together and the powers add as
+/// integers, so ln(x₁ × … × xₙ) = ln(m₁ × … × mₙ) + (e₁ + … + eₙ) ln 2.
+/// The multiplications have no dependencies between lanes, so they vectorize, and are far cheaper than logarithms.
+fn chunked_log_sum(values: &[f64]) -> f64 {
+    let mut log_sum = 0.0;
+    let mut exponent = 0;
+    for chunk in values.chunks(CHUNK) {
+        let (product, chunk_exponent) = chunk_product(chunk);
+        log_sum += math::ln(product);
+        exponent += chunk_exponent;
+    }
+    log_sum + (exponent - EXPONENT_BIAS * values.len() as i64) as f64 * core::f64::consts::LN_2
+}
+
+/// The product of a chunk's mantissas, and the sum of its biased exponents
+#[cfg(not(all(feature = "simd", target_arch = "x86_64")))]
+fn chunk_product(chunk: &[f64]) -> (f64, i64) {
+    let mut products = [1.0; LANES];
+    let mut exponents = [0; LANES];
+    let mut groups = chunk.chunks_exact(LANES);
+    for group in &mut groups {
+        for lane in 0..LANES {
+            let (mantissa, exponent) = split(group[lane]);
+            products[lane] *= mantissa;
+            exponents[lane] += exponent;
+        }
+    }
+    for (lane, &value) in groups.remainder().iter().enumerate() {
+        let (mantissa, exponent) = split(value);
+        products[lane] *= mantissa;
+        exponents[lane] += exponent;
+    }
+    (products.iter().product(), exponents.iter().sum())
+}
+
+/// The product of a chunk's mantissas, and the sum of its biased exponents, with explicit SSE2 instructions
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+fn chunk_product(chunk: &[f64]) -> (f64, i64) {
+    // SAFETY: SSE2 is part of every x86-64 processor, so no runtime detection is needed
+    unsafe { sse2_chunk_product(chunk) }
+}
+
+/// Two registers of two lanes each match the portable version's four lanes.
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+#[target_feature(enable = "sse2")]
+fn sse2_chunk_product(chunk: &[f64]) -> (f64, i64) {
+    use core::arch::x86_64::*;
+
+    let mantissa_mask = _mm_castsi128_pd(_mm_set1_epi64x(MANTISSA_MASK as i64));
+    let one = _mm_set1_pd(1.0);
+    let split_pair = |pair: __m128d| {
+        let mantissas = _mm_or_pd(_mm_and_pd(pair, mantissa_mask), one);
+        let exponents = _mm_srli_epi64::<{ MANTISSA_BITS as i32 }>(_mm_castpd_si128(pair));
+        (mantissas, exponents)
+    };
+
+    let (mut low_products, mut high_products) = (one, one);
+    let (mut low_exponents, mut high_exponents) = (_mm_setzero_si128(), _mm_setzero_si128());
+    let mut groups = chunk.chunks_exact(LANES);
+    for group in &mut groups {
+        // SAFETY: each group holds four values, so both unaligned two-value loads are in bounds
+        let (low, high) = unsafe { (_mm_loadu_pd(group.as_ptr()), _mm_loadu_pd(group.as_ptr().add(2))) };
+        let (mantissas, exponents) = split_pair(low);
+        low_products = _mm_mul_pd(low_products, mantissas);
+        low_exponents = _mm_add_epi64(low_exponents, exponents);
+        let (mantissas, exponents) = split_pair(high);
+        high_products = _mm_mul_pd(high_products, mantissas);
+        high_exponents = _mm_add_epi64(high_exponents, exponents);
+    }
+
+    let mut products = [1.0; LANES];
+    let mut exponents = [0; LANES];
+    // SAFETY: each array holds four values, so both unaligned two-value stores are in bounds
+    unsafe {
+        _mm_storeu_pd(products.as_mut_ptr(), low_products);
+        _mm_storeu_pd(products.as_mut_ptr().add(2), high_products);
+        _mm_storeu_si128(exponents.as_mut_ptr().cast(), low_exponents);
+        _mm_storeu_si128(exponents.as_mut_ptr().add(2).cast(), high_exponents);
+    }
+    for (lane, &value) in groups.remainder().iter().enumerate() {
+        let (mantissa, exponent) = split(value);
+        products[lane] *= mantissa;
+        exponents[lane] += exponent;
+    }
+    (products.iter().product(), exponents.iter().sum::<i64>())
 }
 
 #[cfg(test)]
@@ -94,6 +231,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::excessive_precision)]
     fn test_geometric_mean_small_numbers() {
         let result = geometric_mean(&[0.1, 0.01]).unwrap();
         assert!((result - 0.031622776601683795).abs() < 1e-10);
@@ -105,6 +243,39 @@ mod tests {
         assert!((result - 100.0).abs() < 1e-8);
     }
 
+    #[test]
+    fn test_chunk_product_multiplies_mantissas_lane_by_lane() {
+        let values: Vec<f64> = (1..=CHUNK).map(|i| i as f64 * 1.37e10 / (i * i) as f64).collect();
+        for len in 0..=CHUNK {
+            let chunk = &values[..len];
+            let mut products = [1.0; LANES];
+            let mut exponents = [0; LANES];
+            for (i, &value) in chunk.iter().enumerate() {
+                let (mantissa, exponent) = split(value);
+                products[i % LANES] *= mantissa;
+                exponents[i % LANES] += exponent;
+            }
+            let expected = (products.iter().product::<f64>(), exponents.iter().sum::<i64>());
+            assert_eq!(chunk_product(chunk), expected, "{} values", len);
+        }
+    }
+
+    #[test]
+    fn test_long_inputs_use_one_log_per_chunk() {
+        let values: Vec<f64> = (1..=1_000).map(|i| i as f64).collect();
+        let expected = (values.iter().map(|x| x.ln()).sum::<f64>() / 1_000.0).exp();
+        assert!((geometric_mean(&values).unwrap() - expected).abs() < expected * 1e-13);
+        assert!((geometric_mean(&[1e300; 200]).unwrap() / 1e300 - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_values_that_dont_split_take_logs_one_by_one() {
+        let subnormal = geometric_mean(&[1e-310, 1e10]).unwrap();
+        assert!((subnormal - 1e-150).abs() < 1e-150 * 1e-12, "{}", subnormal);
+        assert_eq!(geometric_mean(&[4.0, f64::INFINITY]), Ok(f64::INFINITY));
+        assert!(geometric_mean(&[4.0, f64::NAN]).unwrap().is_nan());
+    }
+
     #[test]
     fn test_trait_implementation_matches_function() {
         use crate::traits::EstimateGeometricMean;
@@ -126,6 +297,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_geometric_std_dev() {
+        // Logs ln 2 either side of the mean, so the spread is a factor of 2
+        assert!((geometric_std_dev(&[50.0, 200.0]).unwrap() - 2.0).abs() < 1e-12);
+        assert!((geometric_std_dev(&[10.0, 100.0, 1_000.0]).unwrap() - 10.0_f64.powf((2.0_f64 / 3.0).sqrt())).abs() < 1e-9);
+        assert!((geometric_std_dev(&[70.0]).unwrap() - 1.0).abs() < 1e-12);
+        assert!((geometric_std_dev(&[300.0; 5]).unwrap() - 1.0).abs() < 1e-12);
+
+        assert_eq!(geometric_std_dev(&[]), Err(GeometricMeanError::EmptyInput));
+        assert_eq!(geometric_std_dev(&[4.0, 0.0]), Err(GeometricMeanError::NonPositiveValue));
+    }
+
     #[test]
     fn test_trait_implementation_error_cases() {
         use crate::traits::EstimateGeometricMean;
@@ -184,6 +367,19 @@ mod tests {
             TestResult::from_bool((scaled_mean - expected).abs() < tolerance)
         }
 
+        #[quickcheck]
+        fn prop_chunked_log_sum_matches_per_value_logs(values: Vec<PositiveF64>, repeats: u8) -> TestResult {
+            // Repeating the values makes inputs spanning several chunks, with a partial one at the end
+            let values: Vec<f64> = values.iter().map(|x| x.0).cycle().take(values.len() * (repeats as usize % 8 + 1)).collect();
+            if values.is_empty() {
+                return TestResult::discard();
+            }
+
+            let per_value: f64 = values.iter().map(|x| x.ln()).sum();
+            let magnitude: f64 = values.iter().map(|x| x.ln().abs()).sum();
+            TestResult::from_bool((chunked_log_sum(&values) - per_value).abs() <= magnitude.max(1.0) * 1e-13)
+        }
+
         #[quickcheck]
         fn prop_order_independence(mut values: Vec<PositiveF64>) -> TestResult {
             if values.len() < 2 {
@@ -0,0 +1,302 @@
+//! Fermi estimation: a quantity too obscure to guess directly, broken into factors the team can guess.
+//!
+//! Each factor is estimated with the table-based method from the team's guesses, and the factors are then combined by
+//! adding and subtracting their scaled logs, the same way the table turns multiplication into addition.
+//! The result is graded against the exact computation: the product and quotient of each factor's exact geometric mean.
+
+use rand::Rng;
+use rand::distributions::Distribution;
+
+use crate::display::{TableLog, Value, joined};
+use crate::exact::geometric_mean;
+use crate::table_based::{GeometricMeanError, log_representation_to_number, number_to_log_representation, rounded_up_average};
+use crate::trivia_guess::TriviaGuessDistribution;
+
+/// Whether a factor multiplies or divides the running estimate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Operation {
+    Multiply,
+    Divide,
+}
+
+impl Operation {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Operation::Multiply => "×",
+            Operation::Divide => "÷",
+        }
+    }
+}
+
+/// One factor of a Fermi problem and the team's guesses for it
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FermiFactor {
+    pub label: String,
+    pub operation: Operation,
+    pub guesses: Vec<u64>,
+}
+
+/// A quantity expressed as a chain of factors, starting from the first and applying each later one in turn
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FermiProblem {
+    pub question: String,
+    pub factors: Vec<FermiFactor>,
+}
+
+/// A factor of a built-in scenario, with the range its true value is drawn from
+struct ScenarioFactor {
+    label: &'static str,
+    operation: Operation,
+    min: u64,
+    max: u64,
+}
+
+struct Scenario {
+    question: &'static str,
+    factors: &'static [ScenarioFactor],
+}
+
+const fn factor(label: &'static str, operation: Operation, min: u64, max: u64) -> ScenarioFactor {
+    ScenarioFactor { label, operation, min, max }
+}
+
+/// Classic Fermi questions, with factor ranges chosen so every combination stays at least 1
+const SCENARIOS: [Scenario; 5] = [
+    Scenario {
+        question: "How many piano tuners work in a large city?",
+        factors: &[
+            factor("People in the city", Operation::Multiply, 1_000_000, 10_000_000),
+            factor("People per household", Operation::Divide, 2, 4),
+            factor("Households per piano", Operation::Divide, 10, 30),
+            factor("Tunings per piano each year", Operation::Multiply, 1, 2),
+            factor("Tunings per tuner each year", Operation::Divide, 500, 1_500),
+        ],
+    },
+    Scenario {
+        question: "How many times does a heart beat in a lifetime?",
+        factors: &[
+            factor("Beats per minute", Operation::Multiply, 60, 90),
+            factor("Minutes per day", Operation::Multiply, 1_440, 1_440),
+            factor("Days per year", Operation::Multiply, 365, 365),
+            factor("Years in a lifetime", Operation::Multiply, 70, 90),
+        ],
+    },
+    Scenario {
+        question: "How many words are in a long novel?",
+        factors: &[
+            factor("Pages", Operation::Multiply, 300, 800),
+            factor("Lines per page", Operation::Multiply, 25, 40),
+            factor("Words per line", Operation::Multiply, 8, 14),
+        ],
+    },
+    Scenario {
+        question: "How many hairs are on a human head?",
+        factors: &[
+            factor("Scalp area in square centimeters", Operation::Multiply, 500, 800),
+            factor("Hairs per square centimeter", Operation::Multiply, 150, 250),
+        ],
+    },
+    Scenario {
+        question: "How many school buses would it take to carry a city's schoolchildren at once?",
+        factors: &[
+            factor("People in the city", Operation::Multiply, 500_000, 5_000_000),
+            factor("People per schoolchild", Operation::Divide, 5, 8),
+            factor("Seats per bus", Operation::Divide, 40, 80),
+        ],
+    },
+];
+
+impl FermiProblem {
+    /// Generate a problem from one of the built-in scenarios, drawing each factor's true value from its range and the
+    /// team's guesses around it
+    pub fn generate<R: Rng>(rng: &mut R, team_size: usize, log_std_dev: f64) -> FermiProblem {
+        let scenario = &SCENARIOS[rng.gen_range(0..SCENARIOS.len())];
+
+        let factors = scenario
+            .factors
+            .iter()
+            .map(|factor| {
+                let ln_value = rng.gen_range((factor.min as f64).ln()..=(factor.max as f64).ln());
+                let distribution = TriviaGuessDistribution::new(ln_value.exp().round().max(1.0) as u64, log_std_dev)
+                    .expect("scenario values are positive and the spread is validated by the caller");
+                FermiFactor {
+                    label: factor.label.to_string(),
+                    operation: factor.operation,
+                    guesses: (0..team_size).map(|_| distribution.sample(rng)).collect(),
+                }
+            })
+            .collect();
+
+        FermiProblem { question: scenario.question.to_string(), factors }
+    }
+
+    /// The product and quotient of each factor's exact geometric mean
+    pub fn exact(&self) -> Result<f64, GeometricMeanError> {
+        if self.factors.is_empty() {
+            return Err(GeometricMeanError::EmptyInput);
+        }
+
+        let mut total = 1.0;
+        for factor in &self.factors {
+            let guesses: Vec<f64> = factor.guesses.iter().map(|&g| g as f64).collect();
+            let mean = geometric_mean(&guesses)?;
+            match factor.operation {
+                Operation::Multiply => total *= mean,
+                Operation::Divide => total /= mean,
+            }
+        }
+        Ok(total)
+    }
+
+    /// Work the problem with the table: average each factor's scaled logs, then add or subtract them
+    pub fn steps(&self) -> Result<FermiSteps, GeometricMeanError> {
+        if self.factors.is_empty() {
+            return Err(GeometricMeanError::EmptyInput);
+        }
+
+        let mut factor_steps = Vec::with_capacity(self.factors.len());
+        for factor in &self.factors {
+            if factor.guesses.is_empty() {
+                return Err(GeometricMeanError::EmptyInput);
+            }
+            if factor.guesses.contains(&0) {
+                return Err(GeometricMeanError::NonPositiveValue);
+            }
+
+            let log_conversions: Vec<i32> =
+                factor.guesses.iter().map(|&g| number_to_log_representation(g as f64)).collect();
+            let sum: i32 = log_conversions.iter().sum();
+            let average = rounded_up_average(sum, log_conversions.len());
+            factor_steps.push(FactorStep { factor: factor.clone(), log_conversions, average });
+        }
+
+        let total_log: i32 = factor_steps
+            .iter()
+            .map(|step| match step.factor.operation {
+                Operation::Multiply => step.average,
+                Operation::Divide => -step.average,
+            })
+            .sum();
+        if total_log < 0 {
+            return Err(GeometricMeanError::ValueTooSmall);
+        }
+
+        Ok(FermiSteps { factor_steps, total_log, estimate: log_representation_to_number(total_log) })
+    }
+}
+
+struct FactorStep {
+    factor: FermiFactor,
+    log_conversions: Vec<i32>,
+    average: i32,
+}
+
+/// The worked table solution to a Fermi problem
+pub struct FermiSteps {
+    factor_steps: Vec<FactorStep>,
+    total_log: i32,
+    estimate: f64,
+}
+
+impl FermiSteps {
+    pub fn estimate(&self) -> f64 {
+        self.estimate
+    }
+}
+
+impl std::fmt::Display for FermiSteps {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "1. Average each factor's guesses in log representation:")?;
+        for step in &self.factor_steps {
+            let logs = joined(step.log_conversions.iter().map(|&l| TableLog(l)), ", ");
+            writeln!(f, "   {}: {} → {}", step.factor.label, logs, TableLog(step.average))?;
+        }
+        writeln!(f)?;
+
+        writeln!(f, "2. Add the multiplied factors and subtract the divided ones:")?;
+        write!(f, "  ")?;
+        for (i, step) in self.factor_steps.iter().enumerate() {
+            match (i, step.factor.operation) {
+                (0, Operation::Multiply) => write!(f, " {}", TableLog(step.average))?,
+                (_, Operation::Multiply) => write!(f, " + {}", TableLog(step.average))?,
+                (_, Operation::Divide) => write!(f, " - {}", TableLog(step.average))?,
+            }
+        }
+        writeln!(f, " = {}", TableLog(self.total_log))?;
+        writeln!(f)?;
+
+        writeln!(f, "3. Convert back:")?;
+        write!(f, "   {} → {}", TableLog(self.total_log), Value(self.estimate))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn problem() -> FermiProblem {
+        let factor = |label: &str, operation, guesses: &[u64]| FermiFactor {
+            label: label.to_string(),
+            operation,
+            guesses: guesses.to_vec(),
+        };
+        FermiProblem {
+            question: "How many words are in a long novel?".to_string(),
+            factors: vec![
+                factor("Pages", Operation::Multiply, &[300, 500]),
+                factor("Lines per page", Operation::Multiply, &[30, 40]),
+                factor("Words per line", Operation::Divide, &[10]),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_exact() {
+        let exact = problem().exact().unwrap();
+        assert!((exact - (150_000.0_f64).sqrt() * 1_200.0_f64.sqrt() / 10.0).abs() < 1e-6);
+        assert!(FermiProblem { question: String::new(), factors: vec![] }.exact().is_err());
+
+        let mut zero_guess = problem();
+        zero_guess.factors[1].guesses.push(0);
+        assert_eq!(zero_guess.exact(), Err(GeometricMeanError::NonPositiveValue));
+        zero_guess.factors[1].guesses.clear();
+        assert_eq!(zero_guess.exact(), Err(GeometricMeanError::EmptyInput));
+    }
+
+    #[test]
+    fn test_steps() {
+        let steps = problem().steps().unwrap();
+        assert_eq!(steps.estimate(), 1_600.0);
+        assert_eq!(
+            steps.to_string(),
+            "1. Average each factor's guesses in log representation:\n   Pages: 2.5, 2.7 → 2.6\n   Lines per page: 1.5, 1.6 → 1.6\n   Words per line: 1.0 → 1.0\n\n2. Add the multiplied factors and subtract the divided ones:\n   2.6 + 1.6 - 1.0 = 3.2\n\n3. Convert back:\n   3.2 → 1600"
+        );
+    }
+
+    #[test]
+    fn test_steps_below_one() {
+        let mut problem = problem();
+        problem.factors[2].guesses = vec![1_000_000];
+        assert_eq!(problem.steps().err(), Some(GeometricMeanError::ValueTooSmall));
+        problem.factors[2].guesses = vec![0];
+        assert_eq!(problem.steps().err(), Some(GeometricMeanError::NonPositiveValue));
+    }
+
+    #[test]
+    fn test_generated_problems_can_be_worked() {
+        let mut rng = StdRng::seed_from_u64(23);
+        for _ in 0..200 {
+            let problem = FermiProblem::generate(&mut rng, 3, 0.3);
+            assert!(problem.factors.iter().all(|factor| factor.guesses.len() == 3));
+            let steps = problem.steps().unwrap();
+            let exact = problem.exact().unwrap();
+            let error = (steps.estimate() / exact).max(exact / steps.estimate());
+            assert!(error < 3.0, "{} vs {}", steps.estimate(), exact);
+        }
+    }
+}
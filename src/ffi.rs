@@ -0,0 +1,264 @@
+//! C API for embedding the estimators in software written in other languages.
+//!
+//! Every function returns a [`GeomeanStatus`] and writes its result through an out-pointer, so callers can
+//! distinguish failures without sentinel values.
+//! Panics are caught at the boundary rather than unwinding into foreign code.
+//! The matching header, `include/pen_and_paper_geometric_mean.h`, is generated by cbindgen into the build's output
+//! directory when building with the `ffi` feature, and a test checks the committed copy matches it.
+
+use std::ffi::{CStr, c_char};
+use std::panic::{UnwindSafe, catch_unwind};
+
+use crate::exact::geometric_mean;
+use crate::log_linear::{convert_from_log_linear, convert_to_log_linear};
+use crate::methods;
+use crate::practice_mode::{AnswerEvaluation, evaluate_answer};
+use crate::table_based::{log_representation_to_number, number_to_log_representation};
+
+/// Outcome of a call into the C API
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GeomeanStatus {
+    Ok = 0,
+    /// A required pointer argument was null
+    NullPointer = 1,
+    /// The method name was not valid UTF-8
+    InvalidUtf8 = 2,
+    /// No estimation method has the given name
+    UnknownMethod = 3,
+    /// The values cannot be estimated or converted, e.g. empty, non-positive, or below 1 for a pen-and-paper method
+    InvalidInput = 4,
+    /// The library panicked; this is a bug
+    Panic = 5,
+}
+
+/// How an answer to a problem was graded, with the same tiers as practice mode
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GeomeanGrade {
+    /// The answer matches the method's estimate, rounded either way
+    Correct = 0,
+    /// The answer is strictly closer to the exact geometric mean than the method's estimate
+    Excellent = 1,
+    Incorrect = 2,
+}
+
+/// Run `body` and write its value to `out`, translating errors and panics into a status
+fn write_result<T>(out: *mut T, body: impl FnOnce() -> Result<T, GeomeanStatus> + UnwindSafe) -> GeomeanStatus {
+    if out.is_null() {
+        return GeomeanStatus::NullPointer;
+    }
+
+    match catch_unwind(body) {
+        Ok(Ok(value)) => {
+            // SAFETY: `out` is non-null, and the caller guarantees it points to writable memory for a `T`
+            unsafe { out.write(value) };
+            GeomeanStatus::Ok
+        }
+        Ok(Err(status)) => status,
+        Err(_) => GeomeanStatus::Panic,
+    }
+}
+
+/// # Safety
+/// `method` must be null or a NUL-terminated string valid for the duration of the call.
+unsafe fn method_name<'a>(method: *const c_char) -> Result<&'a str, GeomeanStatus> {
+    if method.is_null() {
+        return Err(GeomeanStatus::NullPointer);
+    }
+    // SAFETY: upheld by the caller
+    unsafe { CStr::from_ptr(method) }.to_str().map_err(|_| GeomeanStatus::InvalidUtf8)
+}
+
+/// # Safety
+/// `values` must be null or point to `len` readable doubles valid for the duration of the call.
+unsafe fn values_slice<'a>(values: *const f64, len: usize) -> Result<&'a [f64], GeomeanStatus> {
+    if len == 0 {
+        return Ok(&[]);
+    }
+    if values.is_null() {
+        return Err(GeomeanStatus::NullPointer);
+    }
+    // SAFETY: upheld by the caller
+    Ok(unsafe { std::slice::from_raw_parts(values, len) })
+}
+
+fn estimate_with(method: &str, values: &[f64]) -> Result<f64, GeomeanStatus> {
+    match methods::estimate_by_name(method, values) {
+        Some(result) => result.map_err(|_| GeomeanStatus::InvalidInput),
+        None => Err(GeomeanStatus::UnknownMethod),
+    }
+}
+
+fn grade(method: &str, guesses: &[f64], user_answer: u64) -> Result<GeomeanGrade, GeomeanStatus> {
+    let estimate = estimate_with(method, guesses)?;
+    let exact = geometric_mean(guesses).map_err(|_| GeomeanStatus::InvalidInput)?;
+
+    Ok(match evaluate_answer(user_answer, exact, estimate) {
+        AnswerEvaluation::Correct => GeomeanGrade::Correct,
+        AnswerEvaluation::Excellent => GeomeanGrade::Excellent,
+        AnswerEvaluation::Incorrect | AnswerEvaluation::GaveUp => GeomeanGrade::Incorrect,
+    })
+}
+
+/// The pen-and-paper conversions are only defined for finite values of at least 1
+fn convertible(value: f64) -> Result<f64, GeomeanStatus> {
+    if value.is_finite() && value >= 1.0 { Ok(value) } else { Err(GeomeanStatus::InvalidInput) }
+}
+
+/// Estimate the geometric mean of `len` values with the named method ("exact", "log-linear", "table-based", or "auto-shortcut")
+///
+/// # Safety
+/// `method` must be a NUL-terminated string, `values` must point to `len` doubles (or may be null when `len` is 0),
+/// and `out` must point to a writable double.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn geomean_estimate(
+    method: *const c_char,
+    values: *const f64,
+    len: usize,
+    out: *mut f64,
+) -> GeomeanStatus {
+    write_result(out, || {
+        // SAFETY: upheld by the caller
+        let (method, values) = unsafe { (method_name(method)?, values_slice(values, len)?) };
+        estimate_with(method, values)
+    })
+}
+
+/// Grade a user's answer to a problem with the given guesses, solved with the named method
+///
+/// # Safety
+/// `method` must be a NUL-terminated string, `guesses` must point to `len` doubles (or may be null when `len` is 0),
+/// and `out` must point to a writable `GeomeanGrade`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn geomean_grade(
+    method: *const c_char,
+    guesses: *const f64,
+    len: usize,
+    user_answer: u64,
+    out: *mut GeomeanGrade,
+) -> GeomeanStatus {
+    write_result(out, || {
+        // SAFETY: upheld by the caller
+        let (method, guesses) = unsafe { (method_name(method)?, values_slice(guesses, len)?) };
+        grade(method, guesses, user_answer)
+    })
+}
+
+/// Convert a number to log-linear notation, e.g. 2847 to 4.2847
+///
+/// # Safety
+/// `out` must point to a writable double.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn geomean_to_log_linear(value: f64, out: *mut f64) -> GeomeanStatus {
+    write_result(out, || convertible(value).map(convert_to_log_linear))
+}
+
+/// Convert log-linear notation back to a number, e.g. 3.75 to 750
+///
+/// # Safety
+/// `out` must point to a writable double.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn geomean_from_log_linear(log_value: f64, out: *mut f64) -> GeomeanStatus {
+    write_result(out, || convertible(log_value).map(convert_from_log_linear))
+}
+
+/// Convert a number to the table-based log representation, in tenths, e.g. 2000 to 33 (3.3)
+///
+/// # Safety
+/// `out` must point to a writable int32_t.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn geomean_to_table_log(value: f64, out: *mut i32) -> GeomeanStatus {
+    write_result(out, || convertible(value).map(number_to_log_representation))
+}
+
+/// Convert a table-based log representation, in tenths, back to a number, e.g. 36 to 4000
+///
+/// # Safety
+/// `out` must point to a writable double.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn geomean_from_table_log(scaled_log: i32, out: *mut f64) -> GeomeanStatus {
+    write_result(out, || {
+        if scaled_log < 0 {
+            return Err(GeomeanStatus::InvalidInput);
+        }
+        Ok(log_representation_to_number(scaled_log))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ptr;
+
+    #[test]
+    fn test_committed_header_is_current() {
+        let generated = concat!(env!("OUT_DIR"), "/pen_and_paper_geometric_mean.h");
+        assert!(
+            include_str!(concat!(env!("OUT_DIR"), "/pen_and_paper_geometric_mean.h"))
+                == include_str!("../include/pen_and_paper_geometric_mean.h"),
+            "include/pen_and_paper_geometric_mean.h is out of date; copy the generated {} over it",
+            generated
+        );
+    }
+
+    #[test]
+    fn test_estimate() {
+        let values = [25.0, 400.0];
+        let mut out = 0.0;
+        let status = unsafe { geomean_estimate(c"table-based".as_ptr(), values.as_ptr(), values.len(), &mut out) };
+        assert_eq!(status, GeomeanStatus::Ok);
+        assert_eq!(out, 100.0);
+    }
+
+    #[test]
+    fn test_estimate_errors() {
+        let values = [25.0, 400.0];
+        let mut out = 0.0;
+        let estimate = |method: *const c_char, values: *const f64, len: usize, out: *mut f64| unsafe {
+            geomean_estimate(method, values, len, out)
+        };
+
+        assert_eq!(estimate(c"abacus".as_ptr(), values.as_ptr(), 2, &mut out), GeomeanStatus::UnknownMethod);
+        assert_eq!(estimate(c"\xff".as_ptr(), values.as_ptr(), 2, &mut out), GeomeanStatus::InvalidUtf8);
+        assert_eq!(estimate(c"exact".as_ptr(), ptr::null(), 0, &mut out), GeomeanStatus::InvalidInput);
+        assert_eq!(estimate(c"exact".as_ptr(), ptr::null(), 2, &mut out), GeomeanStatus::NullPointer);
+        assert_eq!(estimate(ptr::null(), values.as_ptr(), 2, &mut out), GeomeanStatus::NullPointer);
+        assert_eq!(estimate(c"exact".as_ptr(), values.as_ptr(), 2, ptr::null_mut()), GeomeanStatus::NullPointer);
+        assert_eq!(out, 0.0);
+    }
+
+    #[test]
+    fn test_grade() {
+        assert_eq!(grade("table-based", &[25.0, 400.0], 100), Ok(GeomeanGrade::Correct));
+        assert_eq!(grade("table-based", &[25.0, 400.0], 5000), Ok(GeomeanGrade::Incorrect));
+        assert_eq!(grade("log-linear", &[0.5], 1), Err(GeomeanStatus::InvalidInput));
+
+        let guesses = [25.0, 400.0];
+        let mut out = GeomeanGrade::Incorrect;
+        let status = unsafe { geomean_grade(c"table-based".as_ptr(), guesses.as_ptr(), 2, 100, &mut out) };
+        assert_eq!(status, GeomeanStatus::Ok);
+        assert_eq!(out, GeomeanGrade::Correct);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let mut number = 0.0;
+        let mut scaled = 0;
+        unsafe {
+            assert_eq!(geomean_to_log_linear(300.0, &mut number), GeomeanStatus::Ok);
+            assert!((number - 3.3).abs() < 1e-10);
+            assert_eq!(geomean_from_log_linear(3.75, &mut number), GeomeanStatus::Ok);
+            assert!((number - 750.0).abs() < 1e-8);
+
+            assert_eq!(geomean_to_table_log(2000.0, &mut scaled), GeomeanStatus::Ok);
+            assert_eq!(scaled, 33);
+            assert_eq!(geomean_from_table_log(36, &mut number), GeomeanStatus::Ok);
+            assert!((number - 4000.0).abs() < 1e-6);
+
+            assert_eq!(geomean_to_table_log(0.5, &mut scaled), GeomeanStatus::InvalidInput);
+            assert_eq!(geomean_to_log_linear(f64::NAN, &mut number), GeomeanStatus::InvalidInput);
+            assert_eq!(geomean_from_table_log(-1, &mut number), GeomeanStatus::InvalidInput);
+        }
+    }
+}
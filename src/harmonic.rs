@@ -0,0 +1,312 @@
+//! Harmonic mean methods, for trivia questions about rates and speeds.
+//!
+//! Averaging speeds over equal distances, or rates over equal amounts of work, calls for the harmonic mean rather than
+//! the geometric mean.
+//! The estimators reuse the geometric mean traits, overriding [`EstimateGeometricMean::exact_mean`] so that practice
+//! mode grades against the harmonic mean.
+
+use alloc::vec::Vec;
+
+use crate::display::{TableLog, Value, joined};
+use crate::table_based::{log_representation_to_number, number_to_log_representation};
+use crate::traits::{EstimateGeometricMean, EstimateGeometricMeanStepByStep, FinalAnswer, MethodMetadata};
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HarmonicMeanError {
+    EmptyInput,
+    NonPositiveValue,
+    ValueTooSmall,
+}
+
+impl core::fmt::Display for HarmonicMeanError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            HarmonicMeanError::EmptyInput => write!(f, "Cannot calculate harmonic mean of empty input"),
+            HarmonicMeanError::NonPositiveValue => write!(f, "Harmonic mean requires all positive values"),
+            HarmonicMeanError::ValueTooSmall => write!(f, "Values must be >= 1.0 for this pen-and-paper method"),
+        }
+    }
+}
+
+impl core::error::Error for HarmonicMeanError {}
+
+/// The number of values divided by the sum of their reciprocals
+pub fn harmonic_mean(values: &[f64]) -> Result<f64, HarmonicMeanError> {
+    if values.is_empty() {
+        return Err(HarmonicMeanError::EmptyInput);
+    }
+
+    for &value in values {
+        if value <= 0.0 {
+            return Err(HarmonicMeanError::NonPositiveValue);
+        }
+    }
+
+    let reciprocal_sum: f64 = values.iter().map(|&x| 1.0 / x).sum();
+    Ok(values.len() as f64 / reciprocal_sum)
+}
+
+pub struct ExactHarmonicMean;
+
+impl MethodMetadata for ExactHarmonicMean {
+    const NAME: &'static str = "exact-harmonic";
+    const DESCRIPTION: &'static str = "Divides the count of values by the sum of their reciprocals. \
+This is the answer the reciprocal-table method is graded against.";
+    const MEAN: &'static str = "harmonic mean";
+}
+
+impl EstimateGeometricMean for ExactHarmonicMean {
+    type Error = HarmonicMeanError;
+
+    fn estimate_geometric_mean(values: &[f64]) -> Result<f64, Self::Error> {
+        harmonic_mean(values)
+    }
+
+    fn exact_mean(values: &[f64]) -> Option<f64> {
+        harmonic_mean(values).ok()
+    }
+}
+
+/// Estimates the harmonic mean with the 10^(1/10) table used by the table-based geometric mean method
+///
+/// Reciprocals are awkward by hand, so each value is instead compared with the largest: the difference of their table
+/// logs gives, from the table, how many times smaller the value is.
+/// Those ratios are plain table entries to add up, and the largest value divided by their average is the harmonic mean.
+/// The division is done by subtracting logs, so the only arithmetic beyond the table is one sum and one division.
+pub struct ReciprocalTableApproximation;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReciprocalTableSteps {
+    input_values: Vec<f64>,
+    log_conversions: Vec<i32>,
+    /// Table log of the largest value
+    largest_log: i32,
+    /// How many times smaller than the largest each value is, from the table
+    ratios: Vec<f64>,
+    ratio_sum: f64,
+    /// Table log of the average ratio
+    average_ratio_log: i32,
+    final_result: f64,
+}
+
+impl FinalAnswer for ReciprocalTableSteps {
+    fn final_answer(&self) -> f64 {
+        self.final_result
+    }
+}
+
+impl MethodMetadata for ReciprocalTableApproximation {
+    const NAME: &'static str = "reciprocal-table";
+    const DESCRIPTION: &'static str = "Compares each value with the largest using the table-based method's table, \
+giving how many times smaller each one is. \
+Those ratios are averaged, and the largest value divided by the average ratio is the harmonic mean.";
+    const MEAN: &'static str = "harmonic mean";
+}
+
+impl EstimateGeometricMeanStepByStep for ReciprocalTableApproximation {
+    type StepByStep = ReciprocalTableSteps;
+    type Error = HarmonicMeanError;
+
+    fn estimate_geometric_mean_steps(values: &[f64]) -> Result<Self::StepByStep, Self::Error> {
+        reciprocal_table_approximation_steps(values)
+    }
+}
+
+impl EstimateGeometricMean for ReciprocalTableApproximation {
+    type Error = HarmonicMeanError;
+
+    fn estimate_geometric_mean(values: &[f64]) -> Result<f64, Self::Error> {
+        let steps = Self::estimate_geometric_mean_steps(values)?;
+        Ok(steps.final_answer())
+    }
+
+    fn exact_mean(values: &[f64]) -> Option<f64> {
+        harmonic_mean(values).ok()
+    }
+}
+
+impl core::fmt::Display for ReciprocalTableSteps {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let largest_log = TableLog(self.largest_log);
+
+        writeln!(f, "Input values: [{}]", joined(self.input_values.iter().map(|&v| Value(v)), ", "))?;
+        writeln!(f)?;
+
+        writeln!(f, "1. Convert each value to log representation:")?;
+        for (&value, &log_conv) in self.input_values.iter().zip(self.log_conversions.iter()) {
+            writeln!(f, "   {} → {}", Value(value), TableLog(log_conv))?;
+        }
+        writeln!(f)?;
+
+        writeln!(f, "2. Look up how many times smaller than the largest each value is:")?;
+        for (&log_conv, &ratio) in self.log_conversions.iter().zip(self.ratios.iter()) {
+            writeln!(f, "   {} - {} = {} → {}",
+                     largest_log, TableLog(log_conv), TableLog(self.largest_log - log_conv), Value(ratio))?;
+        }
+        writeln!(f)?;
+
+        writeln!(f, "3. Average the ratios, and convert to log representation:")?;
+        writeln!(f, "   ({}) ÷ {} = {} ÷ {} → {}",
+                 joined(self.ratios.iter().map(|&r| Value(r)), " + "),
+                 self.input_values.len(),
+                 Value(self.ratio_sum),
+                 self.input_values.len(),
+                 TableLog(self.average_ratio_log))?;
+        writeln!(f)?;
+
+        writeln!(f, "4. Divide the largest value by the average ratio, and convert back to final estimate:")?;
+        writeln!(f, "   {} - {} = {} → {}",
+                 largest_log,
+                 TableLog(self.average_ratio_log),
+                 TableLog(self.largest_log - self.average_ratio_log),
+                 Value(self.final_result))?;
+        writeln!(f)?;
+
+        write!(f, "Final estimation: {}", Value(self.final_result))
+    }
+}
+
+/// Table log of `sum / count`, found without dividing: the largest table entry that `count` copies of fit within `sum`
+///
+/// Comparing this way keeps averages that land exactly on a table entry, like (1 + 3) ÷ 2, from slipping below it
+/// through floating point error.
+fn average_log_representation(sum: f64, count: usize) -> i32 {
+    let tolerance = sum * 1e-9;
+    let mut scaled_log = 0;
+    while count as f64 * log_representation_to_number(scaled_log + 1) <= sum + tolerance {
+        scaled_log += 1;
+    }
+    scaled_log
+}
+
+fn reciprocal_table_approximation_steps(values: &[f64]) -> Result<ReciprocalTableSteps, HarmonicMeanError> {
+    if values.is_empty() {
+        return Err(HarmonicMeanError::EmptyInput);
+    }
+
+    for &value in values {
+        if value <= 0.0 {
+            return Err(HarmonicMeanError::NonPositiveValue);
+        }
+        if value < 1.0 {
+            return Err(HarmonicMeanError::ValueTooSmall);
+        }
+    }
+
+    let input_values = values.to_vec();
+    let log_conversions: Vec<i32> = values.iter().map(|&v| number_to_log_representation(v)).collect();
+    let largest_log = *log_conversions.iter().max().expect("values is non-empty");
+
+    let ratios: Vec<f64> = log_conversions.iter().map(|&log_conv| log_representation_to_number(largest_log - log_conv)).collect();
+    let ratio_sum: f64 = ratios.iter().sum();
+
+    // The average ratio is at most the largest ratio, so this never goes below the smallest value's log
+    let average_ratio_log = average_log_representation(ratio_sum, values.len());
+    let final_result = log_representation_to_number(largest_log - average_ratio_log);
+
+    Ok(ReciprocalTableSteps {
+        input_values,
+        log_conversions,
+        largest_log,
+        ratios,
+        ratio_sum,
+        average_ratio_log,
+        final_result,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::GeOneF64;
+    use alloc::string::ToString;
+    use quickcheck_macros::quickcheck;
+
+    #[test]
+    fn test_harmonic_mean() {
+        assert!((harmonic_mean(&[30.0, 60.0]).unwrap() - 40.0).abs() < 1e-10);
+        assert!((harmonic_mean(&[1.0, 2.0, 4.0]).unwrap() - 12.0 / 7.0).abs() < 1e-10);
+        assert_eq!(harmonic_mean(&[]), Err(HarmonicMeanError::EmptyInput));
+        assert_eq!(harmonic_mean(&[5.0, 0.0]), Err(HarmonicMeanError::NonPositiveValue));
+    }
+
+    #[test]
+    fn test_average_lands_exactly_on_table_entry() {
+        assert_eq!(average_log_representation(4.0, 2), 3);
+        assert_eq!(average_log_representation(3.0, 2), 1);
+        assert_eq!(average_log_representation(1.25 + 1.6 + 3.75, 3), 3);
+    }
+
+    #[test]
+    fn test_reciprocal_table_example() {
+        // Averaging 30 mph out and 60 mph back: the true average speed is 40 mph
+        let steps = ReciprocalTableApproximation::estimate_geometric_mean_steps(&[30.0, 60.0]).unwrap();
+        assert_eq!(steps.log_conversions, vec![15, 18]);
+        assert_eq!(steps.ratios, vec![2.0, 1.0]);
+        assert_eq!(steps.average_ratio_log, 1);
+        assert_eq!(steps.final_answer(), 50.0);
+    }
+
+    #[test]
+    fn test_equal_values_are_exact() {
+        assert_eq!(ReciprocalTableApproximation::estimate_geometric_mean(&[400.0, 400.0, 400.0]), Ok(400.0));
+    }
+
+    #[test]
+    fn test_reciprocal_table_errors() {
+        assert_eq!(ReciprocalTableApproximation::estimate_geometric_mean(&[]), Err(HarmonicMeanError::EmptyInput));
+        assert_eq!(ReciprocalTableApproximation::estimate_geometric_mean(&[-1.0]), Err(HarmonicMeanError::NonPositiveValue));
+        assert_eq!(ReciprocalTableApproximation::estimate_geometric_mean(&[0.5]), Err(HarmonicMeanError::ValueTooSmall));
+    }
+
+    #[test]
+    fn test_exact_mean_is_harmonic() {
+        assert_eq!(ReciprocalTableApproximation::exact_mean(&[30.0, 60.0]), harmonic_mean(&[30.0, 60.0]).ok());
+        assert_eq!(ExactHarmonicMean::exact_mean(&[]), None);
+    }
+
+    #[test]
+    fn test_step_by_step_display() {
+        let steps = ReciprocalTableApproximation::estimate_geometric_mean_steps(&[30.0, 60.0]).unwrap();
+        let expected = "Input values: [30, 60]\n\
+                        \n\
+                        1. Convert each value to log representation:\n   \
+                        30 → 1.5\n   \
+                        60 → 1.8\n\
+                        \n\
+                        2. Look up how many times smaller than the largest each value is:\n   \
+                        1.8 - 1.5 = 0.3 → 2\n   \
+                        1.8 - 1.8 = 0.0 → 1\n\
+                        \n\
+                        3. Average the ratios, and convert to log representation:\n   \
+                        (2 + 1) ÷ 2 = 3 ÷ 2 → 0.1\n\
+                        \n\
+                        4. Divide the largest value by the average ratio, and convert back to final estimate:\n   \
+                        1.8 - 0.1 = 1.7 → 50\n\
+                        \n\
+                        Final estimation: 50";
+        assert_eq!(steps.to_string(), expected);
+    }
+
+    #[quickcheck]
+    fn prop_estimate_between_smallest_and_largest_table_values(values: Vec<GeOneF64>) -> bool {
+        let values: Vec<f64> = values.into_iter().map(|v| v.0).collect();
+        let Ok(estimate) = ReciprocalTableApproximation::estimate_geometric_mean(&values) else {
+            return values.is_empty();
+        };
+        let logs = values.iter().map(|&v| number_to_log_representation(v));
+        let smallest = log_representation_to_number(logs.clone().min().unwrap());
+        let largest = log_representation_to_number(logs.max().unwrap());
+        smallest <= estimate && estimate <= largest
+    }
+
+    #[quickcheck]
+    fn prop_harmonic_at_most_geometric(values: Vec<GeOneF64>) -> bool {
+        let values: Vec<f64> = values.into_iter().map(|v| v.0).collect();
+        match (harmonic_mean(&values), crate::exact::geometric_mean(&values)) {
+            (Ok(harmonic), Ok(geometric)) => harmonic <= geometric * (1.0 + 1e-9),
+            _ => values.is_empty(),
+        }
+    }
+}
@@ -0,0 +1,693 @@
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs::{self, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::practice_mode::{AnswerEvaluation, PracticeModeConfig, PracticeResult};
+use crate::rng;
+use crate::traits::MethodMetadata;
+
+/// A single recorded practice round, stored as one JSON line in the history file
+///
+/// Fields added after the first release are optional or defaulted, so older history files still load.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryRecord {
+    /// Unique identifier of the round, stable across machines
+    #[serde(default)]
+    pub id: String,
+    /// Seconds since the Unix epoch at which the answer was submitted
+    pub timestamp: u64,
+    /// Seconds since the Unix epoch at which the round's session started
+    #[serde(default)]
+    pub session_started: Option<u64>,
+    pub guesses: Vec<u64>,
+    pub answer: u64,
+    /// The hidden true answer the guesses were generated around
+    #[serde(default)]
+    pub correct_answer: Option<u64>,
+    #[serde(with = "tier")]
+    pub evaluation: AnswerEvaluation,
+    pub duration: Duration,
+    pub method: String,
+    #[serde(default)]
+    pub hints_used: usize,
+    /// Practice settings the problem was generated with
+    #[serde(default)]
+    pub config: Option<RecordedConfig>,
+    /// Labels the user attached to the round's session
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Free-text note the user attached to the round's session
+    #[serde(default)]
+    pub note: Option<String>,
+    /// Name of the person practicing, for machines shared by several people
+    #[serde(default)]
+    pub profile: Option<String>,
+}
+
+/// Practice settings as stored alongside each history record
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedConfig {
+    pub team_size: usize,
+    pub log_std_dev: f64,
+    pub min_answer: u64,
+    pub max_answer: u64,
+}
+
+impl From<&PracticeModeConfig> for RecordedConfig {
+    fn from(config: &PracticeModeConfig) -> Self {
+        RecordedConfig {
+            team_size: config.team_size,
+            log_std_dev: config.log_std_dev,
+            min_answer: config.min_answer,
+            max_answer: config.max_answer,
+        }
+    }
+}
+
+/// Details shared by every round recorded during one practice session
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionContext {
+    /// Seconds since the Unix epoch at which the session started
+    pub started: u64,
+    pub config: RecordedConfig,
+    pub tags: Vec<String>,
+    pub note: Option<String>,
+    pub profile: Option<String>,
+}
+
+impl SessionContext {
+    /// Context for an untagged session that started at `started` with the given settings
+    pub fn new(started: u64, config: &PracticeModeConfig) -> Self {
+        SessionContext {
+            started,
+            config: config.into(),
+            tags: Vec::new(),
+            note: None,
+            profile: None,
+        }
+    }
+
+    /// Context for an untagged session starting now with the given settings
+    pub fn starting_now(config: &PracticeModeConfig) -> Self {
+        Self::new(unix_timestamp_now(), config)
+    }
+}
+
+/// A round with no answer and nothing recorded about it, to fill in with struct update syntax
+impl Default for HistoryRecord {
+    fn default() -> Self {
+        HistoryRecord {
+            id: String::new(),
+            timestamp: 0,
+            session_started: None,
+            guesses: Vec::new(),
+            answer: 0,
+            correct_answer: None,
+            evaluation: AnswerEvaluation::GaveUp,
+            duration: Duration::ZERO,
+            method: String::new(),
+            hints_used: 0,
+            config: None,
+            tags: Vec::new(),
+            note: None,
+            profile: None,
+        }
+    }
+}
+
+impl HistoryRecord {
+    /// Build a record from a finished practice round, assigning it a fresh round ID
+    pub fn from_result<E: MethodMetadata>(result: &PracticeResult<E>, session: &SessionContext, timestamp: u64) -> Self {
+        HistoryRecord {
+            id: new_round_id(),
+            timestamp,
+            session_started: Some(session.started),
+            guesses: result.input_values.iter().map(|&v| v as u64).collect(),
+            answer: result.user_answer,
+            correct_answer: Some(result.correct_answer),
+            evaluation: result.evaluation.clone(),
+            duration: result.duration,
+            method: E::NAME.to_string(),
+            hints_used: result.hints_used,
+            config: Some(session.config.clone()),
+            tags: session.tags.clone(),
+            note: session.note.clone(),
+            profile: session.profile.clone(),
+        }
+    }
+
+    /// Whether the round's session was tagged with `tag`, ignoring case
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+}
+
+/// Generate a round ID from the current time and a random suffix, so IDs from different machines don't collide
+fn new_round_id() -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+    format!("{:x}-{:08x}", nanos, rng::from_entropy().next_u32())
+}
+
+/// Errors that can occur while reading or writing the history file
+#[derive(Debug)]
+pub enum HistoryError {
+    Io(io::Error),
+    MalformedRecord { line: usize, message: String },
+}
+
+impl std::fmt::Display for HistoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HistoryError::Io(e) => write!(f, "History file error: {}", e),
+            HistoryError::MalformedRecord { line, message } => {
+                write!(f, "Malformed history record on line {}: {}", line, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for HistoryError {}
+
+impl From<io::Error> for HistoryError {
+    fn from(e: io::Error) -> Self {
+        HistoryError::Io(e)
+    }
+}
+
+/// Supported formats for `history export`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    Csv,
+}
+
+impl FromStr for ExportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(ExportFormat::Csv),
+            other => Err(format!("Unsupported export format: {}", other)),
+        }
+    }
+}
+
+/// Default location of the history file, inside the user's data directory
+pub fn default_history_path() -> Option<PathBuf> {
+    crate::paths::data_dir().map(|dir| dir.join("history.jsonl"))
+}
+
+/// Current time as seconds since the Unix epoch
+pub fn unix_timestamp_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Label a tier is recorded under, as used in history files and exports
+pub fn tier_label(evaluation: &AnswerEvaluation) -> &'static str {
+    tier::label(evaluation)
+}
+
+/// Append a single record to the history file, creating it if necessary
+pub fn append_record(path: &Path, record: &HistoryRecord) -> Result<(), HistoryError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let line = serde_json::to_string(record).map_err(io::Error::other)?;
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", line)?;
+    Ok(())
+}
+
+/// Load every record from the history file; a missing file is an empty history
+pub fn load_records(path: &Path) -> Result<Vec<HistoryRecord>, HistoryError> {
+    let file = match fs::File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut records = Vec::new();
+    for (index, line) in BufReader::new(file).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let record = serde_json::from_str(&line).map_err(|e| HistoryError::MalformedRecord {
+            line: index + 1,
+            message: e.to_string(),
+        })?;
+        records.push(record);
+    }
+
+    Ok(records)
+}
+
+/// Records from `incoming` that are not already in `existing`, without duplicates among themselves
+///
+/// Records are matched by round ID; records from versions that predate round IDs are matched by their full contents.
+pub fn new_records(existing: &[HistoryRecord], incoming: Vec<HistoryRecord>) -> Vec<HistoryRecord> {
+    let mut ids: HashSet<String> = existing.iter().filter(|r| !r.id.is_empty()).map(|r| r.id.clone()).collect();
+    let unidentified: Vec<&HistoryRecord> = existing.iter().filter(|r| r.id.is_empty()).collect();
+
+    let mut added: Vec<HistoryRecord> = Vec::new();
+    for record in incoming {
+        let is_new = if record.id.is_empty() {
+            !unidentified.contains(&&record) && !added.contains(&record)
+        } else {
+            ids.insert(record.id.clone())
+        };
+        if is_new {
+            added.push(record);
+        }
+    }
+    added
+}
+
+/// Merge the records of another history file into the one at `path`, returning how many were added
+pub fn import(path: &Path, source: &Path) -> Result<usize, HistoryError> {
+    // Unlike the user's own history, a missing file to import is an error
+    fs::metadata(source)?;
+    let incoming = load_records(source)?;
+    let added = new_records(&load_records(path)?, incoming);
+
+    for record in &added {
+        append_record(path, record)?;
+    }
+    Ok(added.len())
+}
+
+/// Which records `history clear` deletes; with no criteria set, every record matches
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ClearFilter {
+    /// Only records answered before this many seconds since the Unix epoch
+    pub before: Option<u64>,
+    /// Only records from this profile
+    pub profile: Option<String>,
+}
+
+impl ClearFilter {
+    pub fn matches(&self, record: &HistoryRecord) -> bool {
+        let early = self.before.is_none_or(|before| record.timestamp < before);
+        let profiled = self.profile.is_none() || record.profile == self.profile;
+        early && profiled
+    }
+}
+
+/// Delete the records matching `filter` from the history file, returning how many were deleted
+///
+/// The remaining records are written to a temporary file that then replaces the original,
+/// so an interrupted clear never leaves a partially written history.
+pub fn clear(path: &Path, filter: &ClearFilter) -> Result<usize, HistoryError> {
+    let records = load_records(path)?;
+    let (deleted, kept): (Vec<_>, Vec<_>) = records.into_iter().partition(|r| filter.matches(r));
+    if deleted.is_empty() {
+        return Ok(0);
+    }
+
+    let temporary = path.with_extension("jsonl.tmp");
+    let mut file = fs::File::create(&temporary)?;
+    for record in &kept {
+        let line = serde_json::to_string(record).map_err(io::Error::other)?;
+        writeln!(file, "{}", line)?;
+    }
+    file.sync_all()?;
+    fs::rename(&temporary, path)?;
+
+    Ok(deleted.len())
+}
+
+/// Write records to `out` in the requested format
+pub fn export<W: Write>(records: &[HistoryRecord], format: ExportFormat, out: &mut W) -> io::Result<()> {
+    match format {
+        ExportFormat::Csv => export_csv(records, out),
+    }
+}
+
+fn export_csv<W: Write>(records: &[HistoryRecord], out: &mut W) -> io::Result<()> {
+    writeln!(out, "timestamp,guesses,answer,tier,duration_seconds,method,hints")?;
+
+    for record in records {
+        let guesses: Vec<String> = record.guesses.iter().map(|g| g.to_string()).collect();
+        writeln!(
+            out,
+            "{},{},{},{},{:.3},{},{}",
+            format_timestamp(record.timestamp),
+            guesses.join(";"),
+            record.answer,
+            tier::label(&record.evaluation),
+            record.duration.as_secs_f64(),
+            record.method,
+            record.hints_used,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Format seconds since the Unix epoch as an RFC 3339 UTC timestamp
+pub fn format_timestamp(timestamp: u64) -> String {
+    let (year, month, day) = civil_from_days(timestamp / 86_400);
+    let seconds_of_day = timestamp % 86_400;
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        seconds_of_day / 3600,
+        (seconds_of_day % 3600) / 60,
+        seconds_of_day % 60,
+    )
+}
+
+/// Format seconds since the Unix epoch as a UTC calendar date
+pub fn format_date(timestamp: u64) -> String {
+    let (year, month, day) = civil_from_days(timestamp / 86_400);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Parse a `YYYY-MM-DD` calendar date as seconds since the Unix epoch at its start (UTC)
+pub fn parse_date(date: &str) -> Option<u64> {
+    let mut parts = date.split('-');
+    let year: u64 = parts.next()?.parse().ok()?;
+    let month: u64 = parts.next()?.parse().ok()?;
+    let day: u64 = parts.next()?.parse().ok()?;
+    if parts.next().is_some() || year < 1970 || !(1..=12).contains(&month) || day == 0 {
+        return None;
+    }
+
+    let days = days_from_civil(year, month, day);
+    // Reject days past the end of the month, which roll over into the next one
+    (civil_from_days(days) == (year, month, day)).then_some(days * 86_400)
+}
+
+/// Convert a (year, month, day) civil date to days since the Unix epoch, the inverse of `civil_from_days`
+fn days_from_civil(year: u64, month: u64, day: u64) -> u64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let shifted_month = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * shifted_month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Convert days since the Unix epoch to a (year, month, day) civil date
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let day_of_era = z - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Stable lowercase names for answer tiers, used in the history file and exports
+mod tier {
+    use super::AnswerEvaluation;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn label(evaluation: &AnswerEvaluation) -> &'static str {
+        match evaluation {
+            AnswerEvaluation::Correct => "correct",
+            AnswerEvaluation::Excellent => "excellent",
+            AnswerEvaluation::Incorrect => "incorrect",
+            AnswerEvaluation::GaveUp => "gave-up",
+        }
+    }
+
+    pub fn serialize<S: Serializer>(evaluation: &AnswerEvaluation, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(label(evaluation))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<AnswerEvaluation, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        match name.as_str() {
+            "correct" => Ok(AnswerEvaluation::Correct),
+            "excellent" => Ok(AnswerEvaluation::Excellent),
+            "incorrect" => Ok(AnswerEvaluation::Incorrect),
+            "gave-up" => Ok(AnswerEvaluation::GaveUp),
+            other => Err(serde::de::Error::custom(format!("unknown tier: {}", other))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table_based::TableBasedApproximation;
+    use std::marker::PhantomData;
+
+    fn sample_record() -> HistoryRecord {
+        HistoryRecord {
+            id: "round-1".to_string(),
+            timestamp: 1_760_000_000,
+            session_started: Some(1_759_999_900),
+            guesses: vec![150, 2500, 800, 45],
+            answer: 400,
+            correct_answer: Some(500),
+            evaluation: AnswerEvaluation::Correct,
+            duration: Duration::from_millis(12300),
+            method: "table-based".to_string(),
+            hints_used: 0,
+            config: Some(RecordedConfig { team_size: 4, log_std_dev: 4.0, min_answer: 10, max_answer: 1_000_000_000 }),
+            tags: vec!["warmup".to_string()],
+            note: Some("trying log-linear".to_string()),
+            profile: None,
+        }
+    }
+
+    fn temp_history_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("geomean-history-test-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join("history.jsonl")
+    }
+
+    #[test]
+    fn test_record_from_result() {
+        let result = PracticeResult {
+            user_answer: 420,
+            exact_mean: 387.4,
+            estimation_result: 400,
+            duration: Duration::from_millis(12300),
+            evaluation: AnswerEvaluation::Excellent,
+            input_values: vec![25.0, 400.0],
+            correct_answer: 400,
+            hints_used: 0,
+            estimation_method: PhantomData::<TableBasedApproximation>,
+        };
+
+        let config = PracticeModeConfig::new(2, 1.0, 10, 1000).unwrap();
+        let mut session = SessionContext::new(30, &config);
+        session.tags = vec!["Pre-Quiz".to_string()];
+        session.note = Some("before the pub quiz".to_string());
+        let record = HistoryRecord::from_result(&result, &session, 42);
+
+        assert!(!record.id.is_empty());
+        assert_eq!(record.timestamp, 42);
+        assert_eq!(record.session_started, Some(30));
+        assert_eq!(record.correct_answer, Some(400));
+        assert_eq!(record.config, Some(RecordedConfig { team_size: 2, log_std_dev: 1.0, min_answer: 10, max_answer: 1000 }));
+        assert_eq!(record.guesses, vec![25, 400]);
+        assert_eq!(record.answer, 420);
+        assert_eq!(record.evaluation, AnswerEvaluation::Excellent);
+        assert_eq!(record.duration, Duration::from_millis(12300));
+        assert_eq!(record.method, "table-based");
+        assert_eq!(record.note.as_deref(), Some("before the pub quiz"));
+        assert!(record.has_tag("pre-quiz"));
+        assert!(!record.has_tag("warmup"));
+
+        let another = HistoryRecord::from_result(&result, &session, 42);
+        assert_ne!(record.id, another.id);
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29T00:00:00Z");
+        assert_eq!(format_timestamp(1_760_000_000), "2025-10-09T08:53:20Z");
+        assert_eq!(format_date(1_760_000_000), "2025-10-09");
+    }
+
+    #[test]
+    fn test_parse_date() {
+        assert_eq!(parse_date("1970-01-01"), Some(0));
+        assert_eq!(parse_date("2000-02-29"), Some(951_782_400));
+        assert_eq!(parse_date("2025-10-09"), Some(1_759_968_000));
+        assert_eq!(parse_date("2025-02-29"), None);
+        assert_eq!(parse_date("2025-13-01"), None);
+        assert_eq!(parse_date("2025-10"), None);
+        assert_eq!(parse_date("2025-10-09-01"), None);
+        assert_eq!(parse_date("yesterday"), None);
+    }
+
+    #[test]
+    fn test_clear_filter() {
+        let mut alice = sample_record();
+        alice.profile = Some("alice".to_string());
+
+        assert!(ClearFilter::default().matches(&sample_record()));
+
+        let before = ClearFilter { before: Some(1_760_000_001), profile: None };
+        assert!(before.matches(&sample_record()));
+        assert!(!ClearFilter { before: Some(1_760_000_000), profile: None }.matches(&sample_record()));
+
+        let profile = ClearFilter { before: None, profile: Some("alice".to_string()) };
+        assert!(profile.matches(&alice));
+        assert!(!profile.matches(&sample_record()));
+    }
+
+    #[test]
+    fn test_clear_keeps_unmatched_records() {
+        let path = temp_history_path("clear");
+        let mut alice = sample_record();
+        alice.id = "round-2".to_string();
+        alice.profile = Some("alice".to_string());
+        append_record(&path, &sample_record()).unwrap();
+        append_record(&path, &alice).unwrap();
+
+        let filter = ClearFilter { before: None, profile: Some("alice".to_string()) };
+        assert_eq!(clear(&path, &filter).unwrap(), 1);
+        assert_eq!(clear(&path, &filter).unwrap(), 0);
+        assert_eq!(load_records(&path).unwrap(), vec![sample_record()]);
+
+        assert_eq!(clear(&path, &ClearFilter::default()).unwrap(), 1);
+        assert_eq!(load_records(&path).unwrap(), Vec::new());
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_export_format_parsing() {
+        assert_eq!("csv".parse::<ExportFormat>(), Ok(ExportFormat::Csv));
+        assert!("xml".parse::<ExportFormat>().is_err());
+    }
+
+    #[test]
+    fn test_export_csv() {
+        let mut incorrect = sample_record();
+        incorrect.evaluation = AnswerEvaluation::Incorrect;
+        incorrect.answer = 2000;
+
+        let mut out = Vec::new();
+        export(&[sample_record(), incorrect], ExportFormat::Csv, &mut out).unwrap();
+
+        let expected = "timestamp,guesses,answer,tier,duration_seconds,method,hints\n\
+                        2025-10-09T08:53:20Z,150;2500;800;45,400,correct,12.300,table-based,0\n\
+                        2025-10-09T08:53:20Z,150;2500;800;45,2000,incorrect,12.300,table-based,0\n";
+        assert_eq!(String::from_utf8(out).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_append_and_load_round_trip() {
+        let path = temp_history_path("round-trip");
+
+        let first = sample_record();
+        let mut second = sample_record();
+        second.evaluation = AnswerEvaluation::Excellent;
+
+        append_record(&path, &first).unwrap();
+        append_record(&path, &second).unwrap();
+
+        assert_eq!(load_records(&path).unwrap(), vec![first, second]);
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_records_from_older_versions_load_with_defaults() {
+        let line = "{\"timestamp\":0,\"guesses\":[10],\"answer\":10,\"evaluation\":\"gave-up\",\"duration\":{\"secs\":1,\"nanos\":0},\"method\":\"table-based\"}";
+        let record: HistoryRecord = serde_json::from_str(line).unwrap();
+        assert_eq!(record.hints_used, 0);
+        assert_eq!(record.evaluation, AnswerEvaluation::GaveUp);
+        assert_eq!(record.id, "");
+        assert_eq!(record.session_started, None);
+        assert_eq!(record.correct_answer, None);
+        assert_eq!(record.config, None);
+        assert!(record.tags.is_empty());
+        assert_eq!(record.note, None);
+        assert_eq!(record.profile, None);
+    }
+
+    #[test]
+    fn test_new_records_deduplicates_by_id() {
+        let existing = vec![sample_record()];
+        let mut other = sample_record();
+        other.id = "round-2".to_string();
+
+        let added = new_records(&existing, vec![sample_record(), other.clone(), other.clone()]);
+
+        assert_eq!(added, vec![other]);
+    }
+
+    #[test]
+    fn test_new_records_matches_records_without_ids_by_contents() {
+        let mut legacy = sample_record();
+        legacy.id = String::new();
+        let mut different = legacy.clone();
+        different.answer = 2000;
+
+        let added = new_records(&[legacy.clone()], vec![legacy, different.clone(), different.clone()]);
+
+        assert_eq!(added, vec![different]);
+    }
+
+    #[test]
+    fn test_import_appends_only_new_records() {
+        let path = temp_history_path("import-target");
+        let source = temp_history_path("import-source");
+
+        let mut other = sample_record();
+        other.id = "round-2".to_string();
+        append_record(&path, &sample_record()).unwrap();
+        append_record(&source, &sample_record()).unwrap();
+        append_record(&source, &other).unwrap();
+
+        assert_eq!(import(&path, &source).unwrap(), 1);
+        assert_eq!(import(&path, &source).unwrap(), 0);
+        assert_eq!(load_records(&path).unwrap(), vec![sample_record(), other]);
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+        fs::remove_dir_all(source.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_import_missing_source_is_an_error() {
+        let path = temp_history_path("import-missing-target");
+        let source = temp_history_path("import-missing-source");
+        assert!(matches!(import(&path, &source), Err(HistoryError::Io(_))));
+    }
+
+    #[test]
+    fn test_load_missing_file_is_empty() {
+        let path = temp_history_path("missing");
+        assert_eq!(load_records(&path).unwrap(), Vec::new());
+    }
+
+    #[test]
+    fn test_load_malformed_record_reports_line() {
+        let path = temp_history_path("malformed");
+        append_record(&path, &sample_record()).unwrap();
+        fs::OpenOptions::new().append(true).open(&path).unwrap().write_all(b"not json\n").unwrap();
+
+        match load_records(&path) {
+            Err(HistoryError::MalformedRecord { line, .. }) => assert_eq!(line, 2),
+            other => panic!("Expected malformed record error, got {:?}", other),
+        }
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
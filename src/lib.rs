@@ -0,0 +1,91 @@
+//! Pen-and-paper methods for estimating the geometric mean, along with the exact calculation they approximate.
+//!
+//! The estimators implement the traits in [`traits`], and can be compared with the [`evaluation`] harness.
+//! [`harmonic`] has the same treatment for the harmonic mean, which rate and speed questions call for, and [`median`]
+//! for the median and quartiles, the simplest competing aggregation strategy.
+//! [`shortcuts`] works the geometric mean as rounds of paper square roots when a team has two, four, or eight guesses.
+//! [`trivia_guess`] models the guesses a trivia team makes, which the [`practice_mode`] engine turns into problems to solve by hand.
+//! Those three, along with calibration, the sub-skill [`drills`], [`fermi`] problems, [`spread`] estimation, and guesses given as [`ranges`] or [`weighted`] by confidence, generate random problems and need the default `rand` feature,
+//! as does the [`simulation`] of whole games between teams that aggregate their guesses differently, including
+//! [`bayesian`] teams that weigh their guesses against the typical answer.
+//! With the `serde` feature, on by default, public types are serializable, and the remaining modules persist practice between runs:
+//! settings, history, statistics, and missed problems to review, along with banks of real trivia [`questions`].
+//!
+//! The `pdf` feature adds a `pdf` writer, so the CLI can print worksheets and reference cards to hand out, and the
+//! `charts` feature adds SVG and PNG `charts` of how the methods' errors vary.
+//!
+//! Without the default `std` feature, only the estimators and their traits are built, as a `no_std` crate using `libm`
+//! (enable the `libm` feature) for floating point math, so they can run on embedded hardware or WASM.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("building without the `std` feature requires the `libm` feature for floating point math");
+
+extern crate alloc;
+
+mod math;
+mod display;
+
+pub mod exact;
+pub mod log_linear;
+pub mod table_based;
+pub mod harmonic;
+pub mod median;
+pub mod shortcuts;
+pub mod traits;
+pub mod methods;
+#[cfg(feature = "rand")]
+pub mod rng;
+#[cfg(feature = "rand")]
+pub mod evaluation;
+#[cfg(feature = "rand")]
+pub mod trivia_guess;
+#[cfg(feature = "rand")]
+pub mod practice_mode;
+#[cfg(feature = "serde")]
+pub mod history;
+#[cfg(feature = "std")]
+pub mod paths;
+#[cfg(feature = "std")]
+pub mod bayesian;
+#[cfg(feature = "serde")]
+pub mod config;
+#[cfg(feature = "rand")]
+pub mod calibration;
+#[cfg(feature = "rand")]
+pub mod advisor;
+#[cfg(feature = "rand")]
+pub mod error_budget;
+#[cfg(feature = "rand")]
+pub mod doctor;
+#[cfg(feature = "rand")]
+pub mod drills;
+#[cfg(feature = "rand")]
+pub mod fermi;
+#[cfg(feature = "rand")]
+pub mod tutorial;
+#[cfg(feature = "rand")]
+pub mod ranges;
+#[cfg(feature = "rand")]
+pub mod simulation;
+#[cfg(feature = "rand")]
+pub mod spread;
+#[cfg(feature = "rand")]
+pub mod weighted;
+#[cfg(feature = "serde")]
+pub mod stats;
+#[cfg(feature = "serde")]
+pub mod review;
+#[cfg(feature = "serde")]
+pub mod questions;
+#[cfg(feature = "pdf")]
+pub mod pdf;
+#[cfg(feature = "charts")]
+pub mod charts;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(any(test, feature = "test_support"))]
+pub mod test_support;
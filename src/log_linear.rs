@@ -1,12 +1,18 @@
+use alloc::vec::Vec;
+
+use crate::display::{Decimal, joined};
+use crate::math;
+
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GeometricMeanError {
     EmptyInput,
     NonPositiveValue,
     ValueTooSmall,
 }
 
-impl std::fmt::Display for GeometricMeanError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for GeometricMeanError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             GeometricMeanError::EmptyInput => write!(f, "Cannot calculate geometric mean of empty input"),
             GeometricMeanError::NonPositiveValue => write!(f, "Geometric mean requires all positive values"),
@@ -15,10 +21,41 @@ impl std::fmt::Display for GeometricMeanError {
     }
 }
 
-impl std::error::Error for GeometricMeanError {}
+impl core::error::Error for GeometricMeanError {}
 
 pub struct LogLinearApproximation;
 
+impl crate::traits::MethodMetadata for LogLinearApproximation {
+    const NAME: &'static str = "log-linear";
+    const DESCRIPTION: &'static str = "Writes each value as its digit count, then its digits after the point, so 300 becomes 3.3. \
+These are averaged, and the average is read back the same way, using .1 when its decimals are below .1. \
+Nothing needs memorizing, but treating the digits as linear between magnitudes skews the answer.";
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LogLinearSteps {
+    input_values: Vec<f64>,
+    log_conversions: Vec<f64>,
+    sum: f64,
+    average: f64,
+    final_result: f64,
+}
+
+impl crate::traits::FinalAnswer for LogLinearSteps {
+    fn final_answer(&self) -> f64 {
+        self.final_result
+    }
+}
+
+impl crate::traits::EstimateGeometricMeanStepByStep for LogLinearApproximation {
+    type StepByStep = LogLinearSteps;
+    type Error = GeometricMeanError;
+
+    fn estimate_geometric_mean_steps(values: &[f64]) -> Result<Self::StepByStep, Self::Error> {
+        log_linear_approximation_steps(values)
+    }
+}
+
 impl crate::traits::EstimateGeometricMean for LogLinearApproximation {
     type Error = GeometricMeanError;
 
@@ -27,19 +64,51 @@ impl crate::traits::EstimateGeometricMean for LogLinearApproximation {
     }
 }
 
+impl core::fmt::Display for LogLinearSteps {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        writeln!(f, "Input values: [{}]", joined(self.input_values.iter().map(|&v| Decimal(v)), ", "))?;
+        writeln!(f)?;
+
+        writeln!(f, "1. Convert each value to digit count, then the digits after the point:")?;
+        for (&value, &log_conv) in self.input_values.iter().zip(self.log_conversions.iter()) {
+            writeln!(f, "   {} → {}", Decimal(value), Decimal(log_conv))?;
+        }
+        writeln!(f)?;
+
+        writeln!(f, "2. Calculate average of log-linear values:")?;
+        writeln!(f, "   ({}) ÷ {} = {} ÷ {} = {}",
+                 joined(self.log_conversions.iter().map(|&log_conv| Decimal(log_conv)), " + "),
+                 self.input_values.len(),
+                 Decimal(self.sum),
+                 self.input_values.len(),
+                 Decimal(self.average))?;
+        writeln!(f)?;
+
+        writeln!(f, "3. Convert back to final estimate:")?;
+        let fractional_part = self.average - math::floor(self.average);
+        if fractional_part < 0.1 {
+            writeln!(f, "   {} has decimals below .1, so use {}.1 instead", Decimal(self.average), math::floor(self.average))?;
+        }
+        writeln!(f, "   {} → {}", Decimal(self.average), Decimal(self.final_result))?;
+        writeln!(f)?;
+
+        write!(f, "Final estimation: {}", Decimal(self.final_result))
+    }
+}
+
 /// Converts a number to log-linear format: digit_count.remaining_digits
 /// Example: 2847 -> 4.2847, 300 -> 3.3, 70 -> 2.7
-fn convert_to_log_linear(value: f64) -> f64 {
-    let digit_count = (value.log10().floor() as i32) + 1;
-    let fractional_part = value / 10.0_f64.powi(digit_count);
+pub fn convert_to_log_linear(value: f64) -> f64 {
+    let digit_count = (math::floor(math::log10(value)) as i32) + 1;
+    let fractional_part = value / math::powi(10.0, digit_count);
     digit_count as f64 + fractional_part
 }
 
 /// Converts from log-linear format back to a number
 /// Example: 3.75 -> 750, 4.1 -> 1000
 /// Handles edge case: if fractional part < 0.1, treat as 0.1
-fn convert_from_log_linear(log_value: f64) -> f64 {
-    let digit_count = log_value.floor() as i32;
+pub fn convert_from_log_linear(log_value: f64) -> f64 {
+    let digit_count = math::floor(log_value) as i32;
     let mut fractional_part = log_value - digit_count as f64;
 
     // Edge case: if fractional part is too small, use 0.1
@@ -47,13 +116,22 @@ fn convert_from_log_linear(log_value: f64) -> f64 {
         fractional_part = 0.1;
     }
 
-    fractional_part * 10.0_f64.powi(digit_count)
+    fractional_part * math::powi(10.0, digit_count)
 }
 
 /// Approximates geometric mean using log-linear interpolation method
 /// This pen-and-paper method converts each value to digit_count.fractional format,
 /// averages them arithmetically, then converts back to get the final estimate
 fn log_linear_approximation(values: &[f64]) -> Result<f64, GeometricMeanError> {
+    validate(values)?;
+
+    // Summed without keeping the conversions, as evaluation calls this millions of times
+    let sum: f64 = values.iter().map(|&v| convert_to_log_linear(v)).sum();
+    Ok(convert_from_log_linear(sum / values.len() as f64))
+}
+
+/// Both the direct and step-by-step paths accept values of at least 1
+fn validate(values: &[f64]) -> Result<(), GeometricMeanError> {
     if values.is_empty() {
         return Err(GeometricMeanError::EmptyInput);
     }
@@ -67,14 +145,27 @@ fn log_linear_approximation(values: &[f64]) -> Result<f64, GeometricMeanError> {
         }
     }
 
+    Ok(())
+}
+
+fn log_linear_approximation_steps(values: &[f64]) -> Result<LogLinearSteps, GeometricMeanError> {
+    validate(values)?;
+
     // Calculate arithmetic mean of log-linear values
-    let sum: f64 = values.iter()
+    let log_conversions: Vec<f64> = values.iter()
         .map(|&v| convert_to_log_linear(v))
-        .sum();
+        .collect();
+    let sum: f64 = log_conversions.iter().sum();
     let average = sum / values.len() as f64;
 
     // Convert back to final estimate
-    Ok(convert_from_log_linear(average))
+    Ok(LogLinearSteps {
+        input_values: values.to_vec(),
+        log_conversions,
+        sum,
+        average,
+        final_result: convert_from_log_linear(average),
+    })
 }
 
 #[cfg(test)]
@@ -197,54 +288,36 @@ mod tests {
         assert!(result > expected / 10.0 && result < expected * 10.0);
     }
 
+    #[test]
+    fn test_log_linear_steps_readme_example() {
+        use crate::traits::{EstimateGeometricMeanStepByStep, FinalAnswer};
+        let steps = LogLinearApproximation::estimate_geometric_mean_steps(&[300.0, 10000.0, 900.0, 70.0]).unwrap();
+        assert!((steps.final_answer() - 750.0).abs() < 1e-8);
+
+        let display = steps.to_string();
+        assert!(display.contains("   10000 → 5.1\n"), "{}", display);
+        assert!(display.contains("   (3.3 + 5.1 + 3.9 + 2.7) ÷ 4 = 15 ÷ 4 = 3.75\n"), "{}", display);
+        assert!(display.ends_with("   3.75 → 750\n\nFinal estimation: 750"), "{}", display);
+    }
+
+    #[test]
+    fn test_log_linear_steps_edge_case() {
+        use crate::traits::EstimateGeometricMeanStepByStep;
+        let steps = LogLinearApproximation::estimate_geometric_mean_steps(&[80.0, 80.0, 80.0, 800.0]).unwrap();
+        let display = steps.to_string();
+        assert!(display.contains("   3.05 has decimals below .1, so use 3.1 instead\n   3.05 → 100\n"), "{}", display);
+    }
+
     mod property_tests {
         use super::*;
         use crate::exact::geometric_mean;
         use crate::traits::EstimateGeometricMean;
-        use quickcheck::{Arbitrary, Gen, TestResult};
+        use crate::test_support::SameDigitCount;
+        use quickcheck::TestResult;
         use quickcheck_macros::quickcheck;
 
-        #[derive(Clone, Debug)]
-        struct GeOneF64(f64);
-
-        impl Arbitrary for GeOneF64 {
-            fn arbitrary(g: &mut Gen) -> Self {
-                let value = loop {
-                    let candidate = f64::arbitrary(g).abs();
-                    if candidate >= 1.0 && candidate.is_finite() && candidate < 1e50 {
-                        break candidate;
-                    }
-                };
-                GeOneF64(value)
-            }
-        }
-
-        #[derive(Clone, Debug)]
-        struct SameDigitCount(Vec<f64>);
-
-        impl Arbitrary for SameDigitCount {
-            fn arbitrary(g: &mut Gen) -> Self {
-                let digit_count = (u8::arbitrary(g) % 6) + 1; // 1-6 digits
-                let base = 10.0_f64.powi(digit_count as i32 - 1);
-                let upper = base * 10.0;
-
-                let size = (usize::arbitrary(g) % 8) + 1; // 1-8 values
-                let values: Vec<f64> = (0..size)
-                    .map(|_| {
-                        let fraction = loop {
-                            let candidate = f64::arbitrary(g).abs() % 1.0;
-                            if candidate.is_finite() {
-                                break candidate;
-                            }
-                        };
-                        base + fraction * (upper - base)
-                    })
-                    .collect();
-
-                SameDigitCount(values)
-            }
-        }
-
+        /// Log-linear stays within an order of magnitude far beyond the range of u64 answers
+        type GeOneF64 = crate::test_support::GeOneF64<50>;
 
         #[quickcheck]
         fn prop_order_of_magnitude_correctness(values: Vec<GeOneF64>) -> TestResult {
@@ -1,85 +1,137 @@
-mod exact;
-mod log_linear;
-mod table_based;
-mod traits;
-mod evaluation;
-mod trivia_guess;
-mod practice_mode;
 mod cli;
 
-use rand::SeedableRng;
-use rand::rngs::StdRng;
-
-use crate::evaluation::evaluate_estimate;
-use crate::exact::ExactGeometricMean;
-use crate::log_linear::LogLinearApproximation;
-use crate::table_based::TableBasedApproximation;
-
-fn compare() {
-    println!("Pen and Paper Geometric Mean Comparison");
-    println!("======================================");
-
-    let mut rng = StdRng::seed_from_u64(42);
-    let num_tests = 10000;
-    let min_value = 1.0;
-    let max_value = 100000.0;
-
-    println!("Testing {} random cases with values from {} to {}", num_tests, min_value, max_value);
-    println!();
-
-    // Exact method (baseline)
-    let exact_results = evaluate_estimate::<_, ExactGeometricMean>(&mut rng, min_value, max_value, num_tests);
-    println!("Exact Method:");
-    println!("  Mean Absolute Relative Error: {:.6e}", exact_results.mean_absolute_relative_error);
-    println!("  Worst Case Error: {:.6e}", exact_results.worst_case_error);
-    println!("  Worst Case Overestimate: {:.6e}", exact_results.worst_case_overestimate);
-    println!("  Overall Bias: {:.6e}", exact_results.overall_bias);
-    println!("  Valid Tests: {}", exact_results.total_tests);
-    println!();
-
-    // Log-linear approximation
-    let mut rng = StdRng::seed_from_u64(42); // Reset with same seed for fair comparison
-    let log_linear_results = evaluate_estimate::<_, LogLinearApproximation>(&mut rng, min_value, max_value, num_tests);
-    println!("Log-Linear Interpolation:");
-    println!("  Mean Absolute Relative Error: {:.6e}", log_linear_results.mean_absolute_relative_error);
-    println!("  Worst Case Error: {:.6e}", log_linear_results.worst_case_error);
-    println!("  Worst Case Overestimate: {:.6e}", log_linear_results.worst_case_overestimate);
-    println!("  Overall Bias: {:.6e}", log_linear_results.overall_bias);
-    println!("  Valid Tests: {}", log_linear_results.total_tests);
-    println!();
-
-    // Table-based approximation
-    let mut rng = StdRng::seed_from_u64(42); // Reset with same seed for fair comparison
-    let table_results = evaluate_estimate::<_, TableBasedApproximation>(&mut rng, min_value, max_value, num_tests);
-    println!("Table-Based Approximation:");
-    println!("  Mean Absolute Relative Error: {:.6e}", table_results.mean_absolute_relative_error);
-    println!("  Worst Case Error: {:.6e}", table_results.worst_case_error);
-    println!("  Worst Case Overestimate: {:.6e}", table_results.worst_case_overestimate);
-    println!("  Overall Bias: {:.6e}", table_results.overall_bias);
-    println!("  Valid Tests: {}", table_results.total_tests);
-    println!();
-
-    println!("Comparison Summary:");
-    println!("  Log-Linear vs Exact: {:.2}x worse", log_linear_results.mean_absolute_relative_error / exact_results.mean_absolute_relative_error);
-    println!("  Table-Based vs Exact: {:.2}x worse", table_results.mean_absolute_relative_error / exact_results.mean_absolute_relative_error);
-    println!("  Table-Based vs Log-Linear: {:.2}x", table_results.mean_absolute_relative_error / log_linear_results.mean_absolute_relative_error);
-}
-
 fn main() {
     let args: Vec<String> = std::env::args().collect();
 
     match args.get(1).map(|s| s.as_str()) {
         Some("practice") => {
-            cli::practice_mode::run_practice_mode();
+            match cli::practice_mode::PracticeOptions::parse(&args[2..]) {
+                Ok(options) if options.frontend_protocol => cli::frontend::run_frontend_protocol(options),
+                Ok(options) => cli::practice_mode::run_practice_mode(options),
+                Err(e) => println!("{}", e),
+            }
+        }
+        Some("tutorial") => {
+            cli::tutorial::run_tutorial(&args[2..]);
+        }
+        Some("calibrate") => {
+            cli::calibrate::run_calibrate();
+        }
+        Some("history") => {
+            cli::history::run_history(&args[2..]);
+        }
+        Some("stats") => {
+            cli::stats::run_stats(&args[2..]);
+        }
+        Some("drill") => {
+            cli::drill::run_drill(&args[2..]);
+        }
+        Some("advise") => {
+            cli::advise::run_advise(&args[2..]);
+        }
+        Some("budget") => {
+            cli::budget::run_budget(&args[2..]);
+        }
+        Some("doctor") => {
+            cli::doctor::run_doctor();
+        }
+        Some("bench") => {
+            cli::bench::run_bench(&args[2..]);
+        }
+        Some("explain") => {
+            cli::explain::run_explain(&args[2..]);
+        }
+        Some("cheat-sheet") => {
+            cli::cheat_sheet::run_cheat_sheet(&args[2..]);
+        }
+        Some("worksheet") => {
+            cli::worksheet::run_worksheet(&args[2..]);
+        }
+        Some("simulate") => {
+            cli::simulate::run_simulate(&args[2..]);
+        }
+        Some("duel") => {
+            cli::simulate::run_duel(&args[2..]);
+        }
+        Some("questions") => {
+            cli::questions::run_questions(&args[2..]);
+        }
+        Some("snapshots") => {
+            cli::snapshots::run_snapshots(&args[2..]);
+        }
+        Some("--json") => {
+            cli::compare::run_compare_json();
+        }
+        Some("--corpus") => {
+            cli::compare::run_compare_corpus(&args[2..]);
+        }
+        Some("--charts") => {
+            cli::compare::run_compare_charts(&args[2..]);
         }
         Some(arg) => {
             println!("Unknown argument: {}", arg);
             println!("Usage:");
-            println!("  cargo run          - Run comparison analysis");
-            println!("  cargo run practice - Enter practice mode");
+            println!("  cargo run           - Run comparison analysis");
+            println!("  cargo run -- --json - Run comparison analysis, printing versioned JSON");
+            println!("  cargo run -- --corpus <path> - Run comparison analysis on a file of test cases, one per line, streamed from disk");
+            println!("  cargo run --features charts -- --charts <dir> - Write SVG and PNG charts of error by magnitude, distribution, and team size");
+            println!("  cargo run tutorial  - Learn the table method step by step; practice unlocks when you finish (--skip to unlock now)");
+            println!("  cargo run practice  - Enter practice mode");
+            println!("  cargo run calibrate - Find and save a recommended practice difficulty");
+            println!("  cargo run history   - Export, import, or clear recorded practice history");
+            println!("  cargo run stats     - Show weekly accuracy and solve time trends");
+            println!("  cargo run drill <kind>         - Drill a sub-skill: arithmetic-mean, percent-error, factor-error, log10, square-root, cube-root, power, fermi, decimal-sum, small-division");
+            println!("  cargo run advise [options]     - Recommend the cheapest method accurate enough for --team-size <n> and --uncertainty <low|medium|high|extreme>");
+            println!("  cargo run budget [options]     - Show which method and table size stay within --target <percent> for --team-size and --uncertainty");
+            println!("  cargo run cheat-sheet [method] - Print a reference card for log-linear or table-based; --pdf <path> writes it to a PDF");
+            println!("  cargo run doctor               - Run internal consistency checks, e.g. after building on a new platform");
+            println!("  cargo run --release bench [options] - Time the hot paths; --save-baseline stores them, --compare-baseline fails on regressions");
+            println!("  cargo run explain <method>     - Describe a method and work through examples with its own steps");
+            println!("  cargo run worksheet [options]  - Print practice problems and an answer key; --bank <path> draws them from a question bank; --pdf <path> or --latex <path> [--standalone] writes them to a file");
+            println!("  cargo run questions import <csv> <bank> - Add a CSV of questions and answers to a TOML or JSON question bank");
+            println!("  cargo run snapshots [dir]      - Regenerate the golden fixtures for the human-facing formats");
+            println!("  cargo run simulate [options]   - Simulate trivia games between aggregation strategies");
+            println!("  cargo run duel <first> <second> - Duel two strategies on closest-answer questions, with 95% intervals");
+            println!();
+            println!("Practice options:");
+            println!("  --show-work         - Show the worked solution after every round");
+            println!("  --transcript <path> - Record the session as a replayable JSON-lines transcript");
+            println!("  --time-limit <secs> - Show a live countdown while answering each problem");
+            println!("  --time-precision <n> - Show solve times with n decimal places, from 0 to 3 (default 1)");
+            println!("  --warm-up <n>       - Answer n quick sums and divisions before the first problem (0 to skip)");
+            println!("  --bell              - Ring the terminal bell: once Correct, twice Excellent, thrice Incorrect");
+            println!("  --review            - Re-serve missed problems until each is answered correctly twice");
+            println!("  --tag <label>       - Tag the session in history (repeatable); filter with stats --tag");
+            println!("  --note <text>       - Attach a note to the session in history; filter with stats --note");
+            println!("  --profile <name>    - Record the session under a profile, for shared machines");
+            println!("  --no-history        - Don't record this session to history or the review bank");
+            println!("  --frontend-protocol - Read commands and write events as JSON lines on stdio, for GUI frontends");
+            println!("  --method <name>     - Practice table-based, reciprocal-table, median, lower-quartile, or upper-quartile");
+            println!("  --harmonic          - Practice the harmonic mean, for rate and speed questions (reciprocal-table)");
+            println!("  --bank <path>       - Pose questions from a TOML or JSON question bank, guessing around their true answers");
+            println!("  --ranges            - Give each teammate a low-high range instead of a guess, combined by the geometric mean of every endpoint");
+            println!("  --weighted          - Give each guess a confidence from 1 to 5, and practice the confidence-weighted geometric mean");
+            println!("  --spread            - After each answer, also estimate how widely the guesses spread, to one table step");
+            println!();
+            println!("Simulate options:");
+            println!("  --games <n>         - Number of games to play (default 1000)");
+            println!("  --questions <n>     - Questions per game (default 10)");
+            println!("  --team-size <n>     - Guesses per team for each question (default 4)");
+            println!("  --spread <ln>       - Spread of each guess around the true answer, in natural log units (default 1.0)");
+            println!("  --scoring <rule>    - closest, or within:<percent> (default within:10)");
+            println!("  --seed <n>          - Seed for a reproducible run");
+            println!("  --bank <path>       - Take true answers from a TOML or JSON question bank");
+            println!();
+            println!("Bench options:");
+            println!("  --save-baseline     - Store this run's timings as the baseline");
+            println!("  --compare-baseline  - Compare against the stored baseline, exiting with an error if any benchmark regressed");
+            println!("  --baseline <path>   - Where the baseline is stored (default: in the data directory)");
+            println!("  --threshold <pct>   - Slowdown that counts as a regression (default 25%)");
+            println!();
+            println!("At the answer prompt, enter ? for a hint, g to give up, or s to skip the problem");
         }
         None => {
-            compare();
+            cli::compare::run_compare();
         }
     }
 }
@@ -0,0 +1,65 @@
+//! Floating point functions used by the estimators, from std when available and libm otherwise.
+
+#[cfg(feature = "std")]
+mod imp {
+    pub fn ln(x: f64) -> f64 {
+        x.ln()
+    }
+
+    pub fn exp(x: f64) -> f64 {
+        x.exp()
+    }
+
+    pub fn log10(x: f64) -> f64 {
+        x.log10()
+    }
+
+    pub fn floor(x: f64) -> f64 {
+        x.floor()
+    }
+
+    pub fn powi(x: f64, n: i32) -> f64 {
+        x.powi(n)
+    }
+
+    pub fn fract(x: f64) -> f64 {
+        x.fract()
+    }
+
+    pub fn sqrt(x: f64) -> f64 {
+        x.sqrt()
+    }
+}
+
+#[cfg(not(feature = "std"))]
+mod imp {
+    pub fn ln(x: f64) -> f64 {
+        libm::log(x)
+    }
+
+    pub fn exp(x: f64) -> f64 {
+        libm::exp(x)
+    }
+
+    pub fn log10(x: f64) -> f64 {
+        libm::log10(x)
+    }
+
+    pub fn floor(x: f64) -> f64 {
+        libm::floor(x)
+    }
+
+    pub fn powi(x: f64, n: i32) -> f64 {
+        libm::pow(x, n as f64)
+    }
+
+    pub fn fract(x: f64) -> f64 {
+        x - libm::trunc(x)
+    }
+
+    pub fn sqrt(x: f64) -> f64 {
+        libm::sqrt(x)
+    }
+}
+
+pub(crate) use imp::*;
@@ -0,0 +1,325 @@
+//! The median and quartiles of a team's guesses, the simplest competing aggregation strategy.
+//!
+//! These reuse the estimator traits so practice mode can drill picking them out of a shouted list of guesses.
+//! The median is found exactly by hand, while quartiles are taken roughly, as the median of each half (Tukey's hinges),
+//! and graded against the interpolated quartile, so a closer answer can still earn Excellent.
+
+use alloc::vec::Vec;
+
+use crate::display::{Value, joined};
+use crate::math;
+use crate::traits::{EstimateGeometricMean, EstimateGeometricMeanStepByStep, FinalAnswer, MethodMetadata};
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MedianError {
+    EmptyInput,
+    NonFiniteValue,
+}
+
+impl core::fmt::Display for MedianError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MedianError::EmptyInput => write!(f, "Cannot find the median of empty input"),
+            MedianError::NonFiniteValue => write!(f, "Median requires all finite values"),
+        }
+    }
+}
+
+impl core::error::Error for MedianError {}
+
+fn sorted(values: &[f64]) -> Result<Vec<f64>, MedianError> {
+    if values.is_empty() {
+        return Err(MedianError::EmptyInput);
+    }
+    if values.iter().any(|v| !v.is_finite()) {
+        return Err(MedianError::NonFiniteValue);
+    }
+
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    Ok(sorted)
+}
+
+fn median_of_sorted(sorted: &[f64]) -> f64 {
+    let middle = sorted.len() / 2;
+    if sorted.len() % 2 == 1 { sorted[middle] } else { (sorted[middle - 1] + sorted[middle]) / 2.0 }
+}
+
+/// The middle value, or the average of the two middle values for an even count
+pub fn median(values: &[f64]) -> Result<f64, MedianError> {
+    sorted(values).map(|sorted| median_of_sorted(&sorted))
+}
+
+/// The value a fraction `p` of the way through the sorted values, interpolating linearly between neighbours
+///
+/// This is the usual definition of a quantile in spreadsheets; `p` of 0.25 and 0.75 give the quartiles.
+pub fn quantile(values: &[f64], p: f64) -> Result<f64, MedianError> {
+    let sorted = sorted(values)?;
+    let position = (sorted.len() - 1) as f64 * p.clamp(0.0, 1.0);
+    let below = math::floor(position) as usize;
+    let above = (below + 1).min(sorted.len() - 1);
+    Ok(sorted[below] + (sorted[above] - sorted[below]) * (position - below as f64))
+}
+
+/// Which part of the sorted values a method takes the median of
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Half {
+    All,
+    Lower,
+    Upper,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MedianSteps {
+    input_values: Vec<f64>,
+    sorted_values: Vec<f64>,
+    half: Half,
+    /// The values the median is taken of: all of them, or one half
+    selected: Vec<f64>,
+    final_result: f64,
+}
+
+impl FinalAnswer for MedianSteps {
+    fn final_answer(&self) -> f64 {
+        self.final_result
+    }
+}
+
+fn median_steps(values: &[f64], half: Half) -> Result<MedianSteps, MedianError> {
+    let sorted_values = sorted(values)?;
+
+    // Odd counts keep the median in both halves, so a single value is its own quartile
+    let middle = sorted_values.len() / 2;
+    let selected = match half {
+        Half::All => sorted_values.clone(),
+        Half::Lower => sorted_values[..sorted_values.len() - middle].to_vec(),
+        Half::Upper => sorted_values[middle..].to_vec(),
+    };
+    let final_result = median_of_sorted(&selected);
+
+    Ok(MedianSteps { input_values: values.to_vec(), sorted_values, half, selected, final_result })
+}
+
+/// Values separated by commas
+fn list(values: &[f64]) -> impl core::fmt::Display + '_ {
+    joined(values.iter().map(|&v| Value(v)), ", ")
+}
+
+impl core::fmt::Display for MedianSteps {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        writeln!(f, "Input values: [{}]", list(&self.input_values))?;
+        writeln!(f)?;
+
+        writeln!(f, "1. Sort the values:")?;
+        writeln!(f, "   {}", list(&self.sorted_values))?;
+        writeln!(f)?;
+
+        let mut step = 2;
+        if self.half != Half::All {
+            let name = if self.half == Half::Lower { "lower" } else { "upper" };
+            writeln!(f, "{}. Keep the {} half, including the middle value when there is one:", step, name)?;
+            writeln!(f, "   {}", list(&self.selected))?;
+            writeln!(f)?;
+            step += 1;
+        }
+
+        let middle = self.selected.len() / 2;
+        if self.selected.len() % 2 == 1 {
+            writeln!(f, "{}. Take the middle value:", step)?;
+            writeln!(f, "   {}", Value(self.selected[middle]))?;
+        } else {
+            writeln!(f, "{}. Average the two middle values:", step)?;
+            writeln!(f, "   ({} + {}) ÷ 2 = {}",
+                     Value(self.selected[middle - 1]), Value(self.selected[middle]), Value(self.final_result))?;
+        }
+        writeln!(f)?;
+
+        write!(f, "Final estimation: {}", Value(self.final_result))
+    }
+}
+
+/// The median of the guesses, found exactly
+pub struct MedianMethod;
+
+/// The lower quartile of the guesses, taken roughly as the median of the lower half
+pub struct LowerQuartileMethod;
+
+/// The upper quartile of the guesses, taken roughly as the median of the upper half
+pub struct UpperQuartileMethod;
+
+impl MethodMetadata for MedianMethod {
+    const NAME: &'static str = "median";
+    const DESCRIPTION: &'static str = "Sorts the guesses and takes the middle one, or halfway between the middle two.";
+    const MEAN: &'static str = "median";
+}
+
+impl MethodMetadata for LowerQuartileMethod {
+    const NAME: &'static str = "lower-quartile";
+    const DESCRIPTION: &'static str = "Sorts the guesses and takes the median of the lower half, \
+including the middle guess when there is an odd number.";
+    const MEAN: &'static str = "lower quartile";
+}
+
+impl MethodMetadata for UpperQuartileMethod {
+    const NAME: &'static str = "upper-quartile";
+    const DESCRIPTION: &'static str = "Sorts the guesses and takes the median of the upper half, \
+including the middle guess when there is an odd number.";
+    const MEAN: &'static str = "upper quartile";
+}
+
+impl EstimateGeometricMeanStepByStep for MedianMethod {
+    type StepByStep = MedianSteps;
+    type Error = MedianError;
+
+    fn estimate_geometric_mean_steps(values: &[f64]) -> Result<Self::StepByStep, Self::Error> {
+        median_steps(values, Half::All)
+    }
+}
+
+impl EstimateGeometricMeanStepByStep for LowerQuartileMethod {
+    type StepByStep = MedianSteps;
+    type Error = MedianError;
+
+    fn estimate_geometric_mean_steps(values: &[f64]) -> Result<Self::StepByStep, Self::Error> {
+        median_steps(values, Half::Lower)
+    }
+}
+
+impl EstimateGeometricMeanStepByStep for UpperQuartileMethod {
+    type StepByStep = MedianSteps;
+    type Error = MedianError;
+
+    fn estimate_geometric_mean_steps(values: &[f64]) -> Result<Self::StepByStep, Self::Error> {
+        median_steps(values, Half::Upper)
+    }
+}
+
+impl EstimateGeometricMean for MedianMethod {
+    type Error = MedianError;
+
+    fn estimate_geometric_mean(values: &[f64]) -> Result<f64, Self::Error> {
+        median(values)
+    }
+
+    fn exact_mean(values: &[f64]) -> Option<f64> {
+        median(values).ok()
+    }
+}
+
+impl EstimateGeometricMean for LowerQuartileMethod {
+    type Error = MedianError;
+
+    fn estimate_geometric_mean(values: &[f64]) -> Result<f64, Self::Error> {
+        Self::estimate_geometric_mean_steps(values).map(|steps| steps.final_answer())
+    }
+
+    fn exact_mean(values: &[f64]) -> Option<f64> {
+        quantile(values, 0.25).ok()
+    }
+}
+
+impl EstimateGeometricMean for UpperQuartileMethod {
+    type Error = MedianError;
+
+    fn estimate_geometric_mean(values: &[f64]) -> Result<f64, Self::Error> {
+        Self::estimate_geometric_mean_steps(values).map(|steps| steps.final_answer())
+    }
+
+    fn exact_mean(values: &[f64]) -> Option<f64> {
+        quantile(values, 0.75).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+    use alloc::vec;
+    use quickcheck_macros::quickcheck;
+
+    #[test]
+    fn test_median() {
+        assert_eq!(median(&[150.0, 2500.0, 800.0, 45.0, 300.0]), Ok(300.0));
+        assert_eq!(median(&[150.0, 2500.0, 800.0, 45.0]), Ok(475.0));
+        assert_eq!(median(&[7.0]), Ok(7.0));
+        assert_eq!(median(&[]), Err(MedianError::EmptyInput));
+        assert_eq!(median(&[1.0, f64::NAN]), Err(MedianError::NonFiniteValue));
+    }
+
+    #[test]
+    fn test_quantile() {
+        let values = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_eq!(quantile(&values, 0.25), Ok(2.0));
+        assert_eq!(quantile(&values, 0.5), Ok(3.0));
+        assert_eq!(quantile(&[10.0, 20.0, 30.0, 40.0], 0.25), Ok(17.5));
+        assert_eq!(quantile(&[7.0], 0.75), Ok(7.0));
+    }
+
+    #[test]
+    fn test_hinges() {
+        let odd = [9.0, 1.0, 5.0, 3.0, 7.0];
+        assert_eq!(LowerQuartileMethod::estimate_geometric_mean(&odd), Ok(3.0));
+        assert_eq!(UpperQuartileMethod::estimate_geometric_mean(&odd), Ok(7.0));
+
+        let even = [40.0, 10.0, 30.0, 20.0];
+        assert_eq!(LowerQuartileMethod::estimate_geometric_mean(&even), Ok(15.0));
+        assert_eq!(UpperQuartileMethod::estimate_geometric_mean(&even), Ok(35.0));
+        assert_eq!(LowerQuartileMethod::exact_mean(&even), Some(17.5));
+
+        assert_eq!(LowerQuartileMethod::estimate_geometric_mean(&[7.0]), Ok(7.0));
+    }
+
+    #[test]
+    fn test_median_steps_display() {
+        let steps = MedianMethod::estimate_geometric_mean_steps(&[150.0, 2500.0, 800.0, 45.0]).unwrap();
+        let expected = "Input values: [150, 2500, 800, 45]\n\
+                        \n\
+                        1. Sort the values:\n   \
+                        45, 150, 800, 2500\n\
+                        \n\
+                        2. Average the two middle values:\n   \
+                        (150 + 800) ÷ 2 = 475\n\
+                        \n\
+                        Final estimation: 475";
+        assert_eq!(steps.to_string(), expected);
+    }
+
+    #[test]
+    fn test_quartile_steps_display() {
+        let steps = UpperQuartileMethod::estimate_geometric_mean_steps(&[9.0, 1.0, 5.0, 3.0, 7.0]).unwrap();
+        let expected = "Input values: [9, 1, 5, 3, 7]\n\
+                        \n\
+                        1. Sort the values:\n   \
+                        1, 3, 5, 7, 9\n\
+                        \n\
+                        2. Keep the upper half, including the middle value when there is one:\n   \
+                        5, 7, 9\n\
+                        \n\
+                        3. Take the middle value:\n   \
+                        7\n\
+                        \n\
+                        Final estimation: 7";
+        assert_eq!(steps.to_string(), expected);
+    }
+
+    #[quickcheck]
+    fn prop_quartiles_bracket_median(values: Vec<u32>) -> bool {
+        let values: Vec<f64> = values.into_iter().map(f64::from).collect();
+        let Ok(median) = median(&values) else {
+            return values.is_empty();
+        };
+        let lower = LowerQuartileMethod::estimate_geometric_mean(&values).unwrap();
+        let upper = UpperQuartileMethod::estimate_geometric_mean(&values).unwrap();
+        let exact_lower = LowerQuartileMethod::exact_mean(&values).unwrap();
+        let exact_upper = UpperQuartileMethod::exact_mean(&values).unwrap();
+        lower <= median && median <= upper && exact_lower <= median && median <= exact_upper
+    }
+
+    #[test]
+    fn test_quantile_matches_median() {
+        let values = vec![3.0, 1.0, 4.0, 1.0, 5.0, 9.0];
+        assert_eq!(quantile(&values, 0.5), median(&values));
+    }
+}
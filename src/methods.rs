@@ -0,0 +1,51 @@
+use alloc::string::{String, ToString};
+
+use crate::exact::ExactGeometricMean;
+use crate::log_linear::LogLinearApproximation;
+use crate::shortcuts::AutoShortcut;
+use crate::table_based::TableBasedApproximation;
+use crate::traits::{EstimateGeometricMean, MethodMetadata};
+
+/// Names of every estimation method, as given by `MethodMetadata::NAME`
+pub const METHOD_NAMES: [&str; 4] = [
+    ExactGeometricMean::NAME,
+    LogLinearApproximation::NAME,
+    TableBasedApproximation::NAME,
+    AutoShortcut::NAME,
+];
+
+fn estimate<E: EstimateGeometricMean>(values: &[f64]) -> Result<f64, String> {
+    E::estimate_geometric_mean(values).map_err(|e| e.to_string())
+}
+
+/// Estimate the geometric mean with the method named `name`, for callers that choose a method at runtime
+///
+/// Returns `None` when no method has that name.
+pub fn estimate_by_name(name: &str, values: &[f64]) -> Option<Result<f64, String>> {
+    match name {
+        ExactGeometricMean::NAME => Some(estimate::<ExactGeometricMean>(values)),
+        LogLinearApproximation::NAME => Some(estimate::<LogLinearApproximation>(values)),
+        TableBasedApproximation::NAME => Some(estimate::<TableBasedApproximation>(values)),
+        AutoShortcut::NAME => Some(estimate::<AutoShortcut>(values)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_method_name_is_estimable() {
+        for name in METHOD_NAMES {
+            assert!(estimate_by_name(name, &[10.0, 1000.0]).unwrap().is_ok(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_estimate_by_name() {
+        assert_eq!(estimate_by_name("table-based", &[25.0, 400.0]), Some(Ok(100.0)));
+        assert!(estimate_by_name("exact", &[]).unwrap().is_err());
+        assert_eq!(estimate_by_name("abacus", &[25.0, 400.0]), None);
+    }
+}
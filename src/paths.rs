@@ -0,0 +1,25 @@
+use std::path::PathBuf;
+
+/// Name of the per-user directory created inside the platform data and config directories
+const APP_DIR: &str = "pen_and_paper_geometric_mean";
+
+/// Per-user directory for persistent data such as practice history
+pub fn data_dir() -> Option<PathBuf> {
+    base_dir("XDG_DATA_HOME", &[".local", "share"]).map(|dir| dir.join(APP_DIR))
+}
+
+/// Per-user directory for configuration files
+pub fn config_dir() -> Option<PathBuf> {
+    base_dir("XDG_CONFIG_HOME", &[".config"]).map(|dir| dir.join(APP_DIR))
+}
+
+/// Resolve an XDG base directory, falling back to its conventional location under `$HOME`
+fn base_dir(xdg_variable: &str, home_fallback: &[&str]) -> Option<PathBuf> {
+    match std::env::var_os(xdg_variable) {
+        Some(dir) if !dir.is_empty() => Some(PathBuf::from(dir)),
+        _ => {
+            let home = PathBuf::from(std::env::var_os("HOME")?);
+            Some(home_fallback.iter().fold(home, |path, part| path.join(part)))
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! Printed practice material: pages of monospaced text, as the CLI already lays it out, written with `pdf-writer`.
+//!
+//! Text is set in Courier, one of the fonts every PDF reader provides, so nothing is embedded and the columns of the
+//! terminal output line up on paper.
+//! The standard fonts only cover the Windows-1252 character set, so characters outside it are replaced with the
+//! nearest plain equivalent, such as `->` for `→`.
+
+use std::io;
+use std::path::Path;
+
+use pdf_writer::{Content, Finish, Name, Pdf, Rect, Ref, Str};
+
+/// US Letter, in points
+const PAGE_WIDTH: f32 = 612.0;
+const PAGE_HEIGHT: f32 = 792.0;
+const MARGIN: f32 = 54.0;
+const FONT_SIZE: f32 = 10.0;
+const LINE_HEIGHT: f32 = 12.0;
+const FONT_NAME: Name<'static> = Name(b"F1");
+
+/// Lines of text that fit between the top and bottom margins
+pub const LINES_PER_PAGE: usize = ((PAGE_HEIGHT - 2.0 * MARGIN) / LINE_HEIGHT) as usize;
+
+/// A document of text pages, rendered to PDF by [`PdfDocument::to_bytes`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PdfDocument {
+    pages: Vec<Vec<String>>,
+}
+
+impl PdfDocument {
+    pub fn new() -> Self {
+        PdfDocument::default()
+    }
+
+    /// Start a new page with `text`, continuing onto further pages if it runs past [`LINES_PER_PAGE`] lines
+    pub fn add_page(&mut self, text: &str) {
+        let lines: Vec<String> = text.lines().map(String::from).collect();
+        if lines.is_empty() {
+            self.pages.push(Vec::new());
+        }
+        self.pages.extend(lines.chunks(LINES_PER_PAGE).map(<[String]>::to_vec));
+    }
+
+    pub fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Render the document, with one page object and content stream per page
+    pub fn to_bytes(&self) -> Vec<u8> {
+        // Objects 1 to 3 are the catalog, page tree, and font; each page then takes two, its page and its content
+        let (catalog_id, page_tree_id, font_id) = (Ref::new(1), Ref::new(2), Ref::new(3));
+        let page_id = |index: usize| Ref::new(4 + 2 * index as i32);
+
+        let mut pdf = Pdf::new();
+        pdf.catalog(catalog_id).pages(page_tree_id);
+        pdf.pages(page_tree_id).kids((0..self.pages.len()).map(page_id)).count(self.pages.len() as i32);
+        pdf.type1_font(font_id).base_font(Name(b"Courier")).encoding_predefined(Name(b"WinAnsiEncoding"));
+        for (index, lines) in self.pages.iter().enumerate() {
+            let content_id = page_id(index).next();
+            let mut page = pdf.page(page_id(index));
+            page.parent(page_tree_id).media_box(Rect::new(0.0, 0.0, PAGE_WIDTH, PAGE_HEIGHT)).contents(content_id);
+            page.resources().fonts().pair(FONT_NAME, font_id);
+            page.finish();
+            pdf.stream(content_id, &content_stream(lines));
+        }
+        pdf.finish()
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        std::fs::write(path, self.to_bytes())
+    }
+}
+
+/// Draw each line below the last, starting at the top margin
+fn content_stream(lines: &[String]) -> Vec<u8> {
+    let mut content = Content::new();
+    content.begin_text().set_font(FONT_NAME, FONT_SIZE).set_leading(LINE_HEIGHT).next_line(MARGIN, PAGE_HEIGHT - MARGIN - FONT_SIZE);
+    for line in lines {
+        content.show(Str(&encode_text(line))).next_line_using_leading();
+    }
+    content.end_text();
+    content.finish()
+}
+
+/// Encode text in Windows-1252; `pdf-writer` escapes the string delimiters itself
+fn encode_text(text: &str) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '→' => bytes.extend(b"->"),
+            '≈' => bytes.push(b'~'),
+            '√' => bytes.extend(b"sqrt"),
+            '–' => bytes.push(0x96),
+            '—' => bytes.push(0x97),
+            '…' => bytes.push(0x85),
+            ' '..='~' | '\u{a0}'..='\u{ff}' => bytes.push(c as u32 as u8),
+            _ => bytes.push(b'?'),
+        }
+    }
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn as_text(document: &PdfDocument) -> String {
+        String::from_utf8_lossy(&document.to_bytes()).into_owned()
+    }
+
+    #[test]
+    fn test_document_structure() {
+        let mut document = PdfDocument::new();
+        document.add_page("Cheat Sheet\n2,000 → 3.3");
+        document.add_page("Answers (key)");
+        let text = as_text(&document);
+
+        assert!(text.starts_with("%PDF-"));
+        assert!(text.contains("/Kids [4 0 R 6 0 R]\n  /Count 2\n"));
+        assert!(text.contains("/BaseFont /Courier\n  /Encoding /WinAnsiEncoding\n"));
+        assert!(text.contains("/MediaBox [0 0 612 792]\n  /Contents 5 0 R\n"));
+        assert!(text.contains("(Cheat Sheet) Tj\nT*\n(2,000 -> 3.3) Tj\nT*\n"));
+        assert!(text.contains("(Answers (key)) Tj\n"));
+    }
+
+    #[test]
+    fn test_long_text_continues_onto_new_pages() {
+        let mut document = PdfDocument::new();
+        let text: Vec<String> = (0..LINES_PER_PAGE + 1).map(|i| i.to_string()).collect();
+        document.add_page(&text.join("\n"));
+        assert_eq!(document.page_count(), 2);
+        document.add_page("");
+        assert_eq!(document.page_count(), 3);
+    }
+
+    #[test]
+    fn test_encode_text() {
+        assert_eq!(encode_text("200 – 500 × 2"), b"200 \x96 500 \xd7 2");
+        assert_eq!(encode_text("√ ✓"), b"sqrt ?");
+    }
+}
@@ -3,7 +3,6 @@ use rand::distributions::Distribution;
 use std::marker::PhantomData;
 use std::time::Duration;
 
-use crate::exact::geometric_mean;
 use crate::traits::EstimateGeometricMean;
 use crate::trivia_guess::TriviaGuessDistribution;
 
@@ -34,8 +33,12 @@ impl Timer for SystemTimer {
     }
 }
 
+/// Answers the simulations, evaluations, and worksheets draw problems from, from tens to millions like trivia answers
+pub const ANSWER_RANGE: (u64, u64) = (10, 1_000_000);
+
 /// Configuration for practice mode sessions
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PracticeModeConfig {
     pub team_size: usize,
     pub log_std_dev: f64,
@@ -45,9 +48,11 @@ pub struct PracticeModeConfig {
 
 /// Errors that can occur during practice mode configuration
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConfigurationError {
     ZeroTeamSize,
     InvalidAnswerRange,
+    InvalidProblem,
 }
 
 impl std::fmt::Display for ConfigurationError {
@@ -55,6 +60,7 @@ impl std::fmt::Display for ConfigurationError {
         match self {
             ConfigurationError::ZeroTeamSize => write!(f, "Team size cannot be zero"),
             ConfigurationError::InvalidAnswerRange => write!(f, "Answer range cannot be empty (min >= max)"),
+            ConfigurationError::InvalidProblem => write!(f, "Problem guesses must be non-empty and positive"),
         }
     }
 }
@@ -78,10 +84,19 @@ impl PracticeModeConfig {
             max_answer,
         })
     }
+
+    /// Draw a true answer log-uniformly from the configured range
+    pub fn random_answer<R: Rng>(&self, rng: &mut R) -> u64 {
+        let ln_min = (self.min_answer as f64).ln();
+        let ln_max = (self.max_answer as f64).ln();
+        let ln_correct_answer = rng.gen_range(ln_min..ln_max);
+        ln_correct_answer.exp() as u64
+    }
 }
 
 /// Answer evaluation result
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AnswerEvaluation {
     /// User answer equals floor(estimation_method_result) or ceiling(estimation_method_result)
     Correct,
@@ -89,6 +104,60 @@ pub enum AnswerEvaluation {
     Excellent,
     /// User answer does not meet either criteria above
     Incorrect,
+    /// User gave up without answering
+    GaveUp,
+}
+
+impl AnswerEvaluation {
+    /// Whether the round counts as a success, i.e. Correct or Excellent
+    pub fn is_success(&self) -> bool {
+        matches!(self, AnswerEvaluation::Correct | AnswerEvaluation::Excellent)
+    }
+}
+
+/// Progressive hints towards the estimation method's answer, revealed one at a time
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Hint {
+    /// Number of digits in the estimation method's answer
+    DigitCount(u32),
+    /// Leading two digits of the estimation method's answer (one for single-digit answers)
+    LeadingDigits(u64),
+}
+
+/// A specific practice problem: the team's guesses and the hidden true answer they were drawn around
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Problem {
+    pub guesses: Vec<u64>,
+    pub correct_answer: u64,
+}
+
+impl Problem {
+    /// Generate a random problem: a hidden true answer, and the team's guesses drawn around it
+    pub fn generate<R: Rng>(rng: &mut R, config: &PracticeModeConfig) -> Result<Problem, ConfigurationError> {
+        // Config was already validated during construction
+        let correct_answer = config.random_answer(rng);
+        Self::generate_around(rng, config, correct_answer)
+    }
+
+    /// Generate the team's guesses around a given true answer, e.g. to pose the same question to several teams
+    pub fn generate_around<R: Rng>(
+        rng: &mut R,
+        config: &PracticeModeConfig,
+        correct_answer: u64,
+    ) -> Result<Problem, ConfigurationError> {
+        // Create trivia guess distribution
+        let distribution = TriviaGuessDistribution::new(correct_answer, config.log_std_dev)
+            .map_err(|_| ConfigurationError::InvalidAnswerRange)?;
+
+        // Generate team guesses
+        let guesses: Vec<u64> = (0..config.team_size)
+            .map(|_| distribution.sample(rng))
+            .collect();
+
+        Ok(Problem { guesses, correct_answer })
+    }
 }
 
 /// Type states for practice mode session
@@ -105,10 +174,12 @@ pub struct PracticeSession<S, R, T, E> {
 /// Active session containing problem data and timing information
 pub struct ActiveSession<T: Timer, E> {
     input_values: Vec<f64>,
-    exact_geometric_mean: f64,
+    correct_answer: u64,
+    exact_mean: f64,
     estimation_result: f64,
     start_instant: T::Instant,
     timer: T,
+    hints_used: usize,
     estimation_method: PhantomData<E>,
 }
 
@@ -125,66 +196,88 @@ impl<R: Rng, T: Timer, E: EstimateGeometricMean> PracticeSession<Ready, R, T, E>
 
     /// Start a new practice problem, returning guesses and active session
     pub fn start(mut self, config: PracticeModeConfig) -> Result<(Vec<u64>, ActiveSession<T, E>), ConfigurationError> {
-        // Validate configuration - config was already validated during construction
-
-        // Generate random correct answer in log space
-        let ln_min = (config.min_answer as f64).ln();
-        let ln_max = (config.max_answer as f64).ln();
-        let ln_correct_answer = self.rng.gen_range(ln_min..ln_max);
-        let correct_answer = ln_correct_answer.exp() as u64;
-
-        // Create trivia guess distribution
-        let distribution = TriviaGuessDistribution::new(correct_answer, config.log_std_dev)
-            .map_err(|_| ConfigurationError::InvalidAnswerRange)?;
-
-        // Generate team guesses
-        let guesses: Vec<u64> = (0..config.team_size)
-            .map(|_| distribution.sample(&mut self.rng))
-            .collect();
+        let problem = Problem::generate(&mut self.rng, &config)?;
+        self.start_with_problem(problem)
+    }
 
-        // Calculate exact geometric mean
-        let guesses_f64: Vec<f64> = guesses.iter().map(|&x| x as f64).collect();
-        let exact_geometric_mean = geometric_mean(&guesses_f64)
-            .map_err(|_| ConfigurationError::InvalidAnswerRange)?;
+    /// Start a specific practice problem, such as one being served again for review
+    pub fn start_with_problem(self, problem: Problem) -> Result<(Vec<u64>, ActiveSession<T, E>), ConfigurationError> {
+        // Calculate the exact mean the method estimates
+        let guesses_f64: Vec<f64> = problem.guesses.iter().map(|&x| x as f64).collect();
+        let exact_mean = E::exact_mean(&guesses_f64)
+            .ok_or(ConfigurationError::InvalidProblem)?;
 
         // Calculate estimation method result
         let estimation_result = E::estimate_geometric_mean(&guesses_f64)
-            .map_err(|_| ConfigurationError::InvalidAnswerRange)?;
+            .map_err(|_| ConfigurationError::InvalidProblem)?;
 
         // Start timing
         let start_instant = self.timer.now();
 
         let active_session = ActiveSession {
             input_values: guesses_f64,
-            exact_geometric_mean,
+            correct_answer: problem.correct_answer,
+            exact_mean,
             estimation_result,
             start_instant,
             timer: self.timer,
+            hints_used: 0,
             estimation_method: PhantomData,
         };
 
-        Ok((guesses, active_session))
+        Ok((problem.guesses, active_session))
     }
 }
 
 impl<T: Timer, E: EstimateGeometricMean> ActiveSession<T, E> {
     /// Submit user answer and get evaluation result
     pub fn submit_answer(self, user_answer: u64) -> PracticeResult<E> {
-        let duration = self.timer.elapsed(self.start_instant);
-
         let evaluation = evaluate_answer(
             user_answer,
-            self.exact_geometric_mean,
+            self.exact_mean,
             self.estimation_result,
         );
 
+        self.into_result(user_answer, evaluation)
+    }
+
+    /// Reveal the next hint towards the estimation method's answer, if any remain
+    pub fn hint(&mut self) -> Option<Hint> {
+        let answer = self.estimation_result as u64;
+        let digits = answer.checked_ilog10().unwrap_or(0) + 1;
+
+        let hint = match self.hints_used {
+            0 => Hint::DigitCount(digits),
+            1 => Hint::LeadingDigits(answer / 10_u64.pow(digits.saturating_sub(2))),
+            _ => return None,
+        };
+
+        self.hints_used += 1;
+        Some(hint)
+    }
+
+    /// Give up on the problem, producing a result graded as `GaveUp`
+    ///
+    /// The user answer is recorded as 0, which is never a valid submission.
+    pub fn give_up(self) -> PracticeResult<E> {
+        self.into_result(0, AnswerEvaluation::GaveUp)
+    }
+
+    /// Skip the problem entirely, without grading or producing a result
+    pub fn skip(self) {}
+
+    fn into_result(self, user_answer: u64, evaluation: AnswerEvaluation) -> PracticeResult<E> {
+        let duration = self.timer.elapsed(self.start_instant);
+
         PracticeResult {
             user_answer,
-            exact_geometric_mean: self.exact_geometric_mean,
+            exact_mean: self.exact_mean,
             estimation_result: self.estimation_result as u64,
             duration,
             evaluation,
             input_values: self.input_values,
+            correct_answer: self.correct_answer,
+            hints_used: self.hints_used,
             estimation_method: PhantomData,
         }
     }
@@ -192,16 +285,44 @@ impl<T: Timer, E: EstimateGeometricMean> ActiveSession<T, E> {
 
 /// Result of a practice session submission
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PracticeResult<E> {
     pub user_answer: u64,
-    pub exact_geometric_mean: f64,
+    /// The exact value the method estimates, as given by `EstimateGeometricMean::exact_mean`: the geometric mean, or
+    /// the harmonic mean or median for methods that estimate those
+    pub exact_mean: f64,
     pub estimation_result: u64,
     pub duration: Duration,
     pub evaluation: AnswerEvaluation,
     pub input_values: Vec<f64>,
+    /// The hidden true answer the team's guesses were drawn around
+    pub correct_answer: u64,
+    pub hints_used: usize,
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub estimation_method: PhantomData<E>,
 }
 
+impl<E> PracticeResult<E> {
+    /// The problem this result answered, so it can be served again
+    pub fn problem(&self) -> Problem {
+        Problem {
+            guesses: self.input_values.iter().map(|&v| v as u64).collect(),
+            correct_answer: self.correct_answer,
+        }
+    }
+
+    /// Find the team guess closest to the true answer, as (index, guess)
+    ///
+    /// Closeness is absolute distance, matching trivia scoring; ties go to the earliest guess.
+    pub fn closest_guess(&self) -> Option<(usize, u64)> {
+        self.input_values
+            .iter()
+            .map(|&v| v as u64)
+            .enumerate()
+            .min_by_key(|&(_, guess)| guess.abs_diff(self.correct_answer))
+    }
+}
+
 impl<E> PracticeResult<E>
 where
     E: crate::traits::EstimateGeometricMeanStepByStep,
@@ -212,8 +333,126 @@ where
     }
 }
 
+/// Summary of a single round, kept for end-of-session reporting
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RoundSummary {
+    pub user_answer: u64,
+    pub evaluation: AnswerEvaluation,
+    pub duration: Duration,
+    pub hints_used: usize,
+}
+
+/// Aggregate statistics over all rounds of a practice session
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SessionStats {
+    rounds: Vec<RoundSummary>,
+}
+
+impl SessionStats {
+    pub fn new() -> Self {
+        SessionStats::default()
+    }
+
+    /// Record a finished round
+    pub fn record<E>(&mut self, result: &PracticeResult<E>) {
+        self.rounds.push(RoundSummary {
+            user_answer: result.user_answer,
+            evaluation: result.evaluation.clone(),
+            duration: result.duration,
+            hints_used: result.hints_used,
+        });
+    }
+
+    /// All recorded rounds, in the order they were played
+    pub fn rounds(&self) -> &[RoundSummary] {
+        &self.rounds
+    }
+
+    /// Number of rounds with the given evaluation
+    pub fn count(&self, evaluation: &AnswerEvaluation) -> usize {
+        self.rounds.iter().filter(|r| &r.evaluation == evaluation).count()
+    }
+
+    /// Fraction of rounds that were Correct or Excellent, if any rounds were played
+    pub fn accuracy(&self) -> Option<f64> {
+        if self.rounds.is_empty() {
+            return None;
+        }
+        let successes = self.rounds.iter().filter(|r| r.evaluation.is_success()).count();
+        Some(successes as f64 / self.rounds.len() as f64)
+    }
+
+    pub fn total_duration(&self) -> Duration {
+        self.rounds.iter().map(|r| r.duration).sum()
+    }
+
+    pub fn average_duration(&self) -> Option<Duration> {
+        if self.rounds.is_empty() {
+            return None;
+        }
+        Some(self.total_duration() / self.rounds.len() as u32)
+    }
+
+    pub fn fastest_duration(&self) -> Option<Duration> {
+        self.rounds.iter().map(|r| r.duration).min()
+    }
+}
+
+/// Format numbers with thousands separators for display
+pub fn format_number(n: u64) -> String {
+    let s = n.to_string();
+    let mut result = String::new();
+
+    for (i, c) in s.chars().rev().enumerate() {
+        if i > 0 && i % 3 == 0 {
+            result.push(',');
+        }
+        result.push(c);
+    }
+
+    result.chars().rev().collect()
+}
+
+/// Parse user input as u64, handling validation
+///
+/// Surrounding whitespace and thousands separators are ignored, and the error explains what was wrong.
+pub fn parse_user_input(input: &str) -> Result<u64, String> {
+    let trimmed = input.trim();
+
+    if trimmed.is_empty() {
+        return Err("Please enter a number".to_string());
+    }
+
+    // Remove commas for parsing
+    let cleaned = trimmed.replace(',', "");
+
+    match cleaned.parse::<u64>() {
+        Ok(value) => {
+            if value == 0 {
+                Err("Please enter a positive number".to_string())
+            } else {
+                Ok(value)
+            }
+        }
+        Err(_) => {
+            if cleaned.contains('.') {
+                Err("Please enter a whole number (no decimals)".to_string())
+            } else if cleaned.starts_with('-') {
+                Err("Please enter a positive number".to_string())
+            } else {
+                Err("Please enter a valid number".to_string())
+            }
+        }
+    }
+}
+
 /// Evaluate user answer according to plan specifications
-fn evaluate_answer(user_answer: u64, exact_geometric_mean: f64, estimation_result: f64) -> AnswerEvaluation {
+///
+/// Correct means matching the estimation method's result, rounded either way.
+/// Excellent means being strictly closer to the exact geometric mean than the method's result.
+pub fn evaluate_answer(user_answer: u64, exact_geometric_mean: f64, estimation_result: f64) -> AnswerEvaluation {
     let estimation_floor = estimation_result.floor() as u64;
     let estimation_ceil = estimation_result.ceil() as u64;
 
@@ -358,6 +597,169 @@ mod tests {
         assert!(result.duration > Duration::from_millis(0));
     }
 
+    #[test]
+    fn test_result_carries_correct_answer() {
+        let rng = StdRng::seed_from_u64(42);
+        let timer = MockTimer::new();
+        let config = PracticeModeConfig::new(4, 0.0, 10, 1000).unwrap();
+
+        let session: PracticeSession<Ready, _, _, SumEstimation> = PracticeSession::new(rng, timer);
+        let (guesses, active_session) = session.start(config).unwrap();
+        let result = active_session.submit_answer(1);
+
+        // With perfect certainty every guess is the correct answer rounded to trivia format
+        assert!(result.correct_answer >= 10 && result.correct_answer < 1000);
+        let closest = result.closest_guess().unwrap();
+        assert_eq!(closest, (0, guesses[0]));
+    }
+
+    #[test]
+    fn test_start_with_problem_replays_it() {
+        let rng = StdRng::seed_from_u64(42);
+        let config = PracticeModeConfig::new(3, 1.0, 10, 1000).unwrap();
+
+        let session: PracticeSession<Ready, _, _, SumEstimation> = PracticeSession::new(rng, MockTimer::new());
+        let (guesses, active_session) = session.start(config).unwrap();
+        let original = active_session.submit_answer(1);
+
+        let replay: PracticeSession<Ready, _, _, SumEstimation> =
+            PracticeSession::new(StdRng::seed_from_u64(7), MockTimer::new());
+        let (replayed_guesses, active_session) = replay.start_with_problem(original.problem()).unwrap();
+        let replayed = active_session.submit_answer(1);
+
+        assert_eq!(replayed_guesses, guesses);
+        assert_eq!(replayed.problem(), original.problem());
+        assert_eq!(replayed.estimation_result, original.estimation_result);
+    }
+
+    #[test]
+    fn test_start_with_invalid_problem() {
+        let session: PracticeSession<Ready, _, _, SumEstimation> =
+            PracticeSession::new(StdRng::seed_from_u64(42), MockTimer::new());
+        let problem = Problem { guesses: vec![], correct_answer: 10 };
+        assert!(matches!(session.start_with_problem(problem), Err(ConfigurationError::InvalidProblem)));
+    }
+
+    /// Mock estimation method that rejects every problem
+    struct RejectingEstimation;
+
+    impl EstimateGeometricMean for RejectingEstimation {
+        type Error = crate::exact::GeometricMeanError;
+
+        fn estimate_geometric_mean(_values: &[f64]) -> Result<f64, Self::Error> {
+            Err(crate::exact::GeometricMeanError::NonPositiveValue)
+        }
+    }
+
+    #[test]
+    fn test_start_reports_rejected_problem() {
+        let config = PracticeModeConfig::new(4, 1.0, 10, 1000).unwrap();
+        let session: PracticeSession<Ready, _, _, RejectingEstimation> =
+            PracticeSession::new(StdRng::seed_from_u64(42), MockTimer::new());
+        assert!(matches!(session.start(config), Err(ConfigurationError::InvalidProblem)));
+    }
+
+    #[test]
+    fn test_hints_then_give_up() {
+        let rng = StdRng::seed_from_u64(42);
+        let timer = MockTimer::new();
+        let config = PracticeModeConfig::new(2, 1.0, 10, 100).unwrap();
+
+        let session: PracticeSession<Ready, _, _, SumEstimation> = PracticeSession::new(rng, timer);
+        let (guesses, mut active_session) = session.start(config).unwrap();
+        let sum: u64 = guesses.iter().sum();
+        let digits = sum.ilog10() + 1;
+
+        assert_eq!(active_session.hint(), Some(Hint::DigitCount(digits)));
+        assert_eq!(active_session.hint(), Some(Hint::LeadingDigits(sum / 10_u64.pow(digits - 2))));
+        assert_eq!(active_session.hint(), None);
+
+        let result = active_session.give_up();
+        assert_eq!(result.evaluation, AnswerEvaluation::GaveUp);
+        assert_eq!(result.hints_used, 2);
+        assert!(!result.evaluation.is_success());
+    }
+
+    #[test]
+    fn test_session_stats() {
+        let mut stats = SessionStats::new();
+        assert_eq!(stats.accuracy(), None);
+        assert_eq!(stats.average_duration(), None);
+        assert_eq!(stats.fastest_duration(), None);
+
+        let result = |evaluation, millis| PracticeResult {
+            user_answer: 100,
+            exact_mean: 100.0,
+            estimation_result: 100,
+            duration: Duration::from_millis(millis),
+            evaluation,
+            input_values: vec![100.0],
+            correct_answer: 100,
+            hints_used: 0,
+            estimation_method: PhantomData::<SumEstimation>,
+        };
+
+        stats.record(&result(AnswerEvaluation::Correct, 3000));
+        stats.record(&result(AnswerEvaluation::Incorrect, 9000));
+        stats.record(&result(AnswerEvaluation::Excellent, 6000));
+        stats.record(&result(AnswerEvaluation::Correct, 2000));
+        stats.record(&result(AnswerEvaluation::GaveUp, 5000));
+
+        assert_eq!(stats.rounds().len(), 5);
+        assert_eq!(stats.rounds()[1].evaluation, AnswerEvaluation::Incorrect);
+        assert_eq!(stats.count(&AnswerEvaluation::Correct), 2);
+        assert_eq!(stats.count(&AnswerEvaluation::Excellent), 1);
+        assert_eq!(stats.count(&AnswerEvaluation::Incorrect), 1);
+        assert_eq!(stats.count(&AnswerEvaluation::GaveUp), 1);
+        assert_eq!(stats.accuracy(), Some(0.6));
+        assert_eq!(stats.total_duration(), Duration::from_millis(25000));
+        assert_eq!(stats.average_duration(), Some(Duration::from_millis(5000)));
+        assert_eq!(stats.fastest_duration(), Some(Duration::from_millis(2000)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_practice_result_serde_round_trip() {
+        let result = PracticeResult {
+            user_answer: 400,
+            exact_mean: 387.3,
+            estimation_result: 400,
+            duration: Duration::from_millis(1500),
+            evaluation: AnswerEvaluation::Excellent,
+            input_values: vec![150.0, 1000.0],
+            correct_answer: 350,
+            hints_used: 1,
+            estimation_method: PhantomData::<SumEstimation>,
+        };
+
+        let json = serde_json::to_string(&result).unwrap();
+        assert!(!json.contains("estimation_method"));
+        let restored: PracticeResult<SumEstimation> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.evaluation, AnswerEvaluation::Excellent);
+        assert_eq!(restored.duration, result.duration);
+        assert_eq!(restored.input_values, result.input_values);
+        assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+    }
+
+    #[test]
+    fn test_closest_guess() {
+        let result = PracticeResult {
+            user_answer: 100,
+            exact_mean: 100.0,
+            estimation_result: 100,
+            duration: Duration::from_millis(100),
+            evaluation: AnswerEvaluation::Correct,
+            input_values: vec![10.0, 900.0, 1100.0, 100000.0],
+            correct_answer: 1000,
+            hints_used: 0,
+            estimation_method: PhantomData::<SumEstimation>,
+        };
+        assert_eq!(result.closest_guess(), Some((1, 900)));
+
+        let empty = PracticeResult { input_values: vec![], ..result };
+        assert_eq!(empty.closest_guess(), None);
+    }
+
     #[test]
     fn test_practice_session_sum_minus_one_excellent() {
         let rng = StdRng::seed_from_u64(123);
@@ -443,8 +845,34 @@ mod tests {
         assert_eq!(result.evaluation, AnswerEvaluation::Correct);
 
         // Verify all result fields are populated
-        assert!(result.exact_geometric_mean > 0.0);
+        assert!(result.exact_mean > 0.0);
         assert!(result.estimation_result > 0);
         assert!(result.duration > Duration::from_millis(0));
     }
+
+    #[test]
+    fn test_harmonic_method_is_graded_against_harmonic_mean() {
+        let session: PracticeSession<Ready, _, _, crate::harmonic::ReciprocalTableApproximation> =
+            PracticeSession::new(StdRng::seed_from_u64(42), MockTimer::new());
+        let problem = Problem { guesses: vec![30, 60], correct_answer: 40 };
+        let (_, active_session) = session.start_with_problem(problem).unwrap();
+
+        let result = active_session.submit_answer(45);
+        assert!((result.exact_mean - 40.0).abs() < 1e-10);
+        assert_eq!(result.estimation_result, 50);
+        assert_eq!(result.evaluation, AnswerEvaluation::Excellent);
+    }
+
+    #[test]
+    fn test_quartile_rounds_grade_hinges_against_interpolated_quartile() {
+        let session: PracticeSession<Ready, _, _, crate::median::LowerQuartileMethod> =
+            PracticeSession::new(StdRng::seed_from_u64(42), MockTimer::new());
+        let problem = Problem { guesses: vec![40, 10, 30, 20], correct_answer: 25 };
+        let (_, active_session) = session.start_with_problem(problem).unwrap();
+
+        // The hinge is 15, while the interpolated quartile is 17.5
+        let result = active_session.submit_answer(17);
+        assert_eq!(result.estimation_result, 15);
+        assert_eq!(result.evaluation, AnswerEvaluation::Excellent);
+    }
 }
@@ -0,0 +1,460 @@
+//! Banks of real trivia questions, so practice and simulated games can use true answers from actual quizzes.
+//!
+//! A bank is a TOML or JSON file holding a list of questions, each with its text, its true answer, and optionally a
+//! category and a source:
+//!
+//! ```toml
+//! [[questions]]
+//! text = "How many bones are in the adult human body?"
+//! answer = 206
+//! category = "science"
+//! source = "League night, week 3"
+//! ```
+//!
+//! The JSON form is the same list under a `"questions"` key.
+//! TOML banks need the `toml` feature, on by default.
+//!
+//! [`import_csv`] converts a spreadsheet of a league's past questions into a bank.
+
+use rand::Rng;
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// A single trivia question with a numeric answer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Question {
+    pub text: String,
+    /// The true answer, a positive whole number
+    pub answer: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    /// Where the question came from, e.g. a league, book, or website
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+/// A validated, non-empty list of questions
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuestionBank {
+    pub questions: Vec<Question>,
+}
+
+/// Errors that can occur while loading a question bank
+#[derive(Debug)]
+pub enum QuestionBankError {
+    Io(io::Error),
+    /// The file extension is neither `.toml` nor `.json`
+    UnsupportedFormat(String),
+    Malformed(String),
+    /// The file parsed, but a question is unusable; `index` counts from 1
+    InvalidQuestion { index: usize, reason: String },
+    /// A CSV row is unusable; `line` counts from 1, including the header
+    InvalidRow { line: usize, reason: String },
+    Empty,
+}
+
+impl std::fmt::Display for QuestionBankError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuestionBankError::Io(e) => write!(f, "Question bank error: {}", e),
+            QuestionBankError::UnsupportedFormat(path) if cfg!(feature = "toml") => {
+                write!(f, "Question bank must be a .toml or .json file: {}", path)
+            }
+            QuestionBankError::UnsupportedFormat(path) => {
+                write!(f, "Question bank must be a .json file, as .toml needs the toml feature: {}", path)
+            }
+            QuestionBankError::Malformed(message) => write!(f, "Malformed question bank: {}", message),
+            QuestionBankError::InvalidQuestion { index, reason } => write!(f, "Invalid question {}: {}", index, reason),
+            QuestionBankError::InvalidRow { line, reason } => write!(f, "Invalid CSV row on line {}: {}", line, reason),
+            QuestionBankError::Empty => write!(f, "Question bank has no questions"),
+        }
+    }
+}
+
+impl std::error::Error for QuestionBankError {}
+
+impl From<io::Error> for QuestionBankError {
+    fn from(e: io::Error) -> Self {
+        QuestionBankError::Io(e)
+    }
+}
+
+impl QuestionBank {
+    /// Check that the bank has questions, that every question has text and a positive answer, and that no question
+    /// text appears twice
+    pub fn validate(&self) -> Result<(), QuestionBankError> {
+        if self.questions.is_empty() {
+            return Err(QuestionBankError::Empty);
+        }
+
+        let mut seen = HashSet::new();
+        for (i, question) in self.questions.iter().enumerate() {
+            let invalid = |reason: &str| QuestionBankError::InvalidQuestion { index: i + 1, reason: reason.to_string() };
+            if question.text.trim().is_empty() {
+                return Err(invalid("question text is empty"));
+            }
+            if question.answer == 0 {
+                return Err(invalid("answer must be a positive whole number"));
+            }
+            if !seen.insert(normalized_text(&question.text)) {
+                return Err(invalid("duplicate question text"));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parse and validate a bank in TOML form
+    #[cfg(feature = "toml")]
+    pub fn from_toml(contents: &str) -> Result<QuestionBank, QuestionBankError> {
+        let bank: QuestionBank = toml::from_str(contents).map_err(|e| QuestionBankError::Malformed(e.to_string()))?;
+        bank.validate()?;
+        Ok(bank)
+    }
+
+    /// Parse and validate a bank in JSON form
+    pub fn from_json(contents: &str) -> Result<QuestionBank, QuestionBankError> {
+        let bank: QuestionBank =
+            serde_json::from_str(contents).map_err(|e| QuestionBankError::Malformed(e.to_string()))?;
+        bank.validate()?;
+        Ok(bank)
+    }
+
+    /// The bank in TOML form
+    #[cfg(feature = "toml")]
+    pub fn to_toml(&self) -> String {
+        toml::to_string(self).expect("question banks always serialize")
+    }
+
+    /// A random question from the bank
+    pub fn random_question<R: Rng>(&self, rng: &mut R) -> &Question {
+        self.questions.choose(rng).expect("validated banks are non-empty")
+    }
+
+    /// Append questions whose text isn't already in the bank, returning how many were added
+    pub fn merge(&mut self, questions: Vec<Question>) -> usize {
+        let mut seen: HashSet<String> = self.questions.iter().map(|q| normalized_text(&q.text)).collect();
+        let before = self.questions.len();
+        self.questions.extend(questions.into_iter().filter(|q| seen.insert(normalized_text(&q.text))));
+        self.questions.len() - before
+    }
+
+    /// The distinct categories in the bank, in order of first appearance
+    pub fn categories(&self) -> Vec<&str> {
+        let mut categories: Vec<&str> = Vec::new();
+        for category in self.questions.iter().filter_map(|q| q.category.as_deref()) {
+            if !categories.contains(&category) {
+                categories.push(category);
+            }
+        }
+        categories
+    }
+}
+
+/// Question text compared for duplicates: case, surrounding whitespace, and runs of whitespace are ignored
+pub fn normalized_text(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+/// The file formats a bank can be stored in
+enum Format {
+    #[cfg(feature = "toml")]
+    Toml,
+    Json,
+}
+
+/// The format a path names by its extension
+fn format(path: &Path) -> Result<Format, QuestionBankError> {
+    match path.extension().and_then(|e| e.to_str()).map(str::to_lowercase).as_deref() {
+        #[cfg(feature = "toml")]
+        Some("toml") => Ok(Format::Toml),
+        Some("json") => Ok(Format::Json),
+        _ => Err(QuestionBankError::UnsupportedFormat(path.display().to_string())),
+    }
+}
+
+/// Load and validate a question bank, choosing TOML or JSON by the file extension
+pub fn load(path: &Path) -> Result<QuestionBank, QuestionBankError> {
+    let format = format(path)?;
+    let contents = fs::read_to_string(path)?;
+
+    match format {
+        #[cfg(feature = "toml")]
+        Format::Toml => QuestionBank::from_toml(&contents),
+        Format::Json => QuestionBank::from_json(&contents),
+    }
+}
+
+/// Write a question bank, as TOML or JSON by the file extension
+pub fn save(path: &Path, bank: &QuestionBank) -> Result<(), QuestionBankError> {
+    let contents = match format(path)? {
+        #[cfg(feature = "toml")]
+        Format::Toml => bank.to_toml(),
+        Format::Json => serde_json::to_string_pretty(bank).map_err(|e| QuestionBankError::Malformed(e.to_string()))? + "\n",
+    };
+    fs::write(path, contents)?;
+    Ok(())
+}
+
+/// Questions read from a CSV, with the rows dropped as repeats of an earlier question
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvImport {
+    pub questions: Vec<Question>,
+    pub duplicates: usize,
+}
+
+/// Split one CSV line into fields, honoring double-quoted fields with `""` escapes
+///
+/// Quoted fields can't span lines, which keeps rows and line numbers in step for error messages.
+fn split_csv_line(line: &str) -> Result<Vec<String>, String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut chars = line.chars().peekable();
+    let mut quoted = false;
+
+    while let Some(c) = chars.next() {
+        match (c, quoted) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            ('"', true) => quoted = false,
+            ('"', false) if field.trim().is_empty() => {
+                field.clear();
+                quoted = true;
+            }
+            (',', false) => fields.push(std::mem::take(&mut field)),
+            (c, _) => field.push(c),
+        }
+    }
+    if quoted {
+        return Err("unterminated quoted field".to_string());
+    }
+    fields.push(field);
+
+    Ok(fields.into_iter().map(|f| f.trim().to_string()).collect())
+}
+
+/// Parse an answer written as a positive whole number, allowing thousands separators like `21,196`
+fn parse_answer(answer: &str) -> Result<u64, String> {
+    let digits: String = answer.chars().filter(|c| !matches!(c, ',' | '_' | ' ')).collect();
+    match digits.parse::<u64>() {
+        Ok(value) if value > 0 => Ok(value),
+        _ => Err(format!("answer must be a positive whole number, got {:?}", answer)),
+    }
+}
+
+/// Read questions from a CSV with a header row naming its columns
+///
+/// The header needs a `question` (or `text`) column and an `answer` column, matched case-insensitively, and may
+/// have `category` and `source` columns; any others are ignored.
+/// Blank lines are skipped, as are repeats of an earlier question, which are counted instead.
+pub fn import_csv(contents: &str) -> Result<CsvImport, QuestionBankError> {
+    let mut lines = contents.lines().enumerate().filter(|(_, line)| !line.trim().is_empty());
+
+    let (header_index, header) = lines.next().ok_or(QuestionBankError::Empty)?;
+    let header = split_csv_line(header.trim_start_matches('\u{feff}'))
+        .map_err(|reason| QuestionBankError::InvalidRow { line: header_index + 1, reason })?;
+    let column = |names: &[&str]| header.iter().position(|h| names.contains(&h.to_lowercase().as_str()));
+    let (text_column, answer_column) = match (column(&["question", "text"]), column(&["answer"])) {
+        (Some(text), Some(answer)) => (text, answer),
+        _ => return Err(QuestionBankError::Malformed("CSV header needs question and answer columns".to_string())),
+    };
+    let category_column = column(&["category"]);
+    let source_column = column(&["source"]);
+
+    let mut import = CsvImport { questions: Vec::new(), duplicates: 0 };
+    let mut seen = HashSet::new();
+    for (index, line) in lines {
+        let invalid = |reason: String| QuestionBankError::InvalidRow { line: index + 1, reason };
+        let fields = split_csv_line(line).map_err(invalid)?;
+        let field = |column: usize| fields.get(column).map(String::as_str).unwrap_or("");
+        let optional = |column: Option<usize>| column.map(field).filter(|f| !f.is_empty()).map(str::to_string);
+
+        let text = field(text_column);
+        if text.is_empty() {
+            return Err(invalid("question text is empty".to_string()));
+        }
+        let answer = parse_answer(field(answer_column)).map_err(invalid)?;
+
+        if !seen.insert(normalized_text(text)) {
+            import.duplicates += 1;
+            continue;
+        }
+        import.questions.push(Question {
+            text: text.to_string(),
+            answer,
+            category: optional(category_column),
+            source: optional(source_column),
+        });
+    }
+
+    if import.questions.is_empty() {
+        return Err(QuestionBankError::Empty);
+    }
+    Ok(import)
+}
+
+// The fixtures are TOML banks
+#[cfg(all(test, feature = "toml"))]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use std::path::PathBuf;
+
+    const TOML_BANK: &str = r#"
+[[questions]]
+text = "How many bones are in the adult human body?"
+answer = 206
+category = "science"
+source = "League night, week 3"
+
+[[questions]]
+text = "How many kilometers long is the Great Wall of China?"
+answer = 21196
+"#;
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("geomean-questions-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir.join(name)
+    }
+
+    #[test]
+    fn test_from_toml() {
+        let bank = QuestionBank::from_toml(TOML_BANK).unwrap();
+        assert_eq!(bank.questions.len(), 2);
+        assert_eq!(bank.questions[0].answer, 206);
+        assert_eq!(bank.questions[0].source.as_deref(), Some("League night, week 3"));
+        assert_eq!(bank.questions[1].category, None);
+        assert_eq!(bank.categories(), vec!["science"]);
+    }
+
+    #[test]
+    fn test_json_and_toml_agree() {
+        let json = r#"{"questions": [
+            {"text": "How many bones are in the adult human body?", "answer": 206, "category": "science", "source": "League night, week 3"},
+            {"text": "How many kilometers long is the Great Wall of China?", "answer": 21196}
+        ]}"#;
+        assert_eq!(QuestionBank::from_json(json).unwrap(), QuestionBank::from_toml(TOML_BANK).unwrap());
+    }
+
+    #[test]
+    fn test_toml_round_trip() {
+        let bank = QuestionBank::from_toml(TOML_BANK).unwrap();
+        assert_eq!(QuestionBank::from_toml(&bank.to_toml()).unwrap(), bank);
+    }
+
+    #[test]
+    fn test_validation() {
+        let invalid_index = |toml: &str| match QuestionBank::from_toml(toml) {
+            Err(QuestionBankError::InvalidQuestion { index, .. }) => Some(index),
+            _ => None,
+        };
+
+        assert_eq!(invalid_index("[[questions]]\ntext = \"Zero?\"\nanswer = 0\n"), Some(1));
+        assert_eq!(invalid_index("[[questions]]\ntext = \"  \"\nanswer = 5\n"), Some(1));
+        let duplicate = "[[questions]]\ntext = \"How tall?\"\nanswer = 5\n[[questions]]\ntext = \"how  TALL? \"\nanswer = 6\n";
+        assert_eq!(invalid_index(duplicate), Some(2));
+
+        assert!(matches!(QuestionBank::from_toml("questions = []"), Err(QuestionBankError::Empty)));
+        assert!(matches!(QuestionBank::from_toml("[[questions]]\ntext = \"Negative?\"\nanswer = -3\n"), Err(QuestionBankError::Malformed(_))));
+        assert!(matches!(QuestionBank::from_json("{}"), Err(QuestionBankError::Malformed(_))));
+    }
+
+    #[test]
+    fn test_load_by_extension() {
+        let path = temp_path("bank.toml");
+        fs::write(&path, TOML_BANK).unwrap();
+        assert_eq!(load(&path).unwrap().questions.len(), 2);
+
+        assert!(matches!(load(&temp_path("bank.yaml")), Err(QuestionBankError::UnsupportedFormat(_))));
+        assert!(matches!(load(&temp_path("missing.json")), Err(QuestionBankError::Io(_))));
+    }
+
+    #[test]
+    fn test_example_bank_loads() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/questions/example.toml");
+        let bank = load(&path).unwrap();
+        assert_eq!(bank.questions.len(), 6);
+        assert_eq!(bank.categories(), vec!["science", "geography", "music", "history"]);
+    }
+
+    #[test]
+    fn test_split_csv_line() {
+        assert_eq!(split_csv_line("a, b ,c"), Ok(vec!["a".to_string(), "b".to_string(), "c".to_string()]));
+        assert_eq!(
+            split_csv_line(r#""How many ""wonders""?", "21,196""#),
+            Ok(vec![r#"How many "wonders"?"#.to_string(), "21,196".to_string()])
+        );
+        assert_eq!(split_csv_line("a,"), Ok(vec!["a".to_string(), String::new()]));
+        assert!(split_csv_line(r#""unterminated"#).is_err());
+    }
+
+    #[test]
+    fn test_import_csv() {
+        let csv = "Question,Answer,Category,Round\n\
+                   How many bones are in the adult human body?,206,science,1\n\
+                   \n\
+                   \"How many kilometers long is the Great Wall of China?\",\"21,196\",,2\n\
+                   how many bones are in the  adult human body?,207,science,3\n";
+        let import = import_csv(csv).unwrap();
+
+        assert_eq!(import.duplicates, 1);
+        assert_eq!(import.questions.len(), 2);
+        assert_eq!(import.questions[0].category.as_deref(), Some("science"));
+        assert_eq!(import.questions[1].answer, 21_196);
+        assert_eq!(import.questions[1].category, None);
+        assert_eq!(import.questions[1].source, None);
+    }
+
+    #[test]
+    fn test_import_csv_errors() {
+        let invalid_line = |csv: &str| match import_csv(csv) {
+            Err(QuestionBankError::InvalidRow { line, .. }) => Some(line),
+            _ => None,
+        };
+
+        assert_eq!(invalid_line("text,answer\nHow far?,12.5\n"), Some(2));
+        assert_eq!(invalid_line("text,answer\nHow far?,12\nHow cold?,-40\n"), Some(3));
+        assert_eq!(invalid_line("text,answer\nHow far?,0\n"), Some(2));
+        assert_eq!(invalid_line("text,answer\n,12\n"), Some(2));
+        assert_eq!(invalid_line("text,answer\n\"How far?,12\n"), Some(2));
+        assert!(matches!(import_csv("prompt,value\nHow far?,12\n"), Err(QuestionBankError::Malformed(_))));
+        assert!(matches!(import_csv("text,answer\n"), Err(QuestionBankError::Empty)));
+        assert!(matches!(import_csv(""), Err(QuestionBankError::Empty)));
+    }
+
+    #[test]
+    fn test_merge_skips_existing_questions() {
+        let mut bank = QuestionBank::from_toml(TOML_BANK).unwrap();
+        let import = import_csv("question,answer\nHOW MANY BONES are in the adult human body?,206\nHow many keys does a piano have?,88\n").unwrap();
+
+        assert_eq!(bank.merge(import.questions), 1);
+        assert_eq!(bank.questions.len(), 3);
+        assert_eq!(bank.questions[2].answer, 88);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let bank = QuestionBank::from_toml(TOML_BANK).unwrap();
+        for name in ["saved.toml", "saved.json"] {
+            let path = temp_path(name);
+            save(&path, &bank).unwrap();
+            assert_eq!(load(&path).unwrap(), bank);
+        }
+        assert!(matches!(save(&temp_path("saved.csv"), &bank), Err(QuestionBankError::UnsupportedFormat(_))));
+    }
+
+    #[test]
+    fn test_random_question() {
+        let bank = QuestionBank::from_toml(TOML_BANK).unwrap();
+        let mut rng = StdRng::seed_from_u64(1);
+        assert!(bank.questions.contains(bank.random_question(&mut rng)));
+    }
+}
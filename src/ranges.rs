@@ -0,0 +1,199 @@
+//! Low–high ranges in place of point guesses, which many teams give naturally ("somewhere between 200 and 500").
+//!
+//! The usual way to combine ranges is the geometric mean of each range's geometric midpoint.
+//! The midpoint of `low`–`high` is `√(low × high)`, so this is just the geometric mean of every endpoint, and the
+//! table-based method works it by averaging all the endpoints' logs, with no extra arithmetic.
+//! An alternative heuristic trusts the teammates' shared ground: when every range overlaps, take the midpoint of the
+//! overlap instead.
+
+use rand::Rng;
+use rand::distributions::Distribution;
+
+use crate::exact::geometric_mean;
+use crate::practice_mode::{ConfigurationError, PracticeModeConfig, Problem};
+use crate::trivia_guess::TriviaGuessDistribution;
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RangeError {
+    EmptyInput,
+    ZeroBound,
+    Reversed,
+}
+
+impl std::fmt::Display for RangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RangeError::EmptyInput => write!(f, "Cannot combine an empty list of ranges"),
+            RangeError::ZeroBound => write!(f, "Range bounds must be positive"),
+            RangeError::Reversed => write!(f, "Range low must not exceed its high"),
+        }
+    }
+}
+
+impl std::error::Error for RangeError {}
+
+/// A teammate's guess given as an inclusive low–high range
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GuessRange {
+    pub low: u64,
+    pub high: u64,
+}
+
+impl GuessRange {
+    pub fn new(low: u64, high: u64) -> Result<Self, RangeError> {
+        if low == 0 {
+            return Err(RangeError::ZeroBound);
+        }
+        if low > high {
+            return Err(RangeError::Reversed);
+        }
+        Ok(GuessRange { low, high })
+    }
+
+    /// The geometric midpoint, `√(low × high)`, which sits the same factor from either end
+    pub fn geometric_midpoint(&self) -> f64 {
+        (self.low as f64 * self.high as f64).sqrt()
+    }
+}
+
+fn validate(ranges: &[GuessRange]) -> Result<(), RangeError> {
+    if ranges.is_empty() {
+        return Err(RangeError::EmptyInput);
+    }
+    ranges.iter().try_for_each(|range| GuessRange::new(range.low, range.high).map(|_| ()))
+}
+
+/// Every range's endpoints in order, low then high, whose geometric mean is the geometric mean of the midpoints
+pub fn endpoints(ranges: &[GuessRange]) -> Vec<u64> {
+    ranges.iter().flat_map(|range| [range.low, range.high]).collect()
+}
+
+/// The geometric mean of each range's geometric midpoint
+pub fn midpoint_mean(ranges: &[GuessRange]) -> Result<f64, RangeError> {
+    validate(ranges)?;
+    let endpoints: Vec<f64> = endpoints(ranges).into_iter().map(|e| e as f64).collect();
+    geometric_mean(&endpoints).map_err(|_| RangeError::ZeroBound)
+}
+
+/// The values inside every range, if they all overlap
+pub fn intersection(ranges: &[GuessRange]) -> Option<GuessRange> {
+    let low = ranges.iter().map(|range| range.low).max()?;
+    let high = ranges.iter().map(|range| range.high).min()?;
+    (low <= high).then_some(GuessRange { low, high })
+}
+
+/// The midpoint of the ranges' overlap, falling back to [`midpoint_mean`] when some pair of ranges is disjoint
+pub fn intersection_estimate(ranges: &[GuessRange]) -> Result<f64, RangeError> {
+    validate(ranges)?;
+    match intersection(ranges) {
+        Some(overlap) => Ok(overlap.geometric_midpoint()),
+        None => midpoint_mean(ranges),
+    }
+}
+
+/// Bounds of the natural log half-width of a generated range, so each range spans roughly 1.8× to 7.4× end to end
+const HALF_WIDTH_LN: (f64, f64) = (0.3, 1.0);
+
+/// A practice problem where each teammate gives a range around their guess, rather than the guess itself
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RangeProblem {
+    pub ranges: Vec<GuessRange>,
+    pub correct_answer: u64,
+}
+
+impl RangeProblem {
+    /// Generate a random range problem: a hidden true answer, and the team's ranges drawn around it
+    pub fn generate<R: Rng>(rng: &mut R, config: &PracticeModeConfig) -> Result<RangeProblem, ConfigurationError> {
+        let correct_answer = config.random_answer(rng);
+        Self::generate_around(rng, config, correct_answer)
+    }
+
+    /// Generate the team's ranges around a given true answer
+    ///
+    /// Each teammate's range is centred on a guess drawn as in [`Problem::generate_around`], with a random width, and
+    /// both ends rounded to the round numbers people actually say.
+    pub fn generate_around<R: Rng>(
+        rng: &mut R,
+        config: &PracticeModeConfig,
+        correct_answer: u64,
+    ) -> Result<RangeProblem, ConfigurationError> {
+        let distribution = TriviaGuessDistribution::new(correct_answer, config.log_std_dev)
+            .map_err(|_| ConfigurationError::InvalidAnswerRange)?;
+
+        let ranges = (0..config.team_size)
+            .map(|_| {
+                let centre = distribution.sample(rng) as f64;
+                let half_width = rng.gen_range(HALF_WIDTH_LN.0..=HALF_WIDTH_LN.1).exp();
+                let low = distribution.round_to_trivia_value(centre / half_width);
+                let high = distribution.round_to_trivia_value(centre * half_width).max(low);
+                GuessRange { low, high }
+            })
+            .collect();
+
+        Ok(RangeProblem { ranges, correct_answer })
+    }
+
+    /// The equivalent point-guess problem, with every endpoint as a guess, graded by the same geometric mean
+    pub fn to_problem(&self) -> Problem {
+        Problem { guesses: endpoints(&self.ranges), correct_answer: self.correct_answer }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn ranges(bounds: &[(u64, u64)]) -> Vec<GuessRange> {
+        bounds.iter().map(|&(low, high)| GuessRange::new(low, high).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_new_validates_bounds() {
+        assert_eq!(GuessRange::new(0, 10), Err(RangeError::ZeroBound));
+        assert_eq!(GuessRange::new(20, 10), Err(RangeError::Reversed));
+        assert_eq!(GuessRange::new(10, 10), Ok(GuessRange { low: 10, high: 10 }));
+        assert!((GuessRange { low: 100, high: 400 }.geometric_midpoint() - 200.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_midpoint_mean() {
+        // Midpoints 200 and 50
+        let mean = midpoint_mean(&ranges(&[(100, 400), (25, 100)])).unwrap();
+        assert!((mean - 100.0).abs() < 1e-9);
+        assert_eq!(endpoints(&ranges(&[(100, 400), (25, 100)])), vec![100, 400, 25, 100]);
+        assert_eq!(midpoint_mean(&[]), Err(RangeError::EmptyInput));
+        assert_eq!(midpoint_mean(&[GuessRange { low: 5, high: 1 }]), Err(RangeError::Reversed));
+    }
+
+    #[test]
+    fn test_intersection_estimate() {
+        let overlapping = ranges(&[(100, 400), (200, 1_000), (50, 800)]);
+        assert_eq!(intersection(&overlapping), Some(GuessRange { low: 200, high: 400 }));
+        assert!((intersection_estimate(&overlapping).unwrap() - 80_000.0_f64.sqrt()).abs() < 1e-9);
+
+        let disjoint = ranges(&[(100, 400), (25, 50)]);
+        assert_eq!(intersection(&disjoint), None);
+        assert_eq!(intersection_estimate(&disjoint), midpoint_mean(&disjoint));
+        assert_eq!(intersection(&[]), None);
+    }
+
+    #[test]
+    fn test_generated_ranges() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let config = PracticeModeConfig::new(4, 0.5, 100, 100_000).unwrap();
+        for _ in 0..200 {
+            let problem = RangeProblem::generate(&mut rng, &config).unwrap();
+            assert_eq!(problem.ranges.len(), 4);
+            assert!(problem.ranges.iter().all(|range| range.low >= 1 && range.low <= range.high));
+
+            let point = problem.to_problem();
+            assert_eq!(point.guesses.len(), 8);
+            assert_eq!(point.correct_answer, problem.correct_answer);
+        }
+    }
+}
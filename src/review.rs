@@ -0,0 +1,242 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::history::{ClearFilter, HistoryRecord};
+use crate::practice_mode::Problem;
+
+/// Number of correct answers in review needed before a missed problem is retired
+pub const REQUIRED_CORRECT_ANSWERS: usize = 2;
+
+/// A problem the user missed, kept until it has been answered correctly enough times in review
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MissedProblem {
+    pub guesses: Vec<u64>,
+    pub correct_answer: u64,
+    /// Estimation method the problem was missed with
+    pub method: String,
+    /// Correct answers given for this problem in review so far
+    #[serde(default)]
+    pub correct_answers: usize,
+}
+
+impl MissedProblem {
+    pub fn problem(&self) -> Problem {
+        Problem {
+            guesses: self.guesses.clone(),
+            correct_answer: self.correct_answer,
+        }
+    }
+}
+
+/// The bank of missed problems waiting to be reviewed, stored as JSON in the data directory
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReviewBank {
+    problems: Vec<MissedProblem>,
+}
+
+impl ReviewBank {
+    /// Problems still waiting to be reviewed, oldest miss first
+    pub fn problems(&self) -> &[MissedProblem] {
+        &self.problems
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.problems.is_empty()
+    }
+
+    /// Add a missed problem to the bank
+    ///
+    /// Missing a problem that is already banked starts its review progress over.
+    pub fn add_miss(&mut self, problem: &Problem, method: &str) {
+        let existing = self
+            .problems
+            .iter_mut()
+            .find(|p| p.guesses == problem.guesses && p.method == method);
+
+        match existing {
+            Some(missed) => missed.correct_answers = 0,
+            None => self.problems.push(MissedProblem {
+                guesses: problem.guesses.clone(),
+                correct_answer: problem.correct_answer,
+                method: method.to_string(),
+                correct_answers: 0,
+            }),
+        }
+    }
+
+    /// Record a review attempt at the problem with the given index, returning whether it was retired
+    pub fn record_review(&mut self, index: usize, success: bool) -> bool {
+        let Some(missed) = self.problems.get_mut(index) else {
+            return false;
+        };
+        if !success {
+            return false;
+        }
+
+        missed.correct_answers += 1;
+        if missed.correct_answers >= REQUIRED_CORRECT_ANSWERS {
+            self.problems.remove(index);
+            return true;
+        }
+        false
+    }
+
+    /// Drop the problems missed in the rounds `filter` clears from `records`, returning how many were dropped
+    ///
+    /// A problem stays while any round it was missed in is kept, and clearing with no filter empties the whole bank,
+    /// including problems missed while history was off.
+    pub fn forget_cleared(&mut self, records: &[HistoryRecord], filter: &ClearFilter) -> usize {
+        let before = self.problems.len();
+        if filter.before.is_none() && filter.profile.is_none() {
+            self.problems.clear();
+            return before;
+        }
+
+        self.problems.retain(|missed| {
+            let mut rounds = records.iter().filter(|r| r.guesses == missed.guesses && r.method == missed.method).peekable();
+            rounds.peek().is_none() || !rounds.all(|r| filter.matches(r))
+        });
+        before - self.problems.len()
+    }
+}
+
+/// Errors that can occur while reading or writing the review bank
+#[derive(Debug)]
+pub enum ReviewError {
+    Io(io::Error),
+    Malformed(String),
+}
+
+impl std::fmt::Display for ReviewError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReviewError::Io(e) => write!(f, "Review bank error: {}", e),
+            ReviewError::Malformed(message) => write!(f, "Malformed review bank: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for ReviewError {}
+
+impl From<io::Error> for ReviewError {
+    fn from(e: io::Error) -> Self {
+        ReviewError::Io(e)
+    }
+}
+
+/// Default location of the review bank, inside the user's data directory
+pub fn default_bank_path() -> Option<PathBuf> {
+    crate::paths::data_dir().map(|dir| dir.join("review.json"))
+}
+
+/// Load the review bank; a missing file is an empty bank
+pub fn load(path: &Path) -> Result<ReviewBank, ReviewError> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(ReviewBank::default()),
+        Err(e) => return Err(e.into()),
+    };
+
+    serde_json::from_str(&contents).map_err(|e| ReviewError::Malformed(e.to_string()))
+}
+
+/// Write the review bank, creating its directory if necessary
+pub fn save(path: &Path, bank: &ReviewBank) -> Result<(), ReviewError> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let contents = serde_json::to_string_pretty(bank).map_err(|e| ReviewError::Malformed(e.to_string()))?;
+    fs::write(path, contents + "\n")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::practice_mode::AnswerEvaluation;
+
+    fn problem(guesses: &[u64]) -> Problem {
+        Problem { guesses: guesses.to_vec(), correct_answer: 500 }
+    }
+
+    fn temp_bank_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("geomean-review-test-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join("review.json")
+    }
+
+    #[test]
+    fn test_problem_retired_after_two_correct_answers() {
+        let mut bank = ReviewBank::default();
+        bank.add_miss(&problem(&[100, 2000]), "table-based");
+
+        assert!(!bank.record_review(0, true));
+        assert!(!bank.record_review(0, false));
+        assert_eq!(bank.problems()[0].correct_answers, 1);
+        assert!(bank.record_review(0, true));
+        assert!(bank.is_empty());
+    }
+
+    #[test]
+    fn test_missing_again_restarts_progress() {
+        let mut bank = ReviewBank::default();
+        bank.add_miss(&problem(&[100, 2000]), "table-based");
+        bank.record_review(0, true);
+
+        bank.add_miss(&problem(&[100, 2000]), "table-based");
+        bank.add_miss(&problem(&[100, 2000]), "log-linear");
+
+        assert_eq!(bank.problems().len(), 2);
+        assert_eq!(bank.problems()[0].correct_answers, 0);
+        assert_eq!(bank.problems()[0].problem(), problem(&[100, 2000]));
+    }
+
+    #[test]
+    fn test_record_review_out_of_range_is_ignored() {
+        let mut bank = ReviewBank::default();
+        assert!(!bank.record_review(3, true));
+    }
+
+    #[test]
+    fn test_forget_cleared_follows_the_history_filter() {
+        let round = |guesses: &[u64], profile: &str| HistoryRecord {
+            guesses: guesses.to_vec(),
+            answer: 5000,
+            correct_answer: Some(500),
+            evaluation: AnswerEvaluation::Incorrect,
+            method: "table-based".to_string(),
+            profile: Some(profile.to_string()),
+            ..HistoryRecord::default()
+        };
+        let records = vec![round(&[100, 2000], "alice"), round(&[15, 40], "alice"), round(&[15, 40], "bob")];
+        let mut bank = ReviewBank::default();
+        for guesses in [&[100, 2000][..], &[15, 40], &[7, 70]] {
+            bank.add_miss(&problem(guesses), "table-based");
+        }
+
+        let alice = ClearFilter { before: None, profile: Some("alice".to_string()) };
+        assert_eq!(bank.forget_cleared(&records, &alice), 1);
+        // Still missed in one of Bob's rounds, and never recorded in history
+        let remaining: Vec<&[u64]> = bank.problems().iter().map(|p| p.guesses.as_slice()).collect();
+        assert_eq!(remaining, [&[15, 40][..], &[7, 70]]);
+
+        assert_eq!(bank.forget_cleared(&records, &ClearFilter::default()), 2);
+        assert!(bank.is_empty());
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let path = temp_bank_path("round-trip");
+        assert_eq!(load(&path).unwrap(), ReviewBank::default());
+
+        let mut bank = ReviewBank::default();
+        bank.add_miss(&problem(&[15, 40, 900]), "table-based");
+        save(&path, &bank).unwrap();
+
+        assert_eq!(load(&path).unwrap(), bank);
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,101 @@
+//! The random number generator behind every seeded problem, evaluation, and simulation.
+//!
+//! Everything that draws random numbers is generic over [`rand::Rng`], and [`DefaultRng`] is the generator the crate
+//! and its CLI construct when they need one, so there is a single place to change it.
+//!
+//! # Reproducibility
+//!
+//! A given seed, crate version, and backend reproduce identical problems, evaluations, and simulations, on every
+//! platform, up to the last bits of floating point results where platforms' logarithms differ.
+//! The default backend is ChaCha12 from `rand_chacha`, which is what `rand`'s `StdRng` currently wraps, but pinned so
+//! that a `rand` upgrade can't silently change it; seeds recorded before it was pinned still reproduce.
+//! The `pcg` feature swaps in `rand_pcg`'s PCG32, a much smaller and faster generator that suits WASM and embedded
+//! builds, and without the default `chacha` feature ChaCha12 isn't built at all.
+//! It draws a different stream from the same seed, so seeds only reproduce with the same backend.
+//! Neither backend is suitable for anything security-sensitive.
+//! Changes that alter what a seed generates, in this crate's sampling or in a backend, are breaking changes.
+
+use rand::SeedableRng;
+
+#[cfg(not(any(feature = "chacha", feature = "pcg")))]
+compile_error!("the `rand` feature needs a generator: enable `chacha` (on by default) or `pcg`");
+
+/// The generator used wherever the crate seeds one: ChaCha12, or PCG32 with the `pcg` feature
+#[cfg(not(feature = "pcg"))]
+pub type DefaultRng = rand_chacha::ChaCha12Rng;
+
+/// The generator used wherever the crate seeds one: ChaCha12, or PCG32 with the `pcg` feature
+#[cfg(feature = "pcg")]
+pub type DefaultRng = rand_pcg::Pcg32;
+
+/// The generator behind data that describes the methods rather than a session, like the advisor's evaluation data:
+/// ChaCha12 whenever the `chacha` feature is on, whichever backend [`DefaultRng`] is
+#[cfg(feature = "chacha")]
+pub type ReferenceRng = rand_chacha::ChaCha12Rng;
+
+/// The generator behind data that describes the methods rather than a session, like the advisor's evaluation data:
+/// ChaCha12 whenever the `chacha` feature is on, whichever backend [`DefaultRng`] is
+#[cfg(not(feature = "chacha"))]
+pub type ReferenceRng = rand_pcg::Pcg32;
+
+/// Name of the backend behind [`DefaultRng`], for recording alongside seeds
+pub const BACKEND: &str = if cfg!(feature = "pcg") { "pcg32" } else { "chacha12" };
+
+/// A generator seeded with `seed`, which always produces the same stream for the same backend
+pub fn seeded(seed: u64) -> DefaultRng {
+    DefaultRng::seed_from_u64(seed)
+}
+
+/// A generator seeded from the operating system, for when results needn't be reproducible
+pub fn from_entropy() -> DefaultRng {
+    DefaultRng::from_entropy()
+}
+
+/// A [`ReferenceRng`] seeded with `seed`
+pub fn reference(seed: u64) -> ReferenceRng {
+    ReferenceRng::seed_from_u64(seed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::RngCore;
+
+    use crate::evaluation::evaluate_estimate;
+    use crate::practice_mode::{PracticeModeConfig, Problem};
+    use crate::table_based::TableBasedApproximation;
+
+    #[cfg(feature = "pcg")]
+    #[test]
+    fn test_pcg32_matches_reference_output() {
+        // From the PCG reference implementation's pcg32-demo
+        let mut rng = rand_pcg::Pcg32::new(42, 54);
+        let outputs: Vec<u32> = (0..6).map(|_| rng.next_u32()).collect();
+        assert_eq!(outputs, [0xa15c02b7, 0x7b47f409, 0xba1d3330, 0x83d2f293, 0xbfa4784b, 0xcbed606e]);
+    }
+
+    #[cfg(feature = "chacha")]
+    #[test]
+    fn test_default_backend_reproduces_std_rng_seeds() {
+        let mut chacha = rand_chacha::ChaCha12Rng::seed_from_u64(42);
+        let mut std_rng = rand::rngs::StdRng::seed_from_u64(42);
+        assert!((0..64).all(|_| chacha.next_u64() == std_rng.next_u64()));
+    }
+
+    /// Golden values: if these change, seeds recorded with this version no longer reproduce
+    #[test]
+    fn test_seeds_reproduce_problems_and_evaluations() {
+        let config = PracticeModeConfig::new(4, 1.0, 100, 10_000).unwrap();
+        let problem = Problem::generate(&mut seeded(2024), &config).unwrap();
+        let evaluation = evaluate_estimate::<_, TableBasedApproximation>(&mut seeded(2024), 1.0, 1_000.0, 100);
+
+        let (expected_guesses, expected_answer, expected_error): (&[u64], u64, f64) = if cfg!(feature = "pcg") {
+            (&[390, 160, 75, 135], 123, 0.07253733804373762)
+        } else {
+            (&[1750, 8500, 2700, 2500], 4397, 0.0755158862880129)
+        };
+        assert_eq!(problem.guesses, expected_guesses, "{}", BACKEND);
+        assert_eq!(problem.correct_answer, expected_answer, "{}", BACKEND);
+        assert!((evaluation.mean_absolute_relative_error - expected_error).abs() < 1e-12, "{}", BACKEND);
+    }
+}
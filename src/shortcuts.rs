@@ -0,0 +1,290 @@
+//! Shortcuts for team sizes that are powers of two, where the geometric mean is a few rounds of square roots.
+//!
+//! The geometric mean of two values is the square root of their product, and the geometric mean of four is the
+//! geometric mean of the two pairs' geometric means, so two, four, and eight guesses take one, two, and three rounds of
+//! pairwise square roots.
+//! Each square root is worked as it would be on paper: multiply the two values' leading figures, find the nearest
+//! whole number's square in a table of squares, and correct for the remainder.
+//! That loses far less than the table's steps, but isn't exact, and each root is written down to three significant
+//! figures before the next round.
+//! [`AutoShortcut`] uses a shortcut when the team size allows one and falls back to the table-based method otherwise.
+
+use crate::math;
+use crate::table_based::{self, TableBasedApproximation};
+use crate::traits::{EstimateGeometricMean, MethodMetadata};
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ShortcutError {
+    EmptyInput,
+    NonPositiveValue,
+    ValueTooSmall,
+    /// The shortcut was given a different number of values than it takes
+    WrongCount { expected: usize, actual: usize },
+}
+
+impl core::fmt::Display for ShortcutError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ShortcutError::EmptyInput => write!(f, "Cannot calculate geometric mean of empty input"),
+            ShortcutError::NonPositiveValue => write!(f, "Geometric mean requires all positive values"),
+            ShortcutError::ValueTooSmall => write!(f, "Values must be >= 1.0 for this pen-and-paper method"),
+            ShortcutError::WrongCount { expected, actual } => {
+                write!(f, "This shortcut takes exactly {} values, but was given {}", expected, actual)
+            }
+        }
+    }
+}
+
+impl core::error::Error for ShortcutError {}
+
+impl From<table_based::GeometricMeanError> for ShortcutError {
+    fn from(error: table_based::GeometricMeanError) -> Self {
+        match error {
+            table_based::GeometricMeanError::EmptyInput => ShortcutError::EmptyInput,
+            table_based::GeometricMeanError::NonPositiveValue => ShortcutError::NonPositiveValue,
+            table_based::GeometricMeanError::ValueTooSmall => ShortcutError::ValueTooSmall,
+        }
+    }
+}
+
+/// Team sizes with a shortcut, from the fewest square roots to the most
+pub const SHORTCUT_SIZES: [usize; 3] = [2, 4, 8];
+
+/// Rounds of pairwise square roots that find the geometric mean of `count` values, or `None` without a shortcut
+pub fn shortcut_rounds(count: usize) -> Option<u32> {
+    SHORTCUT_SIZES.contains(&count).then(|| count.trailing_zeros())
+}
+
+/// Whole numbers in the table of squares the paper square root starts from, covering radicands from 10 to 1,000
+const TABLE_OF_SQUARES: core::ops::RangeInclusive<u32> = 3..=32;
+
+/// `value` (at least 1) written to three significant figures, as a leading figure from 1 to 10 and a power of ten
+fn three_figures(value: f64) -> (f64, i32) {
+    let mut exponent = math::floor(math::log10(value)) as i32;
+    let mut mantissa = value / math::powi(10.0, exponent);
+    // Floating point logarithms can land a hair either side of a power of ten
+    if mantissa < 1.0 {
+        mantissa *= 10.0;
+        exponent -= 1;
+    }
+    let mut mantissa = math::floor(mantissa * 100.0 + 0.5) / 100.0;
+    if mantissa >= 10.0 {
+        mantissa /= 10.0;
+        exponent += 1;
+    }
+    (mantissa, exponent)
+}
+
+/// The square root of `radicand`, from 10 to 1,000, as worked on paper: the root of the nearest square in the table,
+/// plus the remainder divided by twice that root
+fn paper_square_root(radicand: f64) -> f64 {
+    let nearest = TABLE_OF_SQUARES
+        .map(f64::from)
+        .min_by(|a, b| (a * a - radicand).abs().total_cmp(&(b * b - radicand).abs()))
+        .expect("the table of squares is not empty");
+    nearest + (radicand - nearest * nearest) / (2.0 * nearest)
+}
+
+/// The geometric mean of two values on paper: multiply their leading figures, add their powers of ten, and take the
+/// square root, leaving an even power of ten to halve
+fn paper_pair_mean(a: f64, b: f64) -> f64 {
+    let (a_mantissa, a_exponent) = three_figures(a);
+    let (b_mantissa, b_exponent) = three_figures(b);
+    let (mut product, mut exponent) = (a_mantissa * b_mantissa, a_exponent + b_exponent);
+    // Move powers of ten into the product until it is from 10 to 1,000 and the power left over is even
+    let shift = if exponent % 2 != 0 { 1 } else if product < 10.0 { 2 } else { 0 };
+    product *= math::powi(10.0, shift);
+    exponent -= shift;
+    paper_square_root(product) * math::powi(10.0, exponent / 2)
+}
+
+/// Replace each adjacent pair with its geometric mean, worked on paper, until one value is left
+///
+/// Only called with a count from [`SHORTCUT_SIZES`].
+fn pairwise_square_roots(values: &[f64]) -> f64 {
+    let mut level = [0.0; 8];
+    level[..values.len()].copy_from_slice(values);
+    let mut len = values.len();
+    while len > 1 {
+        len /= 2;
+        for i in 0..len {
+            level[i] = paper_pair_mean(level[2 * i], level[2 * i + 1]);
+        }
+    }
+    level[0]
+}
+
+/// Values of at least 1, matching the table method the auto-selection falls back to
+fn validate(values: &[f64]) -> Result<(), ShortcutError> {
+    if values.is_empty() {
+        return Err(ShortcutError::EmptyInput);
+    }
+
+    for &value in values {
+        if value <= 0.0 {
+            return Err(ShortcutError::NonPositiveValue);
+        }
+        if value < 1.0 {
+            return Err(ShortcutError::ValueTooSmall);
+        }
+    }
+
+    Ok(())
+}
+
+/// The shortcut for exactly `COUNT` values, which must be one of [`SHORTCUT_SIZES`]
+pub struct PairwiseSquareRoots<const COUNT: usize>;
+
+/// Two guesses: the square root of their product
+pub type TwoGuessShortcut = PairwiseSquareRoots<2>;
+/// Four guesses: two rounds of pairwise square roots
+pub type FourGuessShortcut = PairwiseSquareRoots<4>;
+/// Eight guesses: three rounds of pairwise square roots
+pub type EightGuessShortcut = PairwiseSquareRoots<8>;
+
+impl MethodMetadata for TwoGuessShortcut {
+    const NAME: &'static str = "shortcut-2";
+    const DESCRIPTION: &'static str = "Multiplies the two values' leading figures and takes the square root from the nearest known square. \
+Only works for two values.";
+}
+
+impl MethodMetadata for FourGuessShortcut {
+    const NAME: &'static str = "shortcut-4";
+    const DESCRIPTION: &'static str = "Takes the square root of each pair's product, then the square root of those two results' product. \
+Only works for four values.";
+}
+
+impl MethodMetadata for EightGuessShortcut {
+    const NAME: &'static str = "shortcut-8";
+    const DESCRIPTION: &'static str = "Three rounds of pairwise square roots, halving eight values to four, two, and then one. \
+Only works for eight values.";
+}
+
+impl<const COUNT: usize> EstimateGeometricMean for PairwiseSquareRoots<COUNT> {
+    type Error = ShortcutError;
+
+    fn estimate_geometric_mean(values: &[f64]) -> Result<f64, Self::Error> {
+        validate(values)?;
+        if values.len() != COUNT || shortcut_rounds(COUNT).is_none() {
+            return Err(ShortcutError::WrongCount { expected: COUNT, actual: values.len() });
+        }
+        Ok(pairwise_square_roots(values))
+    }
+}
+
+/// Pairwise square roots when the team size has a shortcut, and the table-based method otherwise
+pub struct AutoShortcut;
+
+impl AutoShortcut {
+    /// Name of the method used for `count` values
+    pub fn selected_method(count: usize) -> &'static str {
+        match count {
+            2 => TwoGuessShortcut::NAME,
+            4 => FourGuessShortcut::NAME,
+            8 => EightGuessShortcut::NAME,
+            _ => TableBasedApproximation::NAME,
+        }
+    }
+}
+
+impl MethodMetadata for AutoShortcut {
+    const NAME: &'static str = "auto-shortcut";
+    const DESCRIPTION: &'static str = "For two, four, or eight values, takes one, two, or three rounds of pairwise square roots. \
+Any other number of values uses the table-based method instead.";
+}
+
+impl EstimateGeometricMean for AutoShortcut {
+    type Error = ShortcutError;
+
+    fn estimate_geometric_mean(values: &[f64]) -> Result<f64, Self::Error> {
+        validate(values)?;
+        match shortcut_rounds(values.len()) {
+            Some(_) => Ok(pairwise_square_roots(values)),
+            None => Ok(TableBasedApproximation::estimate_geometric_mean(values)?),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exact::geometric_mean;
+
+    fn close(actual: f64, expected: f64) -> bool {
+        (actual - expected).abs() <= 1e-9 * expected
+    }
+
+    #[test]
+    fn test_shortcut_rounds() {
+        assert_eq!(shortcut_rounds(2), Some(1));
+        assert_eq!(shortcut_rounds(4), Some(2));
+        assert_eq!(shortcut_rounds(8), Some(3));
+        for count in [0, 1, 3, 5, 16] {
+            assert_eq!(shortcut_rounds(count), None, "{}", count);
+        }
+    }
+
+    #[test]
+    fn test_three_figures() {
+        assert_eq!(three_figures(2_500.0), (2.5, 3));
+        assert_eq!(three_figures(1_000.0), (1.0, 3));
+        assert_eq!(three_figures(38.64), (3.86, 1));
+        assert_eq!(three_figures(9.996), (1.0, 1));
+    }
+
+    #[test]
+    fn test_paper_square_root() {
+        assert_eq!(paper_square_root(49.0), 7.0);
+        // 60 is 4 short of 64, so 8 - 4 ÷ 16
+        assert_eq!(paper_square_root(60.0), 7.75);
+        // 90 is 9 past 81, so 9 + 9 ÷ 18
+        assert_eq!(paper_square_root(90.0), 9.5);
+    }
+
+    #[test]
+    fn test_perfect_squares_are_exact() {
+        assert!(close(TwoGuessShortcut::estimate_geometric_mean(&[25.0, 400.0]).unwrap(), 100.0));
+        assert!(close(FourGuessShortcut::estimate_geometric_mean(&[2.0, 8.0, 10.0, 1_000.0]).unwrap(), 20.0));
+    }
+
+    #[test]
+    fn test_shortcuts_stay_close_to_exact() {
+        // 2 × 3 is 600 hundredths, 24 past 24², so the root is 24 + 24 ÷ 48 tenths: 2.45 rather than 2.449...
+        assert!(close(TwoGuessShortcut::estimate_geometric_mean(&[2.0, 3.0]).unwrap(), 2.45));
+
+        let eight = [3.0, 70.0, 900.0, 1.5, 12_000.0, 45.0, 8.0, 250_000.0];
+        let estimate = EightGuessShortcut::estimate_geometric_mean(&eight).unwrap();
+        let exact = geometric_mean(&eight).unwrap();
+        assert!((estimate / exact - 1.0).abs() < 0.05, "{} vs {}", estimate, exact);
+        assert!(!close(estimate, exact));
+    }
+
+    #[test]
+    fn test_huge_values_do_not_overflow() {
+        assert!(close(TwoGuessShortcut::estimate_geometric_mean(&[1e200, 1e300]).unwrap(), 1e250));
+    }
+
+    #[test]
+    fn test_shortcut_errors() {
+        assert_eq!(
+            TwoGuessShortcut::estimate_geometric_mean(&[1.0, 2.0, 3.0]),
+            Err(ShortcutError::WrongCount { expected: 2, actual: 3 })
+        );
+        assert_eq!(PairwiseSquareRoots::<3>::estimate_geometric_mean(&[1.0, 2.0, 3.0]), Err(ShortcutError::WrongCount { expected: 3, actual: 3 }));
+        assert_eq!(FourGuessShortcut::estimate_geometric_mean(&[]), Err(ShortcutError::EmptyInput));
+        assert_eq!(TwoGuessShortcut::estimate_geometric_mean(&[0.0, 2.0]), Err(ShortcutError::NonPositiveValue));
+        assert_eq!(AutoShortcut::estimate_geometric_mean(&[0.5, 2.0, 4.0]), Err(ShortcutError::ValueTooSmall));
+    }
+
+    #[test]
+    fn test_auto_shortcut_selects_by_size() {
+        assert_eq!(AutoShortcut::selected_method(4), "shortcut-4");
+        assert_eq!(AutoShortcut::selected_method(3), "table-based");
+
+        let four = [2.0, 8.0, 10.0, 1_000.0];
+        assert_eq!(AutoShortcut::estimate_geometric_mean(&four), FourGuessShortcut::estimate_geometric_mean(&four));
+        let three = [2_000.0, 50.0, 350.0];
+        assert_eq!(AutoShortcut::estimate_geometric_mean(&three), Ok(TableBasedApproximation::estimate_geometric_mean(&three).unwrap()));
+    }
+}
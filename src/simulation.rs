@@ -0,0 +1,411 @@
+//! Whole trivia games between teams that aggregate their guesses differently.
+//!
+//! [`evaluation`](crate::evaluation) measures how close each method gets to the exact geometric mean; this module
+//! measures what that is worth at the table.
+//! Every team is posed the same questions, guesses with the same uncertainty, and submits its own aggregate, and a
+//! [`ScoringRule`] awards points per question.
+//! Over many games, the report gives each strategy's share of wins.
+
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use crate::bayesian::{LogNormal, bayesian_estimate};
+use crate::exact::ExactGeometricMean;
+use crate::log_linear::LogLinearApproximation;
+use crate::median::MedianMethod;
+use crate::practice_mode::{ConfigurationError, PracticeModeConfig, Problem};
+use crate::table_based::TableBasedApproximation;
+use crate::traits::{EstimateGeometricMean, MethodMetadata};
+
+/// How a team turns its members' guesses into one answer
+///
+/// Teams know the game's shape, its answer range and how widely they guess, though only the Bayesian strategy uses it.
+#[derive(Debug, Clone, Copy)]
+pub struct Strategy {
+    pub name: &'static str,
+    aggregate: fn(&[f64], &PracticeModeConfig) -> Option<f64>,
+}
+
+fn aggregate_with<E: EstimateGeometricMean>(guesses: &[f64], _: &PracticeModeConfig) -> Option<f64> {
+    E::estimate_geometric_mean(guesses).ok()
+}
+
+fn arithmetic_mean(guesses: &[f64], _: &PracticeModeConfig) -> Option<f64> {
+    if guesses.is_empty() {
+        return None;
+    }
+    Some(guesses.iter().sum::<f64>() / guesses.len() as f64)
+}
+
+fn bayesian(guesses: &[f64], problems: &PracticeModeConfig) -> Option<f64> {
+    let prior = LogNormal::from_range(problems.min_answer as f64, problems.max_answer as f64).ok()?;
+    bayesian_estimate(&prior, guesses, problems.log_std_dev).ok()
+}
+
+impl Strategy {
+    /// Aggregate with an estimation method
+    pub fn method<E: EstimateGeometricMean + MethodMetadata>() -> Self {
+        Strategy { name: E::NAME, aggregate: aggregate_with::<E> }
+    }
+
+    /// Aggregate by averaging the guesses, as a team does when it doesn't think about it
+    pub fn arithmetic_mean() -> Self {
+        Strategy { name: "arithmetic-mean", aggregate: arithmetic_mean }
+    }
+
+    /// Aggregate with the posterior median, using the answer range as the prior and the guess spread as the likelihood
+    pub fn bayesian() -> Self {
+        Strategy { name: "bayesian", aggregate: bayesian }
+    }
+
+    /// The team's answer to a question from a game shaped by `problems`, or `None` when the strategy can't handle the guesses
+    pub fn aggregate(&self, guesses: &[f64], problems: &PracticeModeConfig) -> Option<f64> {
+        (self.aggregate)(guesses, problems)
+    }
+}
+
+/// The Bayesian estimate, the geometric mean methods, the median, and the arithmetic mean
+pub fn default_strategies() -> Vec<Strategy> {
+    vec![
+        Strategy::bayesian(),
+        Strategy::method::<ExactGeometricMean>(),
+        Strategy::method::<LogLinearApproximation>(),
+        Strategy::method::<TableBasedApproximation>(),
+        Strategy::method::<MedianMethod>(),
+        Strategy::arithmetic_mean(),
+    ]
+}
+
+/// The default strategy named `name`
+pub fn strategy_by_name(name: &str) -> Option<Strategy> {
+    default_strategies().into_iter().find(|strategy| strategy.name == name)
+}
+
+/// How points are awarded for each question
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScoringRule {
+    /// A point to every team whose answer is within this fraction of the true answer, e.g. 0.1 for 10%
+    WithinFraction(f64),
+    /// A point to the team whose answer is closest to the true answer, or to each team tied for closest
+    ClosestWins,
+}
+
+impl ScoringRule {
+    /// Points for each team's answer to a question, in the same order
+    pub fn score(&self, answers: &[Option<f64>], correct_answer: f64) -> Vec<u32> {
+        let distance = |answer: &Option<f64>| answer.map_or(f64::INFINITY, |a| (a - correct_answer).abs());
+
+        match *self {
+            ScoringRule::WithinFraction(fraction) => answers
+                .iter()
+                .map(|answer| u32::from(distance(answer) <= fraction * correct_answer))
+                .collect(),
+            ScoringRule::ClosestWins => {
+                let closest = answers.iter().map(distance).fold(f64::INFINITY, f64::min);
+                answers.iter().map(|answer| u32::from(closest.is_finite() && distance(answer) == closest)).collect()
+            }
+        }
+    }
+}
+
+/// The shape of a simulated game
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameConfig {
+    pub questions: usize,
+    /// Team size, guess spread, and answer range, shared by every team
+    pub problems: PracticeModeConfig,
+    pub scoring: ScoringRule,
+    /// True answers to draw each question from, such as a question bank's; random ones from the answer range when
+    /// `None` or empty
+    pub answers: Option<Vec<u64>>,
+}
+
+/// How one strategy fared over every simulated game
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StrategyOutcome {
+    pub name: String,
+    /// Share of games won, with ties split evenly between the teams tied for first
+    pub win_rate: f64,
+    pub mean_points: f64,
+}
+
+/// The outcome of every strategy, in the order they were given
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SimulationReport {
+    pub games: usize,
+    pub outcomes: Vec<StrategyOutcome>,
+}
+
+/// Points for each strategy in a single game
+pub fn play_game<R: Rng>(rng: &mut R, strategies: &[Strategy], config: &GameConfig) -> Result<Vec<u32>, ConfigurationError> {
+    let mut points = vec![0; strategies.len()];
+
+    for _ in 0..config.questions {
+        let correct_answer = match config.answers.as_deref().and_then(|answers| answers.choose(rng)) {
+            Some(&answer) => answer,
+            None => config.problems.random_answer(rng),
+        };
+        let mut answers = Vec::with_capacity(strategies.len());
+        for strategy in strategies {
+            let problem = Problem::generate_around(rng, &config.problems, correct_answer)?;
+            let guesses: Vec<f64> = problem.guesses.iter().map(|&g| g as f64).collect();
+            answers.push(strategy.aggregate(&guesses, &config.problems));
+        }
+
+        for (total, earned) in points.iter_mut().zip(config.scoring.score(&answers, correct_answer as f64)) {
+            *total += earned;
+        }
+    }
+
+    Ok(points)
+}
+
+/// Play `games` games between teams using each strategy, and report each strategy's win rate and average points
+pub fn simulate<R: Rng>(
+    rng: &mut R,
+    strategies: &[Strategy],
+    config: &GameConfig,
+    games: usize,
+) -> Result<SimulationReport, ConfigurationError> {
+    let mut wins = vec![0.0; strategies.len()];
+    let mut total_points = vec![0_u64; strategies.len()];
+
+    for _ in 0..games {
+        let points = play_game(rng, strategies, config)?;
+        let best = points.iter().copied().max().unwrap_or(0);
+        let winners = points.iter().filter(|&&p| p == best).count();
+
+        for (i, &p) in points.iter().enumerate() {
+            total_points[i] += u64::from(p);
+            if p == best {
+                wins[i] += 1.0 / winners as f64;
+            }
+        }
+    }
+
+    let per_game = |total: f64| if games == 0 { f64::NAN } else { total / games as f64 };
+    let outcomes = strategies
+        .iter()
+        .enumerate()
+        .map(|(i, strategy)| StrategyOutcome {
+            name: strategy.name.to_string(),
+            win_rate: per_game(wins[i]),
+            mean_points: per_game(total_points[i] as f64),
+        })
+        .collect();
+
+    Ok(SimulationReport { games, outcomes })
+}
+
+/// z-score for a 95% confidence interval
+const Z_95: f64 = 1.959_963_984_540_054;
+
+/// The 95% Wilson score interval for a proportion observed as `share` of `trials`
+///
+/// Unlike the plain normal approximation, it stays within 0 to 1 and behaves at shares near either end.
+pub fn wilson_interval(share: f64, trials: usize) -> (f64, f64) {
+    if trials == 0 {
+        return (0.0, 1.0);
+    }
+
+    let n = trials as f64;
+    let z2 = Z_95 * Z_95;
+    let center = (share + z2 / (2.0 * n)) / (1.0 + z2 / n);
+    let half_width = Z_95 / (1.0 + z2 / n) * (share * (1.0 - share) / n + z2 / (4.0 * n * n)).sqrt();
+    ((center - half_width).max(0.0), (center + half_width).min(1.0))
+}
+
+/// How often the first of two strategies won a single closest-answer question, at one uncertainty level and team size
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DuelOutcome {
+    pub log_std_dev: f64,
+    pub team_size: usize,
+    pub duels: usize,
+    /// Share of duels the first strategy won, with ties counted as half a win
+    pub win_probability: f64,
+    /// 95% confidence interval for `win_probability`
+    pub confidence_interval: (f64, f64),
+}
+
+/// Play `duels` head-to-head showdowns: one question each, closest answer wins, like a final wager
+pub fn simulate_duel<R: Rng>(
+    rng: &mut R,
+    first: Strategy,
+    second: Strategy,
+    problems: &PracticeModeConfig,
+    duels: usize,
+) -> Result<DuelOutcome, ConfigurationError> {
+    let config = GameConfig { questions: 1, problems: problems.clone(), scoring: ScoringRule::ClosestWins, answers: None };
+    let report = simulate(rng, &[first, second], &config, duels)?;
+    let win_probability = report.outcomes[0].win_rate;
+
+    Ok(DuelOutcome {
+        log_std_dev: problems.log_std_dev,
+        team_size: problems.team_size,
+        duels,
+        win_probability,
+        confidence_interval: wilson_interval(win_probability, duels),
+    })
+}
+
+/// Duel two strategies at every combination of uncertainty level and team size, in row-major order
+pub fn duel_grid<R: Rng>(
+    rng: &mut R,
+    first: Strategy,
+    second: Strategy,
+    log_std_devs: &[f64],
+    team_sizes: &[usize],
+    (min_answer, max_answer): (u64, u64),
+    duels: usize,
+) -> Result<Vec<DuelOutcome>, ConfigurationError> {
+    let mut outcomes = Vec::with_capacity(log_std_devs.len() * team_sizes.len());
+    for &log_std_dev in log_std_devs {
+        for &team_size in team_sizes {
+            let problems = PracticeModeConfig::new(team_size, log_std_dev, min_answer, max_answer)?;
+            outcomes.push(simulate_duel(rng, first, second, &problems, duels)?);
+        }
+    }
+    Ok(outcomes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn config(scoring: ScoringRule) -> GameConfig {
+        GameConfig { questions: 10, problems: PracticeModeConfig::new(4, 1.0, 10, 100_000).unwrap(), scoring, answers: None }
+    }
+
+    #[test]
+    fn test_strategies() {
+        let problems = config(ScoringRule::ClosestWins).problems;
+        assert_eq!(Strategy::method::<TableBasedApproximation>().aggregate(&[25.0, 400.0], &problems), Some(100.0));
+        assert_eq!(Strategy::arithmetic_mean().aggregate(&[25.0, 400.0], &problems), Some(212.5));
+        assert_eq!(Strategy::arithmetic_mean().aggregate(&[], &problems), None);
+        assert_eq!(Strategy::method::<ExactGeometricMean>().aggregate(&[], &problems), None);
+        // Guesses above the range's middle of 1,000 are pulled down towards it
+        let bayesian = Strategy::bayesian().aggregate(&[25_000.0, 40_000.0], &problems).unwrap();
+        assert!(bayesian > 1_000.0 && bayesian < 1_000_000_000.0_f64.sqrt());
+        assert_eq!(Strategy::bayesian().aggregate(&[], &problems), None);
+
+        let names: Vec<&str> = default_strategies().iter().map(|s| s.name).collect();
+        assert_eq!(names, vec!["bayesian", "exact", "log-linear", "table-based", "median", "arithmetic-mean"]);
+    }
+
+    #[test]
+    fn test_within_fraction_scoring() {
+        let answers = [Some(105.0), Some(89.0), None, Some(110.0)];
+        assert_eq!(ScoringRule::WithinFraction(0.1).score(&answers, 100.0), vec![1, 0, 0, 1]);
+    }
+
+    #[test]
+    fn test_closest_wins_scoring() {
+        let answers = [Some(105.0), Some(95.0), None, Some(120.0)];
+        assert_eq!(ScoringRule::ClosestWins.score(&answers, 100.0), vec![1, 1, 0, 0]);
+        assert_eq!(ScoringRule::ClosestWins.score(&[None, None], 100.0), vec![0, 0]);
+    }
+
+    #[test]
+    fn test_win_rates_sum_to_one() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let report = simulate(&mut rng, &default_strategies(), &config(ScoringRule::ClosestWins), 200).unwrap();
+
+        assert_eq!(report.games, 200);
+        let total: f64 = report.outcomes.iter().map(|o| o.win_rate).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+        assert!(report.outcomes.iter().all(|o| (0.0..=10.0).contains(&o.mean_points)));
+    }
+
+    #[test]
+    fn test_bayesian_beats_geometric_mean() {
+        // A small team guessing widely gains most from knowing the typical answer
+        let mut game = config(ScoringRule::ClosestWins);
+        game.problems = PracticeModeConfig::new(2, 1.5, 10, 100_000).unwrap();
+        let mut rng = StdRng::seed_from_u64(5);
+        let report = simulate(&mut rng, &[Strategy::bayesian(), Strategy::method::<ExactGeometricMean>()], &game, 2_000).unwrap();
+        assert!(report.outcomes[0].win_rate > 0.52, "{:?}", report);
+    }
+
+    #[test]
+    fn test_geometric_mean_beats_arithmetic_mean() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let strategies = [Strategy::method::<ExactGeometricMean>(), Strategy::arithmetic_mean()];
+        let report = simulate(&mut rng, &strategies, &config(ScoringRule::WithinFraction(0.25)), 500).unwrap();
+
+        assert!(report.outcomes[0].win_rate > report.outcomes[1].win_rate, "{:?}", report);
+        assert!(report.outcomes[0].mean_points > report.outcomes[1].mean_points, "{:?}", report);
+    }
+
+    #[test]
+    fn test_answers_from_a_question_bank() {
+        let mut rng = StdRng::seed_from_u64(6);
+        let mut game = config(ScoringRule::WithinFraction(0.0));
+        game.problems.log_std_dev = 0.0;
+        game.answers = Some(vec![200, 1_000]);
+
+        // With no spread, every guess is the true answer itself, since both are already round trivia numbers
+        let points = play_game(&mut rng, &[Strategy::arithmetic_mean()], &game).unwrap();
+        assert_eq!(points, vec![10]);
+    }
+
+    #[test]
+    fn test_strategy_by_name() {
+        assert_eq!(strategy_by_name("median").map(|s| s.name), Some("median"));
+        assert!(strategy_by_name("abacus").is_none());
+    }
+
+    #[test]
+    fn test_wilson_interval() {
+        let (low, high) = wilson_interval(0.5, 100);
+        assert!((low - 0.4038).abs() < 1e-4 && (high - 0.5962).abs() < 1e-4, "{} {}", low, high);
+
+        let (low, high) = wilson_interval(1.0, 10);
+        assert!(low > 0.7 && (high - 1.0).abs() < 1e-12, "{} {}", low, high);
+        assert_eq!(wilson_interval(0.3, 0), (0.0, 1.0));
+    }
+
+    #[test]
+    fn test_duel() {
+        let mut rng = StdRng::seed_from_u64(4);
+        let problems = PracticeModeConfig::new(4, 1.0, 10, 100_000).unwrap();
+        let first = Strategy::method::<ExactGeometricMean>();
+        let outcome = simulate_duel(&mut rng, first, Strategy::arithmetic_mean(), &problems, 2_000).unwrap();
+
+        assert_eq!((outcome.team_size, outcome.duels), (4, 2_000));
+        let (low, high) = outcome.confidence_interval;
+        assert!(low < outcome.win_probability && outcome.win_probability < high);
+        assert!(low > 0.5, "{:?}", outcome);
+    }
+
+    #[test]
+    fn test_duel_grid() {
+        let mut rng = StdRng::seed_from_u64(5);
+        let (first, second) = (Strategy::method::<TableBasedApproximation>(), Strategy::method::<MedianMethod>());
+        let outcomes = duel_grid(&mut rng, first, second, &[0.5, 1.5], &[2, 6], (10, 100_000), 50).unwrap();
+
+        let cells: Vec<(f64, usize)> = outcomes.iter().map(|o| (o.log_std_dev, o.team_size)).collect();
+        assert_eq!(cells, vec![(0.5, 2), (0.5, 6), (1.5, 2), (1.5, 6)]);
+        assert!(duel_grid(&mut rng, first, second, &[1.0], &[0], (10, 100_000), 50).is_err());
+    }
+
+    #[test]
+    fn test_identical_strategies_tie() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let strategies = [Strategy::arithmetic_mean(); 2];
+        let report = simulate(&mut rng, &strategies, &config(ScoringRule::ClosestWins), 0).unwrap();
+        assert!(report.outcomes[0].win_rate.is_nan());
+
+        let mut game = config(ScoringRule::ClosestWins);
+        game.problems.log_std_dev = 0.0;
+        let report = simulate(&mut rng, &strategies, &game, 10).unwrap();
+        assert_eq!(report.outcomes[0].win_rate, 0.5);
+        assert_eq!(report.outcomes[1].mean_points, 10.0);
+    }
+}
@@ -0,0 +1,152 @@
+//! Spread-estimation rounds: judging how widely the team's guesses disagree, as well as their average.
+//!
+//! The spread is the geometric standard deviation, the typical factor a guess sits from the team's geometric mean.
+//! It is estimated to one step of the 10^(1/10) table, and says how far to trust the mean: the wider the guesses
+//! disagree, and the fewer there are, the further their geometric mean typically lands from the true answer.
+
+use crate::advisor::noise_floor;
+use crate::exact::{GeometricMeanError, geometric_std_dev};
+use crate::math;
+
+/// Table steps an estimate may be off by and still count as close
+pub const TOLERANCE_STEPS: i32 = 1;
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SpreadError {
+    EmptyInput,
+    NonPositiveValue,
+    /// The estimate was infinite or not a number
+    NonFiniteEstimate,
+    /// The estimate was below 1, which no spread can be
+    EstimateBelowOne,
+}
+
+impl core::fmt::Display for SpreadError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SpreadError::EmptyInput => write!(f, "Cannot calculate the spread of empty input"),
+            SpreadError::NonPositiveValue => write!(f, "The spread requires all positive values"),
+            SpreadError::NonFiniteEstimate => write!(f, "A spread estimate must be a finite number"),
+            SpreadError::EstimateBelowOne => write!(f, "A spread is a factor of at least 1"),
+        }
+    }
+}
+
+impl core::error::Error for SpreadError {}
+
+impl From<GeometricMeanError> for SpreadError {
+    fn from(error: GeometricMeanError) -> Self {
+        match error {
+            GeometricMeanError::EmptyInput => SpreadError::EmptyInput,
+            GeometricMeanError::NonPositiveValue => SpreadError::NonPositiveValue,
+        }
+    }
+}
+
+/// A spread estimate graded against the guesses' geometric standard deviation
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpreadResult {
+    pub estimate: f64,
+    pub exact: f64,
+    /// Table steps from the exact spread to the estimate, positive when the estimate is too wide
+    pub steps_off: i32,
+    pub team_size: usize,
+}
+
+impl SpreadResult {
+    /// Whether the estimate is within [`TOLERANCE_STEPS`] table steps of the exact spread
+    pub fn is_close(&self) -> bool {
+        self.steps_off.abs() <= TOLERANCE_STEPS
+    }
+
+    /// The typical factor by which the team's geometric mean misses the true answer, given guesses this spread
+    pub fn mean_miss_factor(&self) -> f64 {
+        1.0 + noise_floor(math::ln(self.exact), self.team_size)
+    }
+}
+
+/// Grade an estimated spread against the exact spread of `guesses`
+///
+/// Each table step is a factor of 10^(1/10), so the distance is the ratio's log in tenths, to the nearest step.
+/// The estimate must be a finite factor of at least 1, as [`parse_spread`] accepts.
+pub fn grade_spread(guesses: &[f64], estimate: f64) -> Result<SpreadResult, SpreadError> {
+    if !estimate.is_finite() {
+        return Err(SpreadError::NonFiniteEstimate);
+    }
+    if estimate < 1.0 {
+        return Err(SpreadError::EstimateBelowOne);
+    }
+
+    let exact = geometric_std_dev(guesses)?;
+    let steps_off = (10.0 * math::log10(estimate / exact)).round() as i32;
+    Ok(SpreadResult { estimate, exact, steps_off, team_size: guesses.len() })
+}
+
+/// Parse a spread estimate given as a factor, such as `3`, `2.5`, `x3`, or `×3`
+pub fn parse_spread(input: &str) -> Result<f64, String> {
+    let trimmed = input.trim();
+    let factor = trimmed.strip_prefix(['x', 'X', '×']).unwrap_or(trimmed).trim();
+    if factor.is_empty() {
+        return Err("Please enter a spread, such as 3 for ×3".to_string());
+    }
+
+    match factor.parse::<f64>() {
+        Ok(spread) if spread.is_finite() && spread >= 1.0 => Ok(spread),
+        Ok(spread) if spread.is_finite() => Err("A spread is a factor of at least 1".to_string()),
+        _ => Err("Please enter a valid number".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grade_spread() {
+        // A spread of exactly ×2
+        let guesses = [50.0, 200.0];
+        let result = grade_spread(&guesses, 2.0).unwrap();
+        assert_eq!(result.steps_off, 0);
+        assert!(result.is_close());
+
+        assert_eq!(grade_spread(&guesses, 2.5).unwrap().steps_off, 1);
+        assert!(grade_spread(&guesses, 1.6).unwrap().is_close());
+        assert_eq!(grade_spread(&guesses, 3.0).unwrap().steps_off, 2);
+        assert!(!grade_spread(&guesses, 1.25).unwrap().is_close());
+
+        assert_eq!(grade_spread(&[40.0; 4], 1.0).unwrap().steps_off, 0);
+        assert_eq!(grade_spread(&[], 2.0), Err(SpreadError::EmptyInput));
+    }
+
+    #[test]
+    fn test_grade_spread_rejects_invalid_estimates() {
+        let guesses = [50.0, 200.0];
+        assert_eq!(grade_spread(&guesses, f64::NAN), Err(SpreadError::NonFiniteEstimate));
+        assert_eq!(grade_spread(&guesses, f64::INFINITY), Err(SpreadError::NonFiniteEstimate));
+        assert_eq!(grade_spread(&guesses, 0.5), Err(SpreadError::EstimateBelowOne));
+        assert_eq!(grade_spread(&guesses, -2.0), Err(SpreadError::EstimateBelowOne));
+    }
+
+    #[test]
+    fn test_mean_miss_factor_shrinks_with_more_guesses() {
+        let pair = grade_spread(&[50.0, 200.0], 2.0).unwrap();
+        let team = grade_spread(&[50.0, 200.0, 50.0, 200.0, 50.0, 200.0, 50.0, 200.0], 2.0).unwrap();
+        assert!((pair.exact - team.exact).abs() < 1e-12);
+        assert!(team.mean_miss_factor() < pair.mean_miss_factor());
+        assert!((grade_spread(&[40.0; 4], 1.0).unwrap().mean_miss_factor() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_parse_spread() {
+        assert_eq!(parse_spread("3"), Ok(3.0));
+        assert_eq!(parse_spread(" x2.5\n"), Ok(2.5));
+        assert_eq!(parse_spread("×4"), Ok(4.0));
+        assert_eq!(parse_spread("X 1"), Ok(1.0));
+        assert!(parse_spread("0.5").unwrap_err().contains("at least 1"));
+        assert!(parse_spread("").is_err());
+        assert!(parse_spread("wide").is_err());
+        assert!(parse_spread("inf").is_err());
+    }
+}
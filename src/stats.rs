@@ -0,0 +1,274 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use crate::history::HistoryRecord;
+use crate::practice_mode::AnswerEvaluation;
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// The Unix epoch fell on a Thursday, three days after the Monday that starts its week
+const EPOCH_DAYS_AFTER_MONDAY: u64 = 3;
+
+/// Relative change in median solve time below which two weeks are considered the same
+const STEADY_TOLERANCE: f64 = 0.05;
+
+/// Direction of median solve time compared to the previous week
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Trend {
+    Faster,
+    Steady,
+    Slower,
+}
+
+/// Aggregate performance over one Monday-to-Sunday week (UTC)
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeeklyStats {
+    /// Seconds since the Unix epoch at the start of the week's Monday
+    pub week_start: u64,
+    pub rounds: usize,
+    /// Fraction of rounds that were Correct or Excellent
+    pub accuracy: f64,
+    /// Median time of the rounds that were answered, or `None` if every round was given up
+    pub median_duration: Option<Duration>,
+    /// Change in median solve time since the previous week with practice, if both have one
+    pub trend: Option<Trend>,
+}
+
+/// Aggregate performance with one estimation method
+#[derive(Debug, Clone, PartialEq)]
+pub struct MethodStats {
+    pub method: String,
+    pub rounds: usize,
+    /// Fraction of rounds that were Correct or Excellent
+    pub accuracy: f64,
+    /// Median time of the rounds that were answered, or `None` if every round was given up
+    pub median_duration: Option<Duration>,
+    pub fastest_duration: Option<Duration>,
+}
+
+/// Start of the Monday-based week containing `timestamp`
+pub fn week_start(timestamp: u64) -> u64 {
+    let days = timestamp / SECONDS_PER_DAY + EPOCH_DAYS_AFTER_MONDAY;
+    let monday = (days - days % 7).saturating_sub(EPOCH_DAYS_AFTER_MONDAY);
+    monday * SECONDS_PER_DAY
+}
+
+/// Median of a set of durations, averaging the middle pair for an even count
+pub fn median_duration(mut durations: Vec<Duration>) -> Option<Duration> {
+    if durations.is_empty() {
+        return None;
+    }
+
+    durations.sort();
+    let middle = durations.len() / 2;
+    if durations.len().is_multiple_of(2) {
+        Some((durations[middle - 1] + durations[middle]) / 2)
+    } else {
+        Some(durations[middle])
+    }
+}
+
+/// Compare a week's median solve time to the week before it, or `None` if the week before took no time to compare against
+pub fn trend(previous: Duration, current: Duration) -> Option<Trend> {
+    if previous.is_zero() {
+        return None;
+    }
+    let change = (current.as_secs_f64() - previous.as_secs_f64()) / previous.as_secs_f64();
+    if change < -STEADY_TOLERANCE {
+        Some(Trend::Faster)
+    } else if change > STEADY_TOLERANCE {
+        Some(Trend::Slower)
+    } else {
+        Some(Trend::Steady)
+    }
+}
+
+/// Fraction of records that were Correct or Excellent
+fn accuracy(records: &[&HistoryRecord]) -> f64 {
+    let successes = records.iter().filter(|r| r.evaluation.is_success()).count();
+    successes as f64 / records.len() as f64
+}
+
+/// Durations of the records that were answered rather than given up
+fn solve_durations(records: &[&HistoryRecord]) -> Vec<Duration> {
+    records
+        .iter()
+        .filter(|r| r.evaluation != AnswerEvaluation::GaveUp)
+        .map(|r| r.duration)
+        .collect()
+}
+
+/// Accuracy and median solve time for every week with recorded practice, oldest first
+///
+/// Rounds that were given up count against accuracy but have no solve time, so they are left out of the median.
+pub fn weekly_stats(records: &[HistoryRecord]) -> Vec<WeeklyStats> {
+    let mut weeks: BTreeMap<u64, Vec<&HistoryRecord>> = BTreeMap::new();
+    for record in records {
+        weeks.entry(week_start(record.timestamp)).or_default().push(record);
+    }
+
+    let mut previous_median = None;
+    weeks
+        .into_iter()
+        .map(|(week_start, records)| {
+            let median = median_duration(solve_durations(&records));
+
+            let week_trend = previous_median.zip(median).and_then(|(previous, current)| trend(previous, current));
+            previous_median = median.or(previous_median);
+
+            WeeklyStats {
+                week_start,
+                rounds: records.len(),
+                accuracy: accuracy(&records),
+                median_duration: median,
+                trend: week_trend,
+            }
+        })
+        .collect()
+}
+
+/// Accuracy and solve times broken down by estimation method, ordered by method name
+pub fn method_stats(records: &[HistoryRecord]) -> Vec<MethodStats> {
+    let mut methods: BTreeMap<&str, Vec<&HistoryRecord>> = BTreeMap::new();
+    for record in records {
+        methods.entry(record.method.as_str()).or_default().push(record);
+    }
+
+    methods
+        .into_iter()
+        .map(|(method, records)| {
+            let durations = solve_durations(&records);
+            MethodStats {
+                method: method.to_string(),
+                rounds: records.len(),
+                accuracy: accuracy(&records),
+                fastest_duration: durations.iter().min().copied(),
+                median_duration: median_duration(durations),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Monday 2025-10-06T00:00:00Z
+    const MONDAY: u64 = 1_759_708_800;
+    const WEEK: u64 = 7 * SECONDS_PER_DAY;
+
+    fn record(timestamp: u64, evaluation: AnswerEvaluation, seconds: u64) -> HistoryRecord {
+        method_record("table-based", timestamp, evaluation, seconds)
+    }
+
+    fn method_record(method: &str, timestamp: u64, evaluation: AnswerEvaluation, seconds: u64) -> HistoryRecord {
+        HistoryRecord {
+            id: format!("round-{}", timestamp),
+            timestamp,
+            guesses: vec![100, 200],
+            answer: 150,
+            evaluation,
+            duration: Duration::from_secs(seconds),
+            method: method.to_string(),
+            ..HistoryRecord::default()
+        }
+    }
+
+    #[test]
+    fn test_week_start() {
+        assert_eq!(week_start(MONDAY), MONDAY);
+        assert_eq!(week_start(MONDAY + WEEK - 1), MONDAY);
+        assert_eq!(week_start(MONDAY + WEEK), MONDAY + WEEK);
+        assert_eq!(week_start(MONDAY - 1), MONDAY - WEEK);
+        // The week containing the epoch started before it, so it is clamped to the epoch
+        assert_eq!(week_start(0), 0);
+    }
+
+    #[test]
+    fn test_median_duration() {
+        let seconds = |values: &[u64]| values.iter().map(|&s| Duration::from_secs(s)).collect();
+        assert_eq!(median_duration(seconds(&[])), None);
+        assert_eq!(median_duration(seconds(&[30, 10, 20])), Some(Duration::from_secs(20)));
+        assert_eq!(median_duration(seconds(&[40, 10, 20, 30])), Some(Duration::from_secs(25)));
+    }
+
+    #[test]
+    fn test_trend() {
+        let seconds = Duration::from_secs;
+        assert_eq!(trend(seconds(40), seconds(30)), Some(Trend::Faster));
+        assert_eq!(trend(seconds(40), seconds(41)), Some(Trend::Steady));
+        assert_eq!(trend(seconds(40), seconds(50)), Some(Trend::Slower));
+    }
+
+    #[test]
+    fn test_no_trend_from_a_zero_median() {
+        assert_eq!(trend(Duration::ZERO, Duration::from_secs(30)), None);
+        assert_eq!(trend(Duration::ZERO, Duration::ZERO), None);
+    }
+
+    #[test]
+    fn test_weekly_stats() {
+        let records = vec![
+            record(MONDAY + WEEK + 100, AnswerEvaluation::Correct, 20),
+            record(MONDAY, AnswerEvaluation::Correct, 40),
+            record(MONDAY + 100, AnswerEvaluation::Incorrect, 50),
+            record(MONDAY + 200, AnswerEvaluation::GaveUp, 5),
+            record(MONDAY + WEEK + 200, AnswerEvaluation::Excellent, 30),
+        ];
+
+        let weeks = weekly_stats(&records);
+
+        assert_eq!(weeks.len(), 2);
+        assert_eq!(weeks[0].week_start, MONDAY);
+        assert_eq!(weeks[0].rounds, 3);
+        assert!((weeks[0].accuracy - 1.0 / 3.0).abs() < 1e-9);
+        assert_eq!(weeks[0].median_duration, Some(Duration::from_secs(45)));
+        assert_eq!(weeks[0].trend, None);
+
+        assert_eq!(weeks[1].week_start, MONDAY + WEEK);
+        assert_eq!(weeks[1].accuracy, 1.0);
+        assert_eq!(weeks[1].median_duration, Some(Duration::from_secs(25)));
+        assert_eq!(weeks[1].trend, Some(Trend::Faster));
+    }
+
+    #[test]
+    fn test_trend_skips_weeks_without_a_median() {
+        let records = vec![
+            record(MONDAY, AnswerEvaluation::Correct, 40),
+            record(MONDAY + WEEK, AnswerEvaluation::GaveUp, 5),
+            record(MONDAY + 2 * WEEK, AnswerEvaluation::Correct, 60),
+        ];
+
+        let weeks = weekly_stats(&records);
+
+        assert_eq!(weeks[1].median_duration, None);
+        assert_eq!(weeks[1].trend, None);
+        assert_eq!(weeks[2].trend, Some(Trend::Slower));
+    }
+
+    #[test]
+    fn test_method_stats() {
+        let records = vec![
+            method_record("table-based", MONDAY, AnswerEvaluation::Correct, 40),
+            method_record("log-linear", MONDAY, AnswerEvaluation::Incorrect, 60),
+            method_record("table-based", MONDAY, AnswerEvaluation::Excellent, 20),
+            method_record("log-linear", MONDAY, AnswerEvaluation::GaveUp, 5),
+            method_record("table-based", MONDAY, AnswerEvaluation::Incorrect, 30),
+        ];
+
+        let methods = method_stats(&records);
+
+        assert_eq!(methods.len(), 2);
+        assert_eq!(methods[0].method, "log-linear");
+        assert_eq!(methods[0].rounds, 2);
+        assert_eq!(methods[0].accuracy, 0.0);
+        assert_eq!(methods[0].median_duration, Some(Duration::from_secs(60)));
+        assert_eq!(methods[0].fastest_duration, Some(Duration::from_secs(60)));
+
+        assert_eq!(methods[1].method, "table-based");
+        assert_eq!(methods[1].rounds, 3);
+        assert!((methods[1].accuracy - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(methods[1].median_duration, Some(Duration::from_secs(30)));
+        assert_eq!(methods[1].fastest_duration, Some(Duration::from_secs(20)));
+    }
+}
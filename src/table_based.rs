@@ -1,14 +1,18 @@
-use crate::traits::{EstimateGeometricMeanStepByStep, FinalAnswer};
+use alloc::vec::Vec;
+
+use crate::display::{TableLog, Value, joined};
+use crate::math;
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum GeometricMeanError {
     EmptyInput,
     NonPositiveValue,
     ValueTooSmall,
 }
 
-impl std::fmt::Display for GeometricMeanError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for GeometricMeanError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             GeometricMeanError::EmptyInput => write!(f, "Cannot calculate geometric mean of empty input"),
             GeometricMeanError::NonPositiveValue => write!(f, "Geometric mean requires all positive values"),
@@ -17,8 +21,18 @@ impl std::fmt::Display for GeometricMeanError {
     }
 }
 
-impl std::error::Error for GeometricMeanError {}
+impl core::error::Error for GeometricMeanError {}
+
+impl From<crate::exact::GeometricMeanError> for GeometricMeanError {
+    fn from(error: crate::exact::GeometricMeanError) -> Self {
+        match error {
+            crate::exact::GeometricMeanError::EmptyInput => GeometricMeanError::EmptyInput,
+            crate::exact::GeometricMeanError::NonPositiveValue => GeometricMeanError::NonPositiveValue,
+        }
+    }
+}
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableBasedSteps {
     input_values: Vec<f64>,
     log_conversions: Vec<i32>,
@@ -29,12 +43,40 @@ pub struct TableBasedSteps {
 
 pub struct TableBasedApproximation;
 
+impl TableBasedSteps {
+    pub fn input_values(&self) -> &[f64] {
+        &self.input_values
+    }
+
+    /// Each value's table log, in tenths
+    pub fn log_conversions(&self) -> &[i32] {
+        &self.log_conversions
+    }
+
+    /// The logs added up, in tenths
+    pub fn sum(&self) -> i32 {
+        self.sum
+    }
+
+    /// The average log, rounded up to the next tenth
+    pub fn average(&self) -> i32 {
+        self.average
+    }
+}
+
 impl crate::traits::FinalAnswer for TableBasedSteps {
     fn final_answer(&self) -> f64 {
         self.final_result
     }
 }
 
+impl crate::traits::MethodMetadata for TableBasedApproximation {
+    const NAME: &'static str = "table-based";
+    const DESCRIPTION: &'static str = "Converts each value to a log in tenths: the number of zeros, then the last row of a memorized \
+ten-entry table that the value starts at or above. \
+The logs are averaged, rounding up to the next tenth, and converted back with the same table.";
+}
+
 impl crate::traits::EstimateGeometricMeanStepByStep for TableBasedApproximation {
     type StepByStep = TableBasedSteps;
     type Error = GeometricMeanError;
@@ -48,50 +90,40 @@ impl crate::traits::EstimateGeometricMean for TableBasedApproximation {
     type Error = GeometricMeanError;
 
     fn estimate_geometric_mean(values: &[f64]) -> Result<f64, Self::Error> {
-        let steps = Self::estimate_geometric_mean_steps(values)?;
-        Ok(steps.final_answer())
+        table_based_approximation(values)
     }
 }
 
-impl std::fmt::Display for TableBasedSteps {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(f, "Input values: [{}]",
-            self.input_values.iter()
-                .map(|v| if v.fract() == 0.0 { format!("{}", *v as u64) } else { format!("{}", v) })
-                .collect::<Vec<_>>()
-                .join(", "))?;
+impl core::fmt::Display for TableBasedSteps {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        writeln!(f, "Input values: [{}]", joined(self.input_values.iter().map(|&v| Value(v)), ", "))?;
         writeln!(f)?;
 
         writeln!(f, "1. Convert each value to log representation:")?;
-        for (value, &log_conv) in self.input_values.iter().zip(self.log_conversions.iter()) {
-            let displayed_value = if value.fract() == 0.0 { format!("{}", *value as u64) } else { format!("{}", value) };
-            writeln!(f, "   {} → {:.1}", displayed_value, log_conv as f64 / 10.0)?;
+        for (&value, &log_conv) in self.input_values.iter().zip(self.log_conversions.iter()) {
+            writeln!(f, "   {} → {}", Value(value), TableLog(log_conv))?;
         }
         writeln!(f)?;
 
         writeln!(f, "2. Calculate average of log representations:")?;
-        let log_terms: Vec<String> = self.log_conversions.iter()
-            .map(|&log_conv| format!("{:.1}", log_conv as f64 / 10.0))
-            .collect();
-        writeln!(f, "   ({}) ÷ {} = {:.1} ÷ {} = {:.1}",
-                 log_terms.join(" + "),
+        writeln!(f, "   ({}) ÷ {} = {} ÷ {} = {}",
+                 joined(self.log_conversions.iter().map(|&log_conv| TableLog(log_conv)), " + "),
                  self.input_values.len(),
-                 self.sum as f64 / 10.0,
+                 TableLog(self.sum),
                  self.input_values.len(),
-                 self.average as f64 / 10.0)?;
+                 TableLog(self.average))?;
         writeln!(f)?;
 
         writeln!(f, "3. Convert back to final estimate:")?;
-        writeln!(f, "   {:.1} → {}", self.average as f64 / 10.0,
-                 if self.final_result.fract() == 0.0 { format!("{}", self.final_result as u64) } else { format!("{}", self.final_result) })?;
+        writeln!(f, "   {} → {}", TableLog(self.average), Value(self.final_result))?;
         writeln!(f)?;
 
-        write!(f, "Final estimation: {}",
-               if self.final_result.fract() == 0.0 { format!("{}", self.final_result as u64) } else { format!("{}", self.final_result) })
+        write!(f, "Final estimation: {}", Value(self.final_result))
     }
 }
 
-const MULTIPLIERS: [f64; 10] = [
+/// What a number starts with at each tenth of its log, from 0.0 to 0.9: the table memorized for this method
+pub const MULTIPLIERS: [f64; 10] = [
     1.0, 1.25, 1.6, 2.0, 2.5, 3.0, 4.0, 5.0, 6.0, 8.0
 ];
 
@@ -104,22 +136,62 @@ fn find_forward_table_entry(leading_digits: f64) -> usize {
     0
 }
 
-fn number_to_log_representation(value: f64) -> i32 {
-    let zeros = value.log10().floor() as i32;
-    let leading_digits = value / 10.0_f64.powi(zeros);
+/// Decades covered by [`BREAKPOINTS`], from 1 up to 10^20, past the largest u64 trivia answer
+const LOOKUP_DECADES: usize = 20;
+
+/// Where each table log starts, precomputed for bulk conversion: `MULTIPLIERS[i] × 10^d` at index `d × 10 + i`
+///
+/// Powers of ten are exact up to 10^22, so each breakpoint is the same product [`log_representation_to_number`] gives.
+static BREAKPOINTS: [f64; LOOKUP_DECADES * 10] = breakpoints();
+
+const fn breakpoints() -> [f64; LOOKUP_DECADES * 10] {
+    let mut breakpoints = [0.0; LOOKUP_DECADES * 10];
+    let mut power = 1.0;
+    let mut decade = 0;
+    while decade < LOOKUP_DECADES {
+        let mut entry = 0;
+        while entry < MULTIPLIERS.len() {
+            breakpoints[decade * 10 + entry] = MULTIPLIERS[entry] * power;
+            entry += 1;
+        }
+        power *= 10.0;
+        decade += 1;
+    }
+    breakpoints
+}
+
+/// Converts a number (>= 1) to its table-based log representation, in tenths
+/// Example: 2000 -> 33 (3.3), 50 -> 17 (1.7)
+///
+/// Numbers below 10^20 are a binary search over [`BREAKPOINTS`], with no floating point math beyond comparisons.
+pub fn number_to_log_representation(value: f64) -> i32 {
+    if (1.0..BREAKPOINTS[0] * 1e20).contains(&value) {
+        // The last breakpoint at or below the value is the representation
+        return BREAKPOINTS.partition_point(|&breakpoint| breakpoint <= value) as i32 - 1;
+    }
+    computed_log_representation(value)
+}
+
+/// The table-based log representation found from the number's decade and leading digits
+fn computed_log_representation(value: f64) -> i32 {
+    let zeros = math::floor(math::log10(value)) as i32;
+    let leading_digits = value / math::powi(10.0, zeros);
     let table_index = find_forward_table_entry(leading_digits);
     zeros * 10 + table_index as i32
 }
 
-fn log_representation_to_number(scaled_log: i32) -> f64 {
+/// Converts a non-negative log representation, in tenths, back to a number
+/// Example: 36 -> 4000, 28 -> 600
+pub fn log_representation_to_number(scaled_log: i32) -> f64 {
     let zeros = scaled_log / 10;
     let fractional_index = scaled_log % 10;
     let multiplier = MULTIPLIERS[fractional_index as usize];
-    multiplier * 10.0_f64.powi(zeros)
+    multiplier * math::powi(10.0, zeros)
 }
 
 
-fn table_based_approximation_steps(values: &[f64]) -> Result<TableBasedSteps, GeometricMeanError> {
+/// Both the direct and step-by-step paths accept values of at least 1
+fn validate(values: &[f64]) -> Result<(), GeometricMeanError> {
     if values.is_empty() {
         return Err(GeometricMeanError::EmptyInput);
     }
@@ -133,13 +205,31 @@ fn table_based_approximation_steps(values: &[f64]) -> Result<TableBasedSteps, Ge
         }
     }
 
+    Ok(())
+}
+
+/// The average of scaled logs, rounded up to the next tenth
+pub fn rounded_up_average(sum: i32, count: usize) -> i32 {
+    (sum + count as i32 - 1) / count as i32
+}
+
+/// Summed without keeping the conversions, as evaluation calls this millions of times
+fn table_based_approximation(values: &[f64]) -> Result<f64, GeometricMeanError> {
+    validate(values)?;
+    let sum: i32 = values.iter().map(|&v| number_to_log_representation(v)).sum();
+    Ok(log_representation_to_number(rounded_up_average(sum, values.len())))
+}
+
+fn table_based_approximation_steps(values: &[f64]) -> Result<TableBasedSteps, GeometricMeanError> {
+    validate(values)?;
+
     let input_values = values.to_vec();
     let log_conversions: Vec<i32> = values.iter()
         .map(|&v| number_to_log_representation(v))
         .collect();
 
     let sum: i32 = log_conversions.iter().sum();
-    let average = (sum + values.len() as i32 - 1) / values.len() as i32;
+    let average = rounded_up_average(sum, values.len());
     let final_result = log_representation_to_number(average);
 
     Ok(TableBasedSteps {
@@ -154,6 +244,7 @@ fn table_based_approximation_steps(values: &[f64]) -> Result<TableBasedSteps, Ge
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::traits::{EstimateGeometricMeanStepByStep, FinalAnswer};
 
     #[test]
     fn test_forward_conversion_readme_examples() {
@@ -179,6 +270,20 @@ mod tests {
         assert_eq!(result, 39);
     }
 
+    #[test]
+    fn test_breakpoints_match_computed_conversion() {
+        for (scaled_log, &breakpoint) in BREAKPOINTS.iter().enumerate() {
+            assert_eq!(breakpoint, log_representation_to_number(scaled_log as i32));
+            assert_eq!(number_to_log_representation(breakpoint), scaled_log as i32);
+            assert_eq!(computed_log_representation(breakpoint), scaled_log as i32, "{}", breakpoint);
+            // Just below a breakpoint is the step before
+            assert_eq!(number_to_log_representation(breakpoint.next_down()), scaled_log as i32 - 1);
+        }
+        for value in [1e20, 3.5e25, 0.5] {
+            assert_eq!(number_to_log_representation(value), computed_log_representation(value));
+        }
+    }
+
     #[test]
     fn test_reverse_conversion_readme_examples() {
         let result = log_representation_to_number(36);
@@ -267,6 +372,8 @@ mod tests {
 
         let expected = "Input values: [25, 400]\n\n1. Convert each value to log representation:\n   25 → 1.4\n   400 → 2.6\n\n2. Calculate average of log representations:\n   (1.4 + 2.6) ÷ 2 = 4.0 ÷ 2 = 2.0\n\n3. Convert back to final estimate:\n   2.0 → 100\n\nFinal estimation: 100";
         assert_eq!(output, expected);
+        assert_eq!(steps.input_values(), &[25.0, 400.0]);
+        assert_eq!((steps.log_conversions(), steps.sum(), steps.average()), (&[14, 26][..], 40, 20));
     }
 
     #[test]
@@ -284,23 +391,14 @@ mod tests {
     mod property_tests {
         use super::*;
         use crate::exact::geometric_mean;
+        use crate::test_support::GeOneF64;
         use crate::traits::EstimateGeometricMean;
         use quickcheck::{Arbitrary, Gen, TestResult};
         use quickcheck_macros::quickcheck;
 
-        #[derive(Clone, Debug)]
-        struct GeOneF64(f64);
-
-        impl Arbitrary for GeOneF64 {
-            fn arbitrary(g: &mut Gen) -> Self {
-                let value = loop {
-                    let candidate = f64::arbitrary(g).abs();
-                    if candidate >= 1.0 && candidate.is_finite() && candidate < 1e20 {
-                        break candidate;
-                    }
-                };
-                GeOneF64(value)
-            }
+        #[quickcheck]
+        fn prop_lookup_matches_computed_conversion(x: GeOneF64) -> bool {
+            number_to_log_representation(x.0) == computed_log_representation(x.0)
         }
 
         #[quickcheck]
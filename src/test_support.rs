@@ -0,0 +1,173 @@
+//! Quickcheck generators for property-testing estimators, shared by this crate's tests and available to
+//! downstream crates with the `test_support` feature.
+//!
+//! New estimators can reuse these to get the same property coverage as the built-in ones.
+
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+
+use quickcheck::{Arbitrary, Gen};
+
+/// A finite value of at least 1 and below `10^MAX_EXPONENT`, the domain of the pen-and-paper methods
+///
+/// The default bound covers every u64 answer; raise it to push a method further, as log-linear does.
+/// The table-based method loses exactness to floating point error somewhere past `10^23`.
+#[derive(Clone, Debug)]
+pub struct GeOneF64<const MAX_EXPONENT: i32 = 20>(pub f64);
+
+impl<const MAX_EXPONENT: i32> Arbitrary for GeOneF64<MAX_EXPONENT> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let upper = crate::math::powi(10.0, MAX_EXPONENT);
+        let value = loop {
+            let candidate = f64::arbitrary(g).abs();
+            if candidate >= 1.0 && candidate.is_finite() && candidate < upper {
+                break candidate;
+            }
+        };
+        GeOneF64(value)
+    }
+}
+
+/// One to eight values that all have the same number of digits (one to six)
+///
+/// Methods that work digit-by-digit should agree closely with the arithmetic mean on these.
+#[derive(Clone, Debug)]
+pub struct SameDigitCount(pub Vec<f64>);
+
+impl Arbitrary for SameDigitCount {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let digit_count = (u8::arbitrary(g) % 6) + 1; // 1-6 digits
+        let base = crate::math::powi(10.0, digit_count as i32 - 1);
+        let upper = base * 10.0;
+
+        let size = (usize::arbitrary(g) % 8) + 1; // 1-8 values
+        let values: Vec<f64> = (0..size)
+            .map(|_| {
+                let fraction = loop {
+                    let candidate = f64::arbitrary(g).abs() % 1.0;
+                    if candidate.is_finite() {
+                        break candidate;
+                    }
+                };
+                base + fraction * (upper - base)
+            })
+            .collect();
+
+        SameDigitCount(values)
+    }
+}
+
+/// Every number in `min..=max` that a trivia team would plausibly guess
+///
+/// Numbers leading with 1 move in steps of 0.05 of their magnitude (100, 105, 110...),
+/// those leading with 2 to 4 in steps of 0.1 (20, 21, 22...), and those leading with 5 to 9 in half steps (500, 550, 600...).
+pub fn valid_trivia_numbers_in_range(min: u64, max: u64) -> BTreeSet<u64> {
+    let mut valid_numbers = BTreeSet::new();
+
+    for magnitude in 0..=18 {
+        let magnitude_power = 10_u64.pow(magnitude);
+        if magnitude_power > max {
+            break;
+        }
+
+        // First digit 1: steps of 0.05 in leading digit position
+        for k in 0..20 { // 0.05 * 20 = 1.0, so covers 1.xx range
+            let value = magnitude_power + (magnitude_power / 20) * k;
+            if value >= min && value <= max {
+                valid_numbers.insert(value);
+            }
+            if value > max {
+                break;
+            }
+        }
+
+        // First digits 2-4: two significant digits
+        for first_digit in 2..=4 {
+            let base = first_digit * magnitude_power;
+            if base > max {
+                break;
+            }
+            for k in 0..10 { // 0.1 * 10 = 1.0, covers the digit range
+                let value = base + (magnitude_power / 10) * k;
+                if value >= min && value <= max {
+                    valid_numbers.insert(value);
+                }
+                if value > max {
+                    break;
+                }
+            }
+        }
+
+        // First digits 5-9: half-steps in leading digit position
+        for first_digit in 5..=9 {
+            let base = first_digit * magnitude_power;
+            if base > max {
+                break;
+            }
+            for k in 0..2 { // 0.5 * 2 = 1.0, covers the digit range
+                let value = base + (magnitude_power / 2) * k;
+                if value >= min && value <= max {
+                    valid_numbers.insert(value);
+                }
+                if value > max {
+                    break;
+                }
+            }
+        }
+    }
+
+    valid_numbers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use quickcheck::TestResult;
+    use quickcheck_macros::quickcheck;
+
+    #[test]
+    fn test_valid_trivia_numbers_in_range() {
+        let numbers: Vec<u64> = valid_trivia_numbers_in_range(90, 260).into_iter().collect();
+        assert_eq!(
+            numbers,
+            vec![
+                90, 95, 100, 105, 110, 115, 120, 125, 130, 135, 140, 145, 150, 155, 160, 165, 170, 175, 180, 185, 190,
+                195, 200, 210, 220, 230, 240, 250, 260,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_range_starting_between_powers_of_ten() {
+        let numbers = valid_trivia_numbers_in_range(105, 200);
+        assert_eq!(numbers.first(), Some(&105));
+        assert_eq!(numbers.len(), 20);
+    }
+
+    /// A range is the slice of the full set it covers, wherever it starts, including past a power of ten
+    #[quickcheck]
+    fn prop_range_starting_between_powers_of_ten_keeps_leading_ones(min: u32, span: u32) -> TestResult {
+        let (min, max) = (u64::from(min), u64::from(min) + u64::from(span));
+        if (0..10).any(|zeros| 10_u64.pow(zeros) == min) {
+            return TestResult::discard();
+        }
+
+        let from_one: BTreeSet<u64> = valid_trivia_numbers_in_range(1, max).into_iter().filter(|&n| n >= min).collect();
+        TestResult::from_bool(valid_trivia_numbers_in_range(min, max) == from_one)
+    }
+
+    #[quickcheck]
+    fn prop_ge_one_f64_in_bounds(x: GeOneF64, wide: GeOneF64<50>) -> bool {
+        (1.0..1e20).contains(&x.0) && (1.0..1e50).contains(&wide.0)
+    }
+
+    #[quickcheck]
+    fn prop_same_digit_count_shares_digits(same_digits: SameDigitCount) -> bool {
+        let values = &same_digits.0;
+        let share_digit_count = (0..6).any(|zeros| {
+            let base = crate::math::powi(10.0, zeros);
+            values.iter().all(|v| (base..base * 10.0).contains(v))
+        });
+        (1..=8).contains(&values.len()) && share_digit_count
+    }
+}
@@ -1,6 +1,13 @@
 pub trait EstimateGeometricMean {
-    type Error: std::error::Error;
+    type Error: core::error::Error;
     fn estimate_geometric_mean(values: &[f64]) -> Result<f64, Self::Error>;
+
+    /// The exact value being estimated, which practice mode grades answers against, or `None` if it is undefined
+    ///
+    /// This is the geometric mean, except for estimators of other means, like those in `harmonic`, that reuse this interface.
+    fn exact_mean(values: &[f64]) -> Option<f64> {
+        crate::exact::geometric_mean(values).ok()
+    }
 }
 
 pub trait FinalAnswer {
@@ -9,7 +16,15 @@ pub trait FinalAnswer {
 
 pub trait EstimateGeometricMeanStepByStep {
     type StepByStep;
-    type Error: std::error::Error;
+    type Error: core::error::Error;
 
     fn estimate_geometric_mean_steps(values: &[f64]) -> Result<Self::StepByStep, Self::Error>;
-}
\ No newline at end of file
+}
+
+pub trait MethodMetadata {
+    const NAME: &'static str;
+    /// How the method works, in a few sentences, for `explain` and other documentation
+    const DESCRIPTION: &'static str;
+    /// The kind of mean the method estimates, for display
+    const MEAN: &'static str = "geometric mean";
+}
@@ -5,6 +5,7 @@ use std::fmt;
 
 /// Errors that can occur when constructing a TriviaGuessDistribution
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TriviaGuessDistributionError {
     InvalidCorrectAnswer,
     InvalidLogStdDev,
@@ -29,6 +30,28 @@ impl fmt::Display for TriviaGuessDistributionError {
 
 impl Error for TriviaGuessDistributionError {}
 
+/// Every power of ten that fits in a u64, for finding a value's magnitude without a logarithm
+const POWERS_OF_TEN: [u64; 20] = {
+    let mut powers = [1; 20];
+    let mut i = 1;
+    while i < powers.len() {
+        powers[i] = powers[i - 1] * 10;
+        i += 1;
+    }
+    powers
+};
+
+/// The same powers as floats, all exact, so finding a magnitude needs no conversions
+const POWERS_OF_TEN_F64: [f64; 20] = {
+    let mut powers = [0.0; 20];
+    let mut i = 0;
+    while i < powers.len() {
+        powers[i] = POWERS_OF_TEN[i] as f64;
+        i += 1;
+    }
+    powers
+};
+
 /// A distribution that generates realistic trivia-style number guesses using a log-normal
 /// distribution around the correct answer, with trivia-appropriate rounding rules.
 ///
@@ -36,6 +59,7 @@ impl Error for TriviaGuessDistributionError {}
 /// the correct answer with log-normal uncertainty and using round numbers with different
 /// precision rules based on magnitude.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TriviaGuessDistribution {
     /// The true answer that guesses should cluster around
     correct_answer: u64,
@@ -94,28 +118,28 @@ impl TriviaGuessDistribution {
     /// 1. Determine the rounding rule based on the first digit
     /// 2. Use linear bracketing to find the two nearest valid candidates
     /// 3. Choose the candidate with smaller logarithmic distance
-    fn round_to_trivia_value(&self, raw_value: f64) -> u64 {
-        if raw_value <= 1.0 {
+    ///
+    /// No logarithms are taken, since sampling teams calls this for every guess: the magnitude comes from
+    /// comparing against powers of ten, and the closer candidate from comparing squares.
+    pub fn round_to_trivia_value(&self, raw_value: f64) -> u64 {
+        if raw_value.is_nan() || raw_value <= 1.0 {
             return 1;
         }
 
-        // Determine magnitude and first digit
-        let log10_value = raw_value.log10();
-        let magnitude = log10_value.floor() as i32;
+        // Determine magnitude as the largest power of ten at or below the value, which all convert to f64 exactly
+        let magnitude = POWERS_OF_TEN_F64.partition_point(|&power| power <= raw_value) - 1;
 
-        // Handle edge cases for very large or very small values
-        if magnitude < 0 {
-            return 1;
-        }
+        // Handle edge cases for very large values
         if magnitude > 18 {  // 10^18 is close to u64 max
             return u64::MAX;
         }
 
-        let magnitude_power = 10_u64.pow(magnitude as u32);
+        let magnitude_power = POWERS_OF_TEN[magnitude];
 
         // Get the first digit by normalizing to [1, 10) range
-        let normalized = raw_value / (magnitude_power as f64);
-        let first_digit = normalized.floor() as u8;
+        // Truncating is flooring here, as the value is positive
+        let normalized = raw_value / POWERS_OF_TEN_F64[magnitude];
+        let first_digit = normalized as u8;
 
         // Apply appropriate rounding rule based on first digit
         let (candidate_low, candidate_high) = match first_digit {
@@ -164,7 +188,7 @@ impl TriviaGuessDistribution {
         // Find which interval [k×step, (k+1)×step] contains the target
         let offset = target - (base as f64);
         let k = if offset >= 0.0 {
-            (offset / (step_size as f64)).floor() as u64
+            (offset / (step_size as f64)) as u64 // Truncating is flooring for a non-negative offset
         } else {
             0 // Handle edge case where target < base
         };
@@ -177,16 +201,15 @@ impl TriviaGuessDistribution {
     }
 
     /// Choose the candidate with smaller logarithmic distance to the target.
+    ///
+    /// The target is closer in log space to the low candidate exactly when target / low <= high / target,
+    /// that is when target² <= low × high, so the comparison needs no logarithms.
     fn choose_closest_in_log_space(target: f64, candidate_low: u64, candidate_high: u64) -> u64 {
         if candidate_low == 0 || candidate_high == 0 {
             return if candidate_low > 0 { candidate_low } else { candidate_high };
         }
 
-        let ln_target = target.ln();
-        let log_distance_low = (ln_target - (candidate_low as f64).ln()).abs();
-        let log_distance_high = (ln_target - (candidate_high as f64).ln()).abs();
-
-        if log_distance_low <= log_distance_high {
+        if target * target <= candidate_low as f64 * candidate_high as f64 {
             candidate_low
         } else {
             candidate_high
@@ -331,6 +354,49 @@ mod tests {
         }
     }
 
+    /// The rounding as it was before the fast path, with logarithms for the magnitude and the closer candidate
+    fn logarithmic_rounding(raw_value: f64) -> u64 {
+        if raw_value.is_nan() || raw_value <= 1.0 {
+            return 1;
+        }
+        let mut magnitude = raw_value.log10().floor() as i32;
+        if magnitude > 0 && 10_f64.powi(magnitude) > raw_value {
+            magnitude -= 1;
+        }
+        if magnitude > 18 {
+            return u64::MAX;
+        }
+        let power = 10_u64.pow(magnitude as u32);
+        let first_digit = (raw_value / power as f64).floor() as u64;
+        let (base, step_size) = match first_digit {
+            1 => (power, power / 20),
+            2..=4 => (first_digit * power, power / 10),
+            _ => (first_digit * power, power / 2),
+        };
+        let (low, high) = TriviaGuessDistribution::find_bracketing_candidates(raw_value, base, step_size);
+        let ln_target = raw_value.ln();
+        if (ln_target - (low as f64).ln()).abs() <= (ln_target - (high as f64).ln()).abs() { low } else { high }
+    }
+
+    #[quickcheck]
+    fn prop_rounding_matches_logarithms(value: crate::test_support::GeOneF64) -> bool {
+        let dist = TriviaGuessDistribution::new(100, 0.0).unwrap();
+        let (fast, reference) = (dist.round_to_trivia_value(value.0), logarithmic_rounding(value.0));
+
+        // Within rounding error of the geometric midpoint, either candidate is as good as the other
+        let near_midpoint = (value.0 * value.0 / (fast as f64 * reference as f64) - 1.0).abs() < 1e-12;
+        fast == reference || near_midpoint
+    }
+
+    #[test]
+    fn test_rounding_largest_magnitudes() {
+        let dist = TriviaGuessDistribution::new(100, 0.0).unwrap();
+
+        assert_eq!(dist.round_to_trivia_value(9.6e18), 9_500_000_000_000_000_000);
+        assert_eq!(dist.round_to_trivia_value(1e19), u64::MAX);
+        assert_eq!(dist.round_to_trivia_value(f64::INFINITY), u64::MAX);
+    }
+
     // Unit tests for rounding rules
 
     #[test]
@@ -433,12 +499,22 @@ mod tests {
         assert_eq!(dist.round_to_trivia_value(0.5), 1); // Below 1 should return 1
         assert_eq!(dist.round_to_trivia_value(1.0), 1); // Exactly 1 should return 1
         assert_eq!(dist.round_to_trivia_value(1.5), 1); // Just above 1 should still return 1 (rounds to magnitude 0)
+        assert_eq!(dist.round_to_trivia_value(f64::NAN), 1); // Not a number is treated like one below 1
 
         // Test very large numbers
         assert_eq!(dist.round_to_trivia_value(1_000_000.0), 1_000_000);
         assert_eq!(dist.round_to_trivia_value(5_500_000.0), 5_500_000);
     }
 
+    #[test]
+    fn test_rounding_just_below_power_of_ten() {
+        let dist = TriviaGuessDistribution::new(100, 0.0).unwrap();
+
+        // log10 rounds these up to a whole number, as if they had another digit
+        assert_eq!(dist.round_to_trivia_value(999.9999999999999), 1000);
+        assert_eq!(dist.round_to_trivia_value(99_999.99999999999), 100_000);
+    }
+
     #[test]
     fn test_logarithmic_midpoint_rounding() {
         let dist = TriviaGuessDistribution::new(100000, 0.0).unwrap();
@@ -488,6 +564,7 @@ mod tests {
     // Critical validation tests from the plan
 
     #[test]
+    #[allow(clippy::manual_range_contains)]
     fn test_three_digit_sample_validation() {
         // Create distribution with correct_answer=316, log_std_dev=1.151
         let dist = TriviaGuessDistribution::new(316, 1.151).unwrap();
@@ -503,7 +580,7 @@ mod tests {
         }
 
         // Verify all three-digit samples are valid trivia numbers
-        let valid_trivia_numbers = generate_valid_trivia_numbers_in_range(100, 999);
+        let valid_trivia_numbers = crate::test_support::valid_trivia_numbers_in_range(100, 999);
         for sample in three_digit_samples {
             assert!(valid_trivia_numbers.contains(&sample),
                     "Sample {} is not a valid trivia number", sample);
@@ -576,65 +653,4 @@ mod tests {
                       test_below, test_above, result_below);
         }
     }
-
-    /// Generate all valid trivia numbers in a given range for validation testing
-    fn generate_valid_trivia_numbers_in_range(min: u64, max: u64) -> std::collections::HashSet<u64> {
-        let mut valid_numbers = std::collections::HashSet::new();
-
-        for magnitude in 0..=18 {
-            let magnitude_power = 10_u64.pow(magnitude);
-            if magnitude_power > max {
-                break;
-            }
-
-            // First digit 1: steps of 0.05 in leading digit position
-            if magnitude_power >= min {
-                for k in 0..20 { // 0.05 * 20 = 1.0, so covers 1.xx range
-                    let value = magnitude_power + (magnitude_power / 20) * k;
-                    if value >= min && value <= max {
-                        valid_numbers.insert(value);
-                    }
-                    if value > max {
-                        break;
-                    }
-                }
-            }
-
-            // First digits 2-4: two significant digits
-            for first_digit in 2..=4 {
-                let base = first_digit * magnitude_power;
-                if base > max {
-                    break;
-                }
-                for k in 0..10 { // 0.1 * 10 = 1.0, covers the digit range
-                    let value = base + (magnitude_power / 10) * k;
-                    if value >= min && value <= max {
-                        valid_numbers.insert(value);
-                    }
-                    if value > max {
-                        break;
-                    }
-                }
-            }
-
-            // First digits 5-9: half-steps in leading digit position
-            for first_digit in 5..=9 {
-                let base = first_digit * magnitude_power;
-                if base > max {
-                    break;
-                }
-                for k in 0..2 { // 0.5 * 2 = 1.0, covers the digit range
-                    let value = base + (magnitude_power / 2) * k;
-                    if value >= min && value <= max {
-                        valid_numbers.insert(value);
-                    }
-                    if value > max {
-                        break;
-                    }
-                }
-            }
-        }
-
-        valid_numbers
-    }
-}
\ No newline at end of file
+}
@@ -0,0 +1,326 @@
+//! A guided walk through the table-based method for new users, one small step at a time.
+//!
+//! Each lesson takes a team's guesses through the method: convert each guess to its table log, add the logs, divide
+//! by the number of guesses rounding up, and convert back.
+//! Every entry is checked as it is made, and a mistake gets an explanation of what went wrong before the step is
+//! retried, with the expected answer given once the same step has been missed twice.
+
+use crate::drills::{format_number, parse_drill_answer};
+use crate::table_based::{
+    MULTIPLIERS, TableBasedApproximation, log_representation_to_number, number_to_log_representation, rounded_up_average,
+};
+use crate::traits::EstimateGeometricMeanStepByStep;
+
+/// The guesses for each lesson, chosen to cover exact table entries, values between entries, and rounding up
+pub const LESSONS: [&[u64]; 2] = [&[2_000, 50, 350], &[80, 800, 9_000, 30]];
+
+/// Misses on one step before the explanation includes the expected answer
+const MISSES_BEFORE_REVEAL: usize = 2;
+
+/// What a step asks for
+#[derive(Debug, Clone, PartialEq)]
+enum Task {
+    /// Convert a guess to its table log
+    Convert(u64),
+    /// Add the logs, in tenths
+    Sum(Vec<i32>),
+    /// Divide the sum by the number of guesses, rounding up to the next tenth
+    Divide { sum: i32, count: i32 },
+    /// Convert the average log back to a number
+    ConvertBack(i32),
+}
+
+fn format_log(scaled_log: i32) -> String {
+    format!("{:.1}", scaled_log as f64 / 10.0)
+}
+
+/// The table row, as a tenth of a log, for what a number starts with
+fn table_row(value: u64) -> usize {
+    (number_to_log_representation(value as f64) % 10) as usize
+}
+
+impl Task {
+    /// The correct entry: a log in tenths, or a number
+    fn expected(&self) -> i64 {
+        match self {
+            Task::Convert(value) => number_to_log_representation(*value as f64) as i64,
+            Task::Sum(logs) => logs.iter().sum::<i32>() as i64,
+            Task::Divide { sum, count } => rounded_up_average(*sum, *count as usize) as i64,
+            Task::ConvertBack(average) => log_representation_to_number(*average).round() as i64,
+        }
+    }
+
+    fn expects_log(&self) -> bool {
+        !matches!(self, Task::ConvertBack(_))
+    }
+
+    fn prompt(&self) -> String {
+        match self {
+            Task::Convert(value) => format!(
+                "Convert {} to its table log: the whole part is how many digits follow the first, and the tenths are the last table row it starts at or above.",
+                format_number(*value as f64)
+            ),
+            Task::Sum(logs) => {
+                let terms: Vec<String> = logs.iter().map(|&log| format_log(log)).collect();
+                format!("Now add the logs: {}", terms.join(" + "))
+            }
+            Task::Divide { sum, count } => {
+                format!("Now divide {} by {}, the number of guesses, rounding up to the next tenth.", format_log(*sum), count)
+            }
+            Task::ConvertBack(average) => format!(
+                "Finally, convert {} back: the whole part is how many zeros follow the leading digits, and the tenths pick the table row.",
+                format_log(*average)
+            ),
+        }
+    }
+
+    /// Why an entry is wrong, pointing at the likely slip
+    fn explain(&self, entry: i64) -> String {
+        match self {
+            Task::Convert(value) => {
+                let expected = self.expected();
+                let digits = value.to_string().len() - 1;
+                let row = table_row(*value);
+                if entry / 10 != expected / 10 {
+                    format!(
+                        "{} has {} digit(s) after the first, so the whole part of its log is {}.",
+                        format_number(*value as f64),
+                        digits,
+                        digits
+                    )
+                } else {
+                    let next = MULTIPLIERS.get(row + 1).map_or("10".to_string(), |m| m.to_string());
+                    format!(
+                        "{} starts at or above {} (row 0.{}) but below {}, so the tenths are .{}.",
+                        format_number(*value as f64),
+                        MULTIPLIERS[row],
+                        row,
+                        next,
+                        row
+                    )
+                }
+            }
+            Task::Sum(logs) => {
+                let whole: i32 = logs.iter().map(|log| log / 10).sum();
+                let tenths: i32 = logs.iter().map(|log| log % 10).sum();
+                format!(
+                    "Add the whole parts ({}) and the tenths ({} tenths) separately, then combine them.",
+                    whole, tenths
+                )
+            }
+            Task::Divide { sum, count } => {
+                let exact = *sum as f64 / 10.0 / *count as f64;
+                if entry == (sum / count) as i64 && sum % count != 0 {
+                    format!(
+                        "{} ÷ {} is {:.3}, between tenths, and the method always rounds up, not down.",
+                        format_log(*sum),
+                        count,
+                        exact
+                    )
+                } else if sum % count == 0 {
+                    format!("{} ÷ {} is exactly {}, with nothing to round.", format_log(*sum), count, format_log(sum / count))
+                } else {
+                    format!("{} ÷ {} is about {:.3}; round that up to the next tenth.", format_log(*sum), count, exact)
+                }
+            }
+            Task::ConvertBack(average) => {
+                let row = (average % 10) as usize;
+                format!(
+                    "The tenths .{} pick the row starting {}, and the whole part {} is how many zeros follow it.",
+                    row,
+                    MULTIPLIERS[row],
+                    average / 10
+                )
+            }
+        }
+    }
+
+    fn format_expected(&self) -> String {
+        if self.expects_log() { format_log(self.expected() as i32) } else { format_number(self.expected() as f64) }
+    }
+}
+
+/// One prompt in the tutorial
+#[derive(Debug, Clone, PartialEq)]
+pub struct Step {
+    /// Shown before the step when it starts a new lesson
+    pub introduction: Option<String>,
+    task: Task,
+}
+
+impl Step {
+    pub fn prompt(&self) -> String {
+        self.task.prompt()
+    }
+}
+
+/// The result of checking an entry
+#[derive(Debug, Clone, PartialEq)]
+pub enum Feedback {
+    /// Right, with a word of confirmation
+    Correct(String),
+    /// Wrong, with an explanation; the same step is asked again
+    Incorrect(String),
+    /// Not a number at all; the same step is asked again
+    Invalid(String),
+}
+
+/// The tutorial's progress through its steps
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tutorial {
+    steps: Vec<Step>,
+    current: usize,
+    misses: usize,
+}
+
+impl Default for Tutorial {
+    fn default() -> Self {
+        Tutorial::new(&LESSONS)
+    }
+}
+
+impl Tutorial {
+    /// A tutorial with one lesson for each set of guesses, which must all be at least 1
+    pub fn new(lessons: &[&[u64]]) -> Self {
+        let mut steps = Vec::new();
+        for (number, guesses) in lessons.iter().enumerate() {
+            let listed: Vec<String> = guesses.iter().map(|&g| format_number(g as f64)).collect();
+            let introduction = format!(
+                "Lesson {} of {}: the team guessed {}. Let's find their geometric mean with the table.",
+                number + 1,
+                lessons.len(),
+                listed.join(", ")
+            );
+
+            let values: Vec<f64> = guesses.iter().map(|&g| g as f64).collect();
+            let worked = TableBasedApproximation::estimate_geometric_mean_steps(&values).expect("lesson guesses are at least 1");
+            let (sum, count) = (worked.sum(), guesses.len() as i32);
+            let tasks = guesses.iter().map(|&g| Task::Convert(g)).chain([
+                Task::Sum(worked.log_conversions().to_vec()),
+                Task::Divide { sum, count },
+                Task::ConvertBack(worked.average()),
+            ]);
+            steps.extend(tasks.enumerate().map(|(i, task)| Step { introduction: (i == 0).then(|| introduction.clone()), task }));
+        }
+        Tutorial { steps, current: 0, misses: 0 }
+    }
+
+    /// The step waiting for an entry, or `None` once the tutorial is finished
+    pub fn current(&self) -> Option<&Step> {
+        self.steps.get(self.current)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.current >= self.steps.len()
+    }
+
+    /// Steps completed and the total
+    pub fn progress(&self) -> (usize, usize) {
+        (self.current.min(self.steps.len()), self.steps.len())
+    }
+
+    /// Check an entry for the current step, moving on only when it is right
+    pub fn submit(&mut self, input: &str) -> Feedback {
+        let Some(step) = self.steps.get(self.current) else {
+            return Feedback::Invalid("The tutorial is already finished".to_string());
+        };
+        let task = &step.task;
+        let value = match parse_drill_answer(input) {
+            Ok(value) => value,
+            Err(e) => return Feedback::Invalid(e),
+        };
+        let entry = if task.expects_log() { (value * 10.0).round() as i64 } else { value.round() as i64 };
+
+        if entry == task.expected() {
+            let confirmation = format!("Right, {}.", task.format_expected());
+            self.current += 1;
+            self.misses = 0;
+            return Feedback::Correct(confirmation);
+        }
+
+        self.misses += 1;
+        let mut explanation = task.explain(entry);
+        if self.misses >= MISSES_BEFORE_REVEAL {
+            explanation.push_str(&format!(" The answer is {}.", task.format_expected()));
+        }
+        Feedback::Incorrect(explanation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answer_all(tutorial: &mut Tutorial, answers: &[&str]) {
+        for answer in answers {
+            assert!(matches!(tutorial.submit(answer), Feedback::Correct(_)), "{}", answer);
+        }
+    }
+
+    #[test]
+    fn test_first_lesson() {
+        let mut tutorial = Tutorial::new(&[&[2_000, 50, 350]]);
+        let first = tutorial.current().unwrap();
+        assert!(first.introduction.as_ref().unwrap().starts_with("Lesson 1 of 1: the team guessed 2,000, 50, 350."));
+        assert!(first.prompt().starts_with("Convert 2,000 to its table log"));
+
+        answer_all(&mut tutorial, &["3.3", "1.7", "2.5"]);
+        assert_eq!(tutorial.current().unwrap().prompt(), "Now add the logs: 3.3 + 1.7 + 2.5");
+        assert_eq!(tutorial.current().unwrap().introduction, None);
+        answer_all(&mut tutorial, &["7.5"]);
+        assert_eq!(tutorial.submit("2.4"), Feedback::Incorrect("7.5 ÷ 3 is exactly 2.5, with nothing to round.".to_string()));
+        answer_all(&mut tutorial, &["2.5", "300"]);
+        assert!(tutorial.is_finished());
+        assert_eq!(tutorial.progress(), (6, 6));
+    }
+
+    #[test]
+    fn test_mistakes_are_explained() {
+        let mut tutorial = Tutorial::new(&[&[2_000, 50, 350]]);
+        assert_eq!(
+            tutorial.submit("2.3"),
+            Feedback::Incorrect("2,000 has 3 digit(s) after the first, so the whole part of its log is 3.".to_string())
+        );
+        assert_eq!(
+            tutorial.submit("3.4"),
+            Feedback::Incorrect("2,000 starts at or above 2 (row 0.3) but below 2.5, so the tenths are .3. The answer is 3.3.".to_string())
+        );
+        assert!(matches!(tutorial.submit("three"), Feedback::Invalid(_)));
+        assert_eq!(tutorial.progress(), (0, 6));
+        assert_eq!(tutorial.submit("3.3"), Feedback::Correct("Right, 3.3.".to_string()));
+
+        // A fresh step starts without revealing its answer
+        let Feedback::Incorrect(explanation) = tutorial.submit("1.6") else { panic!() };
+        assert!(!explanation.contains("The answer is"));
+    }
+
+    #[test]
+    fn test_rounding_down_is_called_out() {
+        // 10.2 ÷ 4 = 2.55, which rounds up to 2.6
+        let mut tutorial = Tutorial::new(&[&[80, 800, 9_000, 30]]);
+        answer_all(&mut tutorial, &["1.9", "2.9", "3.9", "1.5", "10.2"]);
+        let Feedback::Incorrect(explanation) = tutorial.submit("2.5") else { panic!() };
+        assert!(explanation.contains("always rounds up, not down"), "{}", explanation);
+        answer_all(&mut tutorial, &["2.6", "400"]);
+        assert!(tutorial.is_finished());
+    }
+
+    #[test]
+    fn test_lessons_match_the_method() {
+        use crate::table_based::TableBasedApproximation;
+        use crate::traits::EstimateGeometricMean;
+
+        for guesses in LESSONS {
+            let mut tutorial = Tutorial::new(&[guesses]);
+            while let Some(step) = tutorial.current() {
+                let expected = step.task.format_expected();
+                tutorial.submit(&expected);
+            }
+            let values: Vec<f64> = guesses.iter().map(|&g| g as f64).collect();
+            let estimate = TableBasedApproximation::estimate_geometric_mean(&values).unwrap();
+            let last = Tutorial::new(&[guesses]).steps.last().unwrap().task.expected();
+            assert_eq!(last, estimate.round() as i64);
+        }
+        assert!(Tutorial::default().current().is_some());
+    }
+}
@@ -0,0 +1,134 @@
+//! wasm-bindgen exports for a browser practice front-end.
+//!
+//! Each export is a thin wrapper around a plain Rust function, so the logic can be tested natively.
+//! Timing is left to JavaScript, since `std::time::Instant` is unavailable in the browser.
+
+use wasm_bindgen::prelude::*;
+
+use crate::exact::geometric_mean;
+use crate::methods;
+use crate::practice_mode::{AnswerEvaluation, PracticeModeConfig, Problem, evaluate_answer};
+use crate::rng;
+use crate::table_based::TableBasedApproximation;
+use crate::traits::EstimateGeometricMeanStepByStep;
+
+/// A generated practice problem: the team's guesses and the hidden true answer
+#[wasm_bindgen]
+pub struct WasmProblem {
+    guesses: Vec<u64>,
+    correct_answer: u64,
+}
+
+#[wasm_bindgen]
+impl WasmProblem {
+    #[wasm_bindgen(getter)]
+    pub fn guesses(&self) -> Vec<u64> {
+        self.guesses.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn correct_answer(&self) -> u64 {
+        self.correct_answer
+    }
+}
+
+fn to_f64(values: &[u64]) -> Vec<f64> {
+    values.iter().map(|&v| v as f64).collect()
+}
+
+fn generate(seed: u64, team_size: usize, log_std_dev: f64, min_answer: u64, max_answer: u64) -> Result<Problem, String> {
+    let config = PracticeModeConfig::new(team_size, log_std_dev, min_answer, max_answer).map_err(|e| e.to_string())?;
+    Problem::generate(&mut rng::seeded(seed), &config).map_err(|e| e.to_string())
+}
+
+fn estimate_with(method: &str, values: &[f64]) -> Result<f64, String> {
+    methods::estimate_by_name(method, values).unwrap_or_else(|| Err(format!("Unknown estimation method: {}", method)))
+}
+
+fn grade(method: &str, guesses: &[u64], user_answer: u64) -> Result<AnswerEvaluation, String> {
+    let values = to_f64(guesses);
+    let exact = geometric_mean(&values).map_err(|e| e.to_string())?;
+    let estimate = estimate_with(method, &values)?;
+    Ok(evaluate_answer(user_answer, exact, estimate))
+}
+
+fn tier_name(evaluation: &AnswerEvaluation) -> &'static str {
+    match evaluation {
+        AnswerEvaluation::Correct => "correct",
+        AnswerEvaluation::Excellent => "excellent",
+        AnswerEvaluation::Incorrect => "incorrect",
+        AnswerEvaluation::GaveUp => "gave-up",
+    }
+}
+
+fn steps(guesses: &[u64]) -> Result<String, String> {
+    TableBasedApproximation::estimate_geometric_mean_steps(&to_f64(guesses))
+        .map(|steps| steps.to_string())
+        .map_err(|e| e.to_string())
+}
+
+/// Generate a practice problem deterministically from `seed`, so the front-end controls randomness
+#[wasm_bindgen(js_name = generateProblem)]
+pub fn generate_problem(
+    seed: u64,
+    team_size: usize,
+    log_std_dev: f64,
+    min_answer: u64,
+    max_answer: u64,
+) -> Result<WasmProblem, JsError> {
+    let problem = generate(seed, team_size, log_std_dev, min_answer, max_answer).map_err(|e| JsError::new(&e))?;
+    Ok(WasmProblem { guesses: problem.guesses, correct_answer: problem.correct_answer })
+}
+
+/// Estimate the geometric mean of `values` with the named method ("exact", "log-linear", "table-based", or "auto-shortcut")
+#[wasm_bindgen(js_name = estimate)]
+pub fn estimate(method: &str, values: &[f64]) -> Result<f64, JsError> {
+    estimate_with(method, values).map_err(|e| JsError::new(&e))
+}
+
+/// Grade an answer to a problem solved with the named method, returning "correct", "excellent", or "incorrect"
+#[wasm_bindgen(js_name = gradeAnswer)]
+pub fn grade_answer(method: &str, guesses: &[u64], user_answer: u64) -> Result<String, JsError> {
+    let evaluation = grade(method, guesses, user_answer).map_err(|e| JsError::new(&e))?;
+    Ok(tier_name(&evaluation).to_string())
+}
+
+/// The table-based method's worked solution for a problem, as multi-line text
+#[wasm_bindgen(js_name = stepByStep)]
+pub fn step_by_step(guesses: &[u64]) -> Result<String, JsError> {
+    steps(guesses).map_err(|e| JsError::new(&e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_is_deterministic_per_seed() {
+        let first = generate(7, 4, 1.0, 10, 1000).unwrap();
+        assert_eq!(first, generate(7, 4, 1.0, 10, 1000).unwrap());
+        assert_eq!(first.guesses.len(), 4);
+        assert!(generate(7, 0, 1.0, 10, 1000).is_err());
+    }
+
+    #[test]
+    fn test_estimate_with() {
+        assert_eq!(estimate_with("table-based", &[25.0, 400.0]), Ok(100.0));
+        assert!(estimate_with("abacus", &[25.0, 400.0]).is_err());
+    }
+
+    #[test]
+    fn test_grade() {
+        assert_eq!(grade("table-based", &[25, 400], 100), Ok(AnswerEvaluation::Correct));
+        assert_eq!(grade("table-based", &[25, 400], 5000), Ok(AnswerEvaluation::Incorrect));
+        assert!(grade("table-based", &[], 100).is_err());
+        assert_eq!(tier_name(&AnswerEvaluation::Excellent), "excellent");
+    }
+
+    #[test]
+    fn test_steps() {
+        let text = steps(&[25, 400]).unwrap();
+        assert!(text.contains("25 → 1.4"));
+        assert!(text.ends_with("Final estimation: 100"));
+    }
+}
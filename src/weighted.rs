@@ -0,0 +1,254 @@
+//! Confidence-weighted aggregation, where each teammate says how sure they are of their guess, from 1 to 5.
+//!
+//! The weighted geometric mean raises each guess to its confidence, so a guess given with confidence 3 counts as three
+//! identical guesses.
+//! By hand, that means multiplying each guess's table log by its confidence and dividing the total by the total
+//! confidence.
+//! Because integer weights are the same as repeating guesses, [`ConfidenceWeightedTable`] takes its input expanded that
+//! way, which lets practice mode grade it against the exact geometric mean of the same values with no special casing.
+
+use rand::Rng;
+use rand::distributions::Distribution;
+
+use crate::display::{TableLog, Value, joined};
+use crate::exact::{self, GeometricMeanError as ExactError};
+use crate::practice_mode::{ConfigurationError, PracticeModeConfig, Problem};
+use crate::table_based::{
+    GeometricMeanError, TableBasedApproximation, log_representation_to_number, number_to_log_representation, rounded_up_average,
+};
+use crate::traits::{EstimateGeometricMean, EstimateGeometricMeanStepByStep, FinalAnswer, MethodMetadata};
+use crate::trivia_guess::TriviaGuessDistribution;
+
+/// Confidences run from 1, a wild guess, to 5, near certainty
+pub const MAX_CONFIDENCE: u8 = 5;
+
+/// A teammate's guess and how confident they are in it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WeightedGuess {
+    pub guess: u64,
+    pub confidence: u8,
+}
+
+/// Every guess repeated as many times as its confidence, the unweighted equivalent of the weighted guesses
+pub fn expand(guesses: &[WeightedGuess]) -> Vec<u64> {
+    guesses.iter().flat_map(|g| std::iter::repeat_n(g.guess, g.confidence as usize)).collect()
+}
+
+/// The geometric mean with each guess weighted by its confidence
+pub fn weighted_geometric_mean(guesses: &[WeightedGuess]) -> Result<f64, ExactError> {
+    let expanded: Vec<f64> = expand(guesses).into_iter().map(|g| g as f64).collect();
+    exact::geometric_mean(&expanded)
+}
+
+/// The table-based method with each guess's log weighted by its confidence
+///
+/// Its input is expanded with [`expand`], which loses which repeats belong to which guess, so the step-by-step trait
+/// counts each value once; [`ConfidenceWeightedTable::weighted_steps`] works from the guesses and their confidences.
+pub struct ConfidenceWeightedTable;
+
+/// The worked confidence-weighted table solution
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConfidenceWeightedSteps {
+    /// Each guess, its table log, and its confidence
+    weighted_logs: Vec<(f64, i32, u32)>,
+    sum: i32,
+    total_confidence: u32,
+    average: i32,
+    final_result: f64,
+}
+
+impl MethodMetadata for ConfidenceWeightedTable {
+    const NAME: &'static str = "confidence-weighted";
+    const DESCRIPTION: &'static str = "The table-based method, with each guess's log counted once for each point of confidence. \
+A guess with confidence 3 counts the same as three identical guesses.";
+    const MEAN: &'static str = "confidence-weighted geometric mean";
+}
+
+impl FinalAnswer for ConfidenceWeightedSteps {
+    fn final_answer(&self) -> f64 {
+        self.final_result
+    }
+}
+
+impl ConfidenceWeightedTable {
+    /// The worked solution for the guesses, with one line per guess at its own confidence
+    pub fn weighted_steps(guesses: &[WeightedGuess]) -> Result<ConfidenceWeightedSteps, GeometricMeanError> {
+        let weighted: Vec<(f64, u32)> = guesses.iter().map(|g| (g.guess as f64, u32::from(g.confidence))).collect();
+        steps(&weighted)
+    }
+}
+
+/// Work the weighted table method for (value, confidence) pairs
+fn steps(weighted: &[(f64, u32)]) -> Result<ConfidenceWeightedSteps, GeometricMeanError> {
+    let expanded: Vec<f64> =
+        weighted.iter().flat_map(|&(value, confidence)| std::iter::repeat_n(value, confidence as usize)).collect();
+    // The plain table method validates the values, and gives the same answer as the weighted working below
+    let final_result = TableBasedApproximation::estimate_geometric_mean(&expanded)?;
+
+    let weighted_logs: Vec<(f64, i32, u32)> =
+        weighted.iter().map(|&(value, confidence)| (value, number_to_log_representation(value), confidence)).collect();
+    let sum = weighted_logs.iter().map(|&(_, log, confidence)| log * confidence as i32).sum();
+    let total_confidence = expanded.len() as u32;
+    let average = rounded_up_average(sum, expanded.len());
+    debug_assert_eq!(log_representation_to_number(average), final_result);
+
+    Ok(ConfidenceWeightedSteps { weighted_logs, sum, total_confidence, average, final_result })
+}
+
+impl EstimateGeometricMeanStepByStep for ConfidenceWeightedTable {
+    type StepByStep = ConfidenceWeightedSteps;
+    type Error = GeometricMeanError;
+
+    /// Without confidences, each value is a guess of confidence 1
+    fn estimate_geometric_mean_steps(values: &[f64]) -> Result<Self::StepByStep, Self::Error> {
+        let weighted: Vec<(f64, u32)> = values.iter().map(|&value| (value, 1)).collect();
+        steps(&weighted)
+    }
+}
+
+impl EstimateGeometricMean for ConfidenceWeightedTable {
+    type Error = GeometricMeanError;
+
+    fn estimate_geometric_mean(values: &[f64]) -> Result<f64, Self::Error> {
+        TableBasedApproximation::estimate_geometric_mean(values)
+    }
+}
+
+impl std::fmt::Display for ConfidenceWeightedSteps {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "1. Convert each guess to log representation and multiply by its confidence:")?;
+        for &(value, log, confidence) in &self.weighted_logs {
+            writeln!(f, "   {} → {} × {} = {}", Value(value), TableLog(log), confidence, TableLog(log * confidence as i32))?;
+        }
+        writeln!(f)?;
+
+        writeln!(f, "2. Divide the total by the total confidence:")?;
+        writeln!(
+            f,
+            "   ({}) ÷ {} = {} ÷ {} = {}",
+            joined(self.weighted_logs.iter().map(|&(_, log, confidence)| TableLog(log * confidence as i32)), " + "),
+            self.total_confidence,
+            TableLog(self.sum),
+            self.total_confidence,
+            TableLog(self.average)
+        )?;
+        writeln!(f)?;
+
+        writeln!(f, "3. Convert back to final estimate:")?;
+        writeln!(f, "   {} → {}", TableLog(self.average), Value(self.final_result))?;
+        writeln!(f)?;
+
+        write!(f, "Final estimation: {}", Value(self.final_result))
+    }
+}
+
+/// A practice problem where each teammate gives a guess along with their confidence in it
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WeightedProblem {
+    pub guesses: Vec<WeightedGuess>,
+    pub correct_answer: u64,
+}
+
+impl WeightedProblem {
+    /// Generate a random weighted problem: a hidden true answer, and the team's guesses and confidences
+    pub fn generate<R: Rng>(rng: &mut R, config: &PracticeModeConfig) -> Result<WeightedProblem, ConfigurationError> {
+        let correct_answer = config.random_answer(rng);
+        Self::generate_around(rng, config, correct_answer)
+    }
+
+    /// Generate the team's guesses and confidences around a given true answer
+    ///
+    /// Confidence is drawn uniformly, and confident teammates guess closer: the spread scales from 5/3 of the
+    /// configured spread at confidence 1 down to 1/3 at confidence 5, so weighting by confidence pays off.
+    pub fn generate_around<R: Rng>(
+        rng: &mut R,
+        config: &PracticeModeConfig,
+        correct_answer: u64,
+    ) -> Result<WeightedProblem, ConfigurationError> {
+        let mut guesses = Vec::with_capacity(config.team_size);
+        for _ in 0..config.team_size {
+            let confidence = rng.gen_range(1..=MAX_CONFIDENCE);
+            let spread = config.log_std_dev * f64::from(MAX_CONFIDENCE + 1 - confidence) / 3.0;
+            let distribution = TriviaGuessDistribution::new(correct_answer, spread)
+                .map_err(|_| ConfigurationError::InvalidAnswerRange)?;
+            guesses.push(WeightedGuess { guess: distribution.sample(rng), confidence });
+        }
+
+        Ok(WeightedProblem { guesses, correct_answer })
+    }
+
+    /// The equivalent point-guess problem, with each guess repeated by its confidence, for [`ConfidenceWeightedTable`]
+    pub fn to_problem(&self) -> Problem {
+        Problem { guesses: expand(&self.guesses), correct_answer: self.correct_answer }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn guesses() -> Vec<WeightedGuess> {
+        vec![WeightedGuess { guess: 2_000, confidence: 3 }, WeightedGuess { guess: 500, confidence: 1 }]
+    }
+
+    fn expanded() -> Vec<f64> {
+        expand(&guesses()).into_iter().map(|g| g as f64).collect()
+    }
+
+    #[test]
+    fn test_weighted_geometric_mean() {
+        assert_eq!(expand(&guesses()), vec![2_000, 2_000, 2_000, 500]);
+        let mean = weighted_geometric_mean(&guesses()).unwrap();
+        assert!((mean - (2_000.0_f64.powi(3) * 500.0).powf(0.25)).abs() < 1e-6);
+        assert!(weighted_geometric_mean(&[]).is_err());
+        assert_eq!(weighted_geometric_mean(&[WeightedGuess { guess: 7, confidence: 5 }]).unwrap().round(), 7.0);
+    }
+
+    #[test]
+    fn test_steps() {
+        let steps = ConfidenceWeightedTable::weighted_steps(&guesses()).unwrap();
+        assert_eq!(steps.final_answer(), 1_600.0);
+        assert_eq!(
+            steps.to_string(),
+            "1. Convert each guess to log representation and multiply by its confidence:\n   2000 → 3.3 × 3 = 9.9\n   500 → 2.7 × 1 = 2.7\n\n2. Divide the total by the total confidence:\n   (9.9 + 2.7) ÷ 4 = 12.6 ÷ 4 = 3.2\n\n3. Convert back to final estimate:\n   3.2 → 1600\n\nFinal estimation: 1600"
+        );
+        assert_eq!(ConfidenceWeightedTable::estimate_geometric_mean(&expanded()), Ok(1_600.0));
+        assert!(ConfidenceWeightedTable::estimate_geometric_mean_steps(&[]).is_err());
+        assert!(ConfidenceWeightedTable::weighted_steps(&[]).is_err());
+    }
+
+    #[test]
+    fn test_equal_guesses_keep_their_own_confidences() {
+        let twins = [WeightedGuess { guess: 500, confidence: 5 }, WeightedGuess { guess: 500, confidence: 4 }];
+        let steps = ConfidenceWeightedTable::weighted_steps(&twins).unwrap().to_string();
+        assert!(steps.contains("   500 → 2.7 × 5 = 13.5\n   500 → 2.7 × 4 = 10.8\n"), "{}", steps);
+        assert!(steps.contains("(13.5 + 10.8) ÷ 9 = 24.3 ÷ 9 = 2.7\n"), "{}", steps);
+
+        // Without confidences, each value counts once
+        let values = [500.0, 500.0];
+        let steps = ConfidenceWeightedTable::estimate_geometric_mean_steps(&values).unwrap().to_string();
+        assert!(steps.contains("   500 → 2.7 × 1 = 2.7\n   500 → 2.7 × 1 = 2.7\n"), "{}", steps);
+    }
+
+    #[test]
+    fn test_generated_problems() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let config = PracticeModeConfig::new(4, 0.5, 100, 100_000).unwrap();
+        for _ in 0..200 {
+            let problem = WeightedProblem::generate(&mut rng, &config).unwrap();
+            assert_eq!(problem.guesses.len(), 4);
+            assert!(problem.guesses.iter().all(|g| (1..=MAX_CONFIDENCE).contains(&g.confidence) && g.guess > 0));
+
+            let expanded = problem.to_problem();
+            let total: usize = problem.guesses.iter().map(|g| g.confidence as usize).sum();
+            assert_eq!(expanded.guesses.len(), total);
+            let values: Vec<f64> = expanded.guesses.iter().map(|&g| g as f64).collect();
+            let steps = ConfidenceWeightedTable::weighted_steps(&problem.guesses).unwrap();
+            assert_eq!(Ok(steps.final_answer()), ConfidenceWeightedTable::estimate_geometric_mean(&values));
+        }
+    }
+}